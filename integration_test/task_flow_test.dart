@@ -0,0 +1,237 @@
+// Runs tasks end to end with tool/fake_bun.dart standing in for bun:
+//
+//   flutter test integration_test -d <linux|macos|windows>
+//
+// Preferences, logs and projects live in a temporary directory, so the
+// bob of the machine running the tests is left alone.
+
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:bob/models/project.dart';
+import 'package:bob/services/app_paths.dart';
+import 'package:bob/services/binary_manager.dart';
+import 'package:bob/services/log_file_service.dart';
+import 'package:bob/services/preferences_service.dart';
+import 'package:bob/services/source_watcher.dart';
+import 'package:bob/services/task_service.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:integration_test/integration_test.dart';
+import 'package:path/path.dart' as path;
+
+/// A task started with [TaskService], collecting what it prints
+class _Run {
+  final output = StringBuffer();
+  final exited = Completer<int>();
+  final _lines = StreamController<String>.broadcast();
+
+  void onOutput(String data) {
+    output.write(data);
+    _lines.add(data);
+  }
+
+  /// Completes once [text] was printed
+  Future<void> printed(String text) async {
+    if (output.toString().contains(text)) {
+      return;
+    }
+    await _lines.stream.firstWhere((_) => output.toString().contains(text)).timeout(const Duration(seconds: 10));
+  }
+}
+
+void main() {
+  IntegrationTestWidgetsFlutterBinding.ensureInitialized();
+
+  late Directory sandbox;
+  late Directory projectDirectory;
+  late Project project;
+
+  setUpAll(() async {
+    sandbox = await Directory.systemTemp.createTemp('bob-integration-');
+    AppPaths.configure([
+      '--config-dir',
+      path.join(sandbox.path, 'config'),
+      '--data-dir',
+      path.join(sandbox.path, 'data'),
+    ]);
+    await PreferencesService().initialize();
+
+    final fakeBun = path.join(sandbox.path, Platform.isWindows ? 'fake_bun.exe' : 'fake_bun');
+    final compiled = await Process.run('dart', ['compile', 'exe', 'tool/fake_bun.dart', '-o', fakeBun]);
+    expect(compiled.exitCode, 0, reason: '${compiled.stderr}');
+    BinaryManager.bunOverride = fakeBun;
+  });
+
+  tearDownAll(() async {
+    BinaryManager.bunOverride = null;
+    await sandbox.delete(recursive: true);
+  });
+
+  setUp(() async {
+    projectDirectory = await Directory(path.join(sandbox.path, 'project')).create();
+    await File(path.join(projectDirectory.path, 'fake-bun.json')).writeAsString(jsonEncode({
+      'scripts': {
+        'dev': {
+          'output': ['starting', 'listening on :3000'],
+          'delayMs': 50,
+          'hang': true,
+        },
+        'build': {
+          'output': ['compiling', 'built 3 files', 'done'],
+          'delayMs': 20,
+        },
+        'test': {
+          'output': ['1 failing'],
+          'stderr': true,
+          'exitCode': 1,
+        },
+      },
+    }));
+    project = Project(
+      name: 'project',
+      path: projectDirectory.path,
+      tasks: [
+        Task(name: 'dev', command: 'vite'),
+        Task(name: 'build', command: 'vite build'),
+        Task(name: 'test', command: 'vitest'),
+      ],
+      loadEnvFiles: false,
+    );
+  });
+
+  tearDown(() async {
+    await projectDirectory.delete(recursive: true);
+  });
+
+  Future<_Run> start(TaskService taskService, Task task) async {
+    final run = _Run();
+    await taskService.startTask(project, task, run.onOutput, run.exited.complete);
+    return run;
+  }
+
+  group('start and stop', () {
+    testWidgets('a task runs until it exits on its own', (tester) async {
+      final run = await start(TaskService(), project.tasks[1]);
+
+      expect(await run.exited.future.timeout(const Duration(seconds: 10)), 0);
+    });
+
+    testWidgets('a failing task reports its exit code', (tester) async {
+      final run = await start(TaskService(), project.tasks[2]);
+
+      expect(await run.exited.future.timeout(const Duration(seconds: 10)), 1);
+    });
+
+    testWidgets('stopping a task ends it', (tester) async {
+      final taskService = TaskService();
+      final task = project.tasks[0];
+      final run = await start(taskService, task);
+      await run.printed('listening on :3000');
+      expect(taskService.isTaskRunning(project, task), isTrue);
+
+      await taskService.stopTask(project, task);
+
+      expect(await run.exited.future.timeout(const Duration(seconds: 10)), isNot(0));
+      expect(taskService.isTaskRunning(project, task), isFalse);
+    });
+  });
+
+  group('output streaming', () {
+    testWidgets('lines arrive in order as they are printed', (tester) async {
+      final run = await start(TaskService(), project.tasks[1]);
+      await run.exited.future.timeout(const Duration(seconds: 10));
+
+      expect(
+        const LineSplitter().convert(run.output.toString()),
+        containsAllInOrder(['compiling', 'built 3 files', 'done']),
+      );
+    });
+
+    testWidgets('standard error goes to its own callback when given', (tester) async {
+      final output = StringBuffer();
+      final errors = StringBuffer();
+      final exited = Completer<int>();
+      await TaskService().startTask(
+        project,
+        project.tasks[2],
+        output.write,
+        exited.complete,
+        onErrorOutput: errors.write,
+      );
+      await exited.future.timeout(const Duration(seconds: 10));
+
+      expect(errors.toString(), contains('1 failing'));
+      expect(output.toString(), isNot(contains('1 failing')));
+    });
+
+    testWidgets('output of a running task can be followed', (tester) async {
+      final taskService = TaskService();
+      final task = project.tasks[0];
+      final run = _Run();
+      await taskService.startTask(project, task, run.onOutput, run.exited.complete);
+      final followed = StringBuffer();
+      final subscription = taskService.getTaskOutput(project, task)?.listen(followed.write);
+
+      await run.printed('listening on :3000');
+      await taskService.stopTask(project, task);
+      await subscription?.cancel();
+
+      expect(followed.toString(), contains('listening on :3000'));
+    });
+  });
+
+  group('history', () {
+    testWidgets('the output of a run is kept in a log', (tester) async {
+      final logFileService = LogFileService();
+      final task = project.tasks[1];
+      final log = await logFileService.openRunLog(project, task);
+      final run = _Run();
+      await TaskService().startTask(
+        project,
+        task,
+        (data) {
+          run.onOutput(data);
+          log.write(data);
+        },
+        run.exited.complete,
+      );
+      await run.exited.future.timeout(const Duration(seconds: 10));
+      await log.close();
+
+      final logs = await logFileService.listLogs(project);
+      expect(logs, hasLength(1));
+      expect(await logFileService.readChunk(logs.single.path, 0, logs.single.size), contains('built 3 files'));
+      expect((await logFileService.readLastRunTails(project))[task.name], contains('done'));
+    });
+  });
+
+  group('file watchers', () {
+    testWidgets('changes to source files are reported', (tester) async {
+      await Directory(path.join(projectDirectory.path, 'src')).create();
+      final changed = StreamController<String>.broadcast();
+      final watcher = SourceWatcher(project, changed.add)..start();
+      addTearDown(watcher.cancel);
+
+      final reported = changed.stream.firstWhere((file) => file == 'src/main.ts');
+      await File(path.join(projectDirectory.path, 'src', 'main.ts')).writeAsString('export {};\n');
+
+      expect(await reported.timeout(const Duration(seconds: 10)), 'src/main.ts');
+    });
+
+    testWidgets('changes in excluded directories are not', (tester) async {
+      await File(path.join(projectDirectory.path, '.gitignore')).writeAsString('dist/\n');
+      await Directory(path.join(projectDirectory.path, 'dist')).create();
+      final changed = <String>[];
+      final watcher = SourceWatcher(project, changed.add)..start();
+      addTearDown(watcher.cancel);
+
+      await File(path.join(projectDirectory.path, 'dist', 'bundle.js')).writeAsString('');
+      await File(path.join(projectDirectory.path, 'index.ts')).writeAsString('');
+      await Future<void>.delayed(const Duration(seconds: 1));
+
+      expect(changed, contains('index.ts'));
+      expect(changed.where((file) => file.startsWith('dist/')), isEmpty);
+    });
+  });
+}
@@ -0,0 +1,214 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:flutter/foundation.dart';
+
+import 'mcp_server.dart';
+import 'models/project.dart';
+import 'services/control_server.dart';
+import 'services/preferences_service.dart';
+import 'services/project_service.dart';
+import 'services/task_service.dart';
+import 'utils/task_utils.dart';
+
+/// Commands bob answers on the command line instead of opening its window
+const cliCommands = {'list', 'run', 'stop', 'status', 'mcp'};
+
+// Flags of the app that take a value, left out of command arguments
+const _flagsWithValue = {'--config-dir', '--data-dir', '--profile'};
+
+const _usage = '''
+Usage:
+  bob list                      List projects and their tasks
+  bob run <project> <task>      Start a task
+  bob stop <project> <task>     Stop a task
+  bob status <project> <task>   Show whether a task runs, and how it last exited
+  bob mcp                       Serve AI assistants over MCP, on stdin and stdout
+
+Projects are named as in the sidebar, or by path. Commands go to the running
+bob when there is one. Otherwise run keeps the task in the foreground until
+it exits or Ctrl+C stops it.''';
+
+/// Whether [args] ask for a command line command, wherever the app's flags
+/// are, e.g. `bob --profile work run api dev`
+bool isCliCommand(List<String> args) {
+  final arguments = commandArguments(args);
+  return arguments.isNotEmpty && cliCommands.contains(arguments.first);
+}
+
+/// [args] without the app's flags and their values
+@visibleForTesting
+List<String> commandArguments(List<String> args) {
+  final arguments = <String>[];
+  for (var i = 0; i < args.length; i++) {
+    if (_flagsWithValue.contains(args[i])) {
+      i++;
+    } else if (!args[i].startsWith('--')) {
+      arguments.add(args[i]);
+    }
+  }
+  return arguments;
+}
+
+/// Carry out a command line command and return the exit code
+Future<int> runCli(List<String> args) async {
+  final arguments = commandArguments(args);
+  final command = arguments.first;
+  if (command == 'mcp') {
+    return runMcpServer();
+  }
+  if (command != 'list' && arguments.length != 3) {
+    stderr.writeln(_usage);
+    return 64;
+  }
+
+  try {
+    final query = command == 'list' ? <String, String>{} : {'project': arguments[1], 'task': arguments[2]};
+    final result = await sendToRunningBob(command, query);
+    if (result != null) {
+      _print(command, result);
+      return 0;
+    }
+    return await _runStandalone(command, query);
+  } on ControlException catch (e) {
+    stderr.writeln('bob: ${e.message}');
+    return 1;
+  }
+}
+
+/// Ask a bob running headless to show its window. False when none runs
+/// headless, as bobs started with a window of their own don't keep another
+/// from starting.
+Future<bool> attachToRunning() async {
+  try {
+    final file = await ControlServer.controlFile();
+    if (!await file.exists()) {
+      return false;
+    }
+    final control = jsonDecode(await file.readAsString()) as Map<String, dynamic>;
+    if (control['headless'] != true) {
+      return false;
+    }
+    return await sendToRunningBob('show', {}) != null;
+  } on ControlException {
+    return false;
+  } on FormatException {
+    return false;
+  }
+}
+
+/// Send a command to the running bob. Null when bob isn't running.
+Future<Object?> sendToRunningBob(String command, Map<String, String> query) async {
+  final file = await ControlServer.controlFile();
+  if (!await file.exists()) {
+    return null;
+  }
+
+  final client = HttpClient();
+  try {
+    final control = jsonDecode(await file.readAsString()) as Map<String, dynamic>;
+    final (method, segments) = ControlServer.routeOf(command, query);
+    final request = await client.openUrl(
+      method,
+      Uri(
+        scheme: 'http',
+        host: '127.0.0.1',
+        port: control['port'] as int,
+        pathSegments: segments,
+        queryParameters: {
+          for (final MapEntry(:key, :value) in query.entries)
+            if (key != 'project' && key != 'task') key: value,
+        },
+      ),
+    );
+    request.headers.set(HttpHeaders.authorizationHeader, 'Bearer ${control['token']}');
+    final response = await request.close();
+    final body = jsonDecode(await response.transform(utf8.decoder).join());
+    if (response.statusCode != HttpStatus.ok) {
+      throw ControlException((body as Map<String, dynamic>)['error'].toString());
+    }
+    return body;
+  } on SocketException {
+    // Left behind by a bob that didn't close cleanly
+    return null;
+  } on FormatException {
+    return null;
+  } finally {
+    client.close();
+  }
+}
+
+void _print(String command, Object? result) {
+  if (command == 'list') {
+    for (final project in (result as List<dynamic>).cast<Map<String, dynamic>>()) {
+      stdout.writeln('${project['name']}  ${project['path']}');
+      for (final task in (project['tasks'] as List<dynamic>).cast<Map<String, dynamic>>()) {
+        stdout.writeln('  ${task['name']}  ${task['status']}');
+      }
+    }
+    return;
+  }
+
+  final status = result as Map<String, dynamic>;
+  final exitCode = status['exitCode'];
+  stdout.writeln(exitCode != null && status['status'] != 'running'
+      ? '${status['status']} (exit code $exitCode)'
+      : status['status']);
+}
+
+/// Without a running bob, read the projects from the preferences and run
+/// tasks in the foreground
+Future<int> _runStandalone(String command, Map<String, String> query) async {
+  final projectService = ProjectService();
+  final projects = <Project>[];
+  for (final saved in await PreferencesService().getProjects()) {
+    final result = await projectService.reloadProject(saved);
+    if (result.isSuccess) {
+      projects.add(result.data!);
+    }
+  }
+
+  if (command == 'list') {
+    _print(command, [
+      for (final project in projects)
+        {
+          'name': project.name,
+          'path': project.path,
+          'tasks': [
+            for (final task in project.tasks) {'name': task.name, 'status': TaskStatus.idle.name},
+          ],
+        },
+    ]);
+    return 0;
+  }
+
+  final project = ControlServer.matchProject(projects, query['project']!);
+  final task = ControlServer.matchTask(project, query['task']!);
+  switch (command) {
+    case 'stop':
+      throw const ControlException('bob is not running, so no task is');
+    case 'status':
+      _print(command, {'status': TaskStatus.idle.name});
+      return 0;
+  }
+
+  if (task.type == TaskType.group || task.type == TaskType.pipeline) {
+    throw const ControlException('Groups and pipelines only run in the bob window');
+  }
+
+  final taskService = TaskService();
+  final exited = Completer<int>();
+  final interrupt = ProcessSignal.sigint.watch().listen((_) => taskService.stopTask(project, task));
+  await taskService.startTask(
+    project,
+    task,
+    stdout.write,
+    exited.complete,
+    settings: await PreferencesService().getTaskSettings(TaskUtils.getTaskKey(project, task)),
+    onErrorOutput: stderr.write,
+  );
+  final exitCode = await exited.future;
+  await interrupt.cancel();
+  return exitCode < 0 ? 1 : exitCode;
+}
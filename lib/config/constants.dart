@@ -8,6 +8,55 @@ class AppConstants {
   // Process timeouts
   static const Duration processKillGracePeriod = Duration(seconds: 2);
   static const Duration processKillTotalTimeout = Duration(seconds: 5);
+  static const Duration processReconcileInterval = Duration(seconds: 30);
+
+  // CPU and memory sampling of running tasks, and how many samples the sparkline shows
+  static const Duration resourceSampleInterval = Duration(seconds: 2);
+  static const int resourceHistoryLength = 60;
+
+  // Task dependencies that succeeded more recently than this aren't run again
+  static const Duration dependencyFreshness = Duration(minutes: 5);
+
+  // Failed tasks set to retry wait this long before the first retry, doubling after each
+  static const Duration retryBaseDelay = Duration(seconds: 2);
+
+  // Webhooks fired when tasks exit are given up on after this long
+  static const Duration webhookTimeout = Duration(seconds: 10);
+
+  // Commands run when tasks exit are killed after this long
+  static const Duration exitCommandTimeout = Duration(minutes: 1);
+
+  // Git status
+  static const Duration gitStatusRefreshInterval = Duration(seconds: 15);
+  static const Duration gitStatusDebounce = Duration(milliseconds: 300);
+
+  // Devcontainer status
+  static const Duration devcontainerStatusRefreshInterval = Duration(seconds: 15);
+
+  // Tasks that restart on file changes wait for saves to settle
+  static const Duration sourceChangeDebounce = Duration(milliseconds: 500);
+
+  // Errors and warnings of tasks are counted again once output pauses this long
+  static const Duration problemCountDebounce = Duration(milliseconds: 500);
+
+  // Open tabs and the selected task are saved once they stop changing this long
+  static const Duration uiStateSaveDebounce = Duration(milliseconds: 500);
+
+  // Size the window takes as the quick launcher, when summoned while hidden
+  static const double launcherWidth = 560.0;
+  static const double launcherHeight = 420.0;
+
+  // Tasks listed as recently run in the sidebar
+  static const int recentTasksLimit = 5;
+
+  // Removing a project can be undone from its toast for this long
+  static const Duration undoRemoveDuration = Duration(seconds: 10);
+
+  // Port the local control API listens on unless set otherwise
+  static const int controlApiPort = 7430;
+
+  // Lines of output the local API and MCP server return unless asked otherwise
+  static const int controlOutputLines = 200;
 
   // UI constants
   static const double leftPaneMinWidth = 300.0;
@@ -21,6 +70,8 @@ class AppConstants {
   static const String terminalFontFamily = 'Consolas';
   static const List<String> terminalFontFallbacks = ['Consolas', 'Menlo', 'monospace'];
   static const double terminalFontSize = 13.0;
+  static const double terminalMinFontSize = 8.0;
+  static const double terminalMaxFontSize = 32.0;
   static const int maxOutputLines = 10000; // Circular buffer size
   static const Duration autoScrollDuration = Duration(milliseconds: 100);
 
@@ -60,6 +111,17 @@ class AppConstants {
   static const int outputBufferSize = 10000;
   static const int maxLineLength = 5000;
 
+  // Log files: runs kept per task, total size kept per project, and how
+  // much of a task's last run is shown again when bob starts
+  static const int maxLogRunsPerTask = 20;
+  static const int maxProjectLogsBytes = 200 * 1024 * 1024;
+  static const int restoredLogTailBytes = 64 * 1024;
+
+  // Files tasks also write their output to are moved aside at this size,
+  // keeping this many older ones
+  static const int teeFileMaxBytes = 10 * 1024 * 1024;
+  static const int teeFileBackups = 3;
+
   // Error messages
   static const String noDirectorySelectedError = 'No directory selected';
   static const String invalidJsonError = 'Invalid JSON format';
@@ -1,52 +1,157 @@
 import 'dart:io';
 import 'package:flutter/material.dart';
 import 'package:provider/provider.dart';
+import 'package:screen_retriever/screen_retriever.dart';
 import 'package:window_manager/window_manager.dart';
+import 'cli.dart';
+import 'config/constants.dart';
+import 'models/project.dart';
 import 'providers/project_provider.dart';
 import 'screens/home_screen.dart';
+import 'services/app_paths.dart';
+import 'services/control_server.dart';
+import 'services/launcher_hotkey_service.dart';
+import 'services/perf_monitor.dart';
 import 'services/preferences_service.dart';
+import 'widgets/quick_launcher.dart';
+import 'widgets/quit_dialog.dart';
 
 const String appVersion = '0.0.4';
 
 // Global reference for cleanup on app exit
 ProjectProvider? _projectProvider;
 
-void main() async {
+void main(List<String> args) async {
   WidgetsFlutterBinding.ensureInitialized();
 
-  // Initialize window manager to intercept close for cleanup
-  await windowManager.ensureInitialized();
-  await windowManager.setPreventClose(true);
+  // --read-only locks bob into guest mode, e.g. for wall displays
+  final forceReadOnly = args.contains('--read-only');
+
+  // --perf-report measures frames, state updates and task output, and
+  // writes a report on exit
+  if (args.contains('--perf-report')) {
+    PerfMonitor().start();
+  }
+
+  // --portable, or a `portable` file next to the executable, keeps
+  // preferences, bun and logs in a directory beside it. --config-dir and
+  // --data-dir move them anywhere, to run instances of bob side by side.
+  AppPaths.configure(args);
 
   // Initialize preferences service before app starts
   await PreferencesService().initialize();
 
+  // --profile <name> opens a profile other than the one used last
+  final profileIndex = args.indexOf('--profile');
+  if (profileIndex != -1 && profileIndex + 1 < args.length) {
+    final profile = args[profileIndex + 1];
+    await PreferencesService().useProfile(profile == 'Default' ? null : profile);
+  }
+
+  // bob list, bob run and friends answer on the command line, through the
+  // running bob when there is one
+  if (isCliCommand(args)) {
+    exit(await runCli(args));
+  }
+
+  // --headless keeps tasks, watchers and schedules going with the window
+  // hidden, e.g. when started at login. Starting bob again shows the window
+  // of the headless one rather than a second bob. The window is only hidden,
+  // so a display is still needed: without one, `bob run` runs tasks from the
+  // command line on its own.
+  final headless = args.contains('--headless');
+  if (!headless && await attachToRunning()) {
+    exit(0);
+  }
+  ControlServer.headless = headless;
+
+  // Initialize window manager to intercept close for cleanup
+  await windowManager.ensureInitialized();
+  await windowManager.setPreventClose(true);
+  await _restoreWindowBounds();
+  if (headless) {
+    await windowManager.setSkipTaskbar(true);
+    await windowManager.hide();
+  }
+
   // Set up signal handlers for graceful shutdown (Unix only)
   if (!Platform.isWindows) {
     ProcessSignal.sigint.watch().listen((_) => _cleanup());
     ProcessSignal.sigterm.watch().listen((_) => _cleanup());
   }
 
-  runApp(const MainApp());
+  runApp(MainApp(forceReadOnly: forceReadOnly, headless: headless));
+}
+
+/// Put the window back where it was when bob last closed, or centered on
+/// the main screen when the screen it was on is gone
+Future<void> _restoreWindowBounds() async {
+  final saved = await PreferencesService().getWindowBounds();
+  if (saved == null) {
+    return;
+  }
+
+  final (bounds, maximized) = saved;
+  // Enough of the title bar must show to drag the window by
+  final titleBar = Rect.fromLTWH(bounds.left, bounds.top, bounds.width, 32);
+  final displays = await screenRetriever.getAllDisplays();
+  final onScreen = displays.any((display) {
+    final area = (display.visiblePosition ?? Offset.zero) & (display.visibleSize ?? display.size);
+    final visible = area.intersect(titleBar);
+    return visible.width >= 100 && visible.height > 0;
+  });
+
+  if (onScreen) {
+    await windowManager.setBounds(bounds);
+  } else {
+    final primary = await screenRetriever.getPrimaryDisplay();
+    final area = primary.visibleSize ?? primary.size;
+    await windowManager.setSize(Size(
+      bounds.width.clamp(0, area.width),
+      bounds.height.clamp(0, area.height),
+    ));
+    await windowManager.center();
+  }
+  if (maximized) {
+    await windowManager.maximize();
+  }
 }
 
 Future<void> _cleanup() async {
+  await _projectProvider?.shutdown(stopTasks: true);
   _projectProvider?.dispose();
+  await PerfMonitor().dump();
   exit(0);
 }
 
 class MainApp extends StatefulWidget {
-  const MainApp({super.key});
+  final bool forceReadOnly;
+
+  /// Closing the window hides it again rather than quitting
+  final bool headless;
+
+  const MainApp({super.key, this.forceReadOnly = false, this.headless = false});
 
   @override
   State<MainApp> createState() => _MainAppState();
 }
 
 class _MainAppState extends State<MainApp> with WindowListener {
+  final _navigatorKey = GlobalKey<NavigatorState>();
+  bool _quitting = false;
+  bool _launcherOpen = false;
+
+  // Bounds of the window when last neither maximized nor full screen, to
+  // restore it unmaximized at the same place
+  Rect? _normalBounds;
+
   @override
   void initState() {
     super.initState();
     windowManager.addListener(this);
+    LauncherHotkeyService()
+      ..onPressed = _summonLauncher
+      ..apply();
   }
 
   @override
@@ -55,10 +160,124 @@ class _MainAppState extends State<MainApp> with WindowListener {
     super.dispose();
   }
 
+  /// Show the window, asked by a bob started while this one runs
+  Future<void> _attach() async {
+    await windowManager.setSkipTaskbar(false);
+    await windowManager.show();
+    await windowManager.focus();
+  }
+
+  /// Bring up the quick launcher above other apps. A hidden or minimized
+  /// window shrinks to it, and goes back out of sight once a task is picked.
+  Future<void> _summonLauncher() async {
+    final context = _navigatorKey.currentContext;
+    if (context == null || _launcherOpen || _quitting) {
+      return;
+    }
+    _launcherOpen = true;
+
+    final minimized = await windowManager.isMinimized();
+    final wasVisible = await windowManager.isVisible() && !minimized;
+    final bounds = await windowManager.getBounds();
+    final maximized = await windowManager.isMaximized();
+    if (!wasVisible) {
+      if (minimized) {
+        await windowManager.restore();
+      }
+      if (maximized) {
+        await windowManager.unmaximize();
+      }
+      await windowManager.setSize(const Size(AppConstants.launcherWidth, AppConstants.launcherHeight));
+      await windowManager.center();
+    }
+    await windowManager.setAlwaysOnTop(true);
+    await windowManager.show();
+    await windowManager.focus();
+
+    final picked = context.mounted
+        ? await showDialog<(Project, Task)>(context: context, builder: (context) => const QuickLauncher())
+        : null;
+
+    await windowManager.setAlwaysOnTop(false);
+    if (!wasVisible) {
+      await windowManager.setBounds(bounds);
+      if (maximized) {
+        await windowManager.maximize();
+      }
+      if (minimized) {
+        await windowManager.minimize();
+      } else {
+        await windowManager.hide();
+      }
+    }
+    _launcherOpen = false;
+
+    if (picked case (final project, final task)) {
+      final provider = _projectProvider;
+      if (task.status == TaskStatus.running) {
+        provider?.selectTask(task);
+      } else {
+        await provider?.toggleTask(project, task, focus: wasVisible);
+      }
+    }
+  }
+
+  @override
+  void onWindowResized() => _rememberNormalBounds();
+
+  @override
+  void onWindowMoved() => _rememberNormalBounds();
+
+  Future<void> _rememberNormalBounds() async {
+    if (!await windowManager.isMaximized() && !await windowManager.isFullScreen()) {
+      _normalBounds = await windowManager.getBounds();
+    }
+  }
+
+  Future<void> _saveWindowBounds() async {
+    final maximized = await windowManager.isMaximized();
+    final bounds = maximized
+        ? _normalBounds ?? (await PreferencesService().getWindowBounds())?.$1 ?? await windowManager.getBounds()
+        : await windowManager.getBounds();
+    await PreferencesService().setWindowBounds(bounds, maximized);
+  }
+
   @override
   void onWindowClose() async {
-    // Clean up subprocesses before closing
+    if (_quitting) {
+      return;
+    }
+
+    await _saveWindowBounds();
+
+    // Headless, bob runs on until it gets a signal to stop
+    if (widget.headless) {
+      await windowManager.setSkipTaskbar(true);
+      await windowManager.hide();
+      return;
+    }
+
+    // Ask before stopping running tasks, or leaving them orphaned
+    final runningTasks = _projectProvider?.runningTaskCount ?? 0;
+    var choice = QuitChoice.stopTasks;
+    final context = _navigatorKey.currentContext;
+    if (runningTasks > 0 && context != null) {
+      final picked = await showDialog<QuitChoice>(
+        context: context,
+        builder: (context) => QuitDialog(runningTasks: runningTasks),
+      );
+      if (picked == null) {
+        return;
+      }
+      choice = picked;
+    }
+
+    // Clean up subprocesses before closing, out of sight since stopping takes a moment
+    _quitting = true;
+    await windowManager.hide();
+    await _projectProvider?.shutdown(stopTasks: choice == QuitChoice.stopTasks);
     _projectProvider?.dispose();
+    await PerfMonitor().dump();
     await windowManager.destroy();
   }
 
@@ -66,10 +285,11 @@ class _MainAppState extends State<MainApp> with WindowListener {
   Widget build(BuildContext context) {
     return ChangeNotifierProvider(
       create: (_) {
-        _projectProvider = ProjectProvider();
+        _projectProvider = ProjectProvider(forceReadOnly: widget.forceReadOnly)..onAttach = _attach;
         return _projectProvider!;
       },
       child: MaterialApp(
+        navigatorKey: _navigatorKey,
         title: 'Oncle Bob $appVersion',
         themeMode: ThemeMode.system,
         theme: ThemeData(
@@ -0,0 +1,207 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'cli.dart';
+import 'services/control_server.dart';
+import 'services/preferences_service.dart';
+
+// Version of the Model Context Protocol spoken
+const _protocolVersion = '2024-11-05';
+
+// JSON-RPC error codes
+const _methodNotFound = -32601;
+const _invalidParams = -32602;
+const _internalError = -32603;
+
+const _taskArguments = {
+  'project': {'type': 'string', 'description': 'Name or path of the project'},
+  'task': {'type': 'string', 'description': 'Name of the task, e.g. dev'},
+};
+
+const _tools = [
+  {
+    'name': 'list_projects',
+    'description': 'List the projects in bob, with their tasks and whether each runs',
+    'inputSchema': {'type': 'object', 'properties': {}},
+  },
+  {
+    'name': 'start_task',
+    'description': 'Start a task of a project. Does nothing when it already runs.',
+    'inputSchema': {'type': 'object', 'properties': _taskArguments, 'required': ['project', 'task']},
+  },
+  {
+    'name': 'stop_task',
+    'description': 'Stop a running task of a project',
+    'inputSchema': {'type': 'object', 'properties': _taskArguments, 'required': ['project', 'task']},
+  },
+  {
+    'name': 'restart_task',
+    'description': 'Stop a task if it runs, then start it again',
+    'inputSchema': {'type': 'object', 'properties': _taskArguments, 'required': ['project', 'task']},
+  },
+  {
+    'name': 'task_status',
+    'description': 'Tell whether a task runs, and how it last exited',
+    'inputSchema': {'type': 'object', 'properties': _taskArguments, 'required': ['project', 'task']},
+  },
+  {
+    'name': 'read_task_output',
+    'description': 'Read the last lines a task printed, without colors',
+    'inputSchema': {
+      'type': 'object',
+      'properties': {
+        ..._taskArguments,
+        'lines': {'type': 'integer', 'description': 'How many lines to read, 200 by default'},
+      },
+      'required': ['project', 'task'],
+    },
+  },
+];
+
+/// Answer an AI assistant over the Model Context Protocol, on stdin and
+/// stdout, until it goes away. Tools are carried out by the running bob,
+/// and only when assistants are allowed in its settings.
+Future<int> runMcpServer() async {
+  if (!await PreferencesService().getMcpServer()) {
+    stderr.writeln('bob: Allow AI assistants in the settings of bob first');
+    return 1;
+  }
+
+  await for (final line in stdin.transform(utf8.decoder).transform(const LineSplitter())) {
+    if (line.trim().isEmpty) continue;
+
+    Map<String, dynamic> message;
+    try {
+      message = jsonDecode(line) as Map<String, dynamic>;
+    } catch (e) {
+      _send({
+        'jsonrpc': '2.0',
+        'id': null,
+        'error': {'code': -32700, 'message': 'Parse error'},
+      });
+      continue;
+    }
+
+    // Notifications get no answer
+    final id = message['id'];
+    if (id == null) continue;
+
+    try {
+      final result = await _handle(message['method'] as String?, message['params'] as Map<String, dynamic>? ?? {});
+      _send({'jsonrpc': '2.0', 'id': id, 'result': result});
+    } on _RpcError catch (e) {
+      _send({
+        'jsonrpc': '2.0',
+        'id': id,
+        'error': {'code': e.code, 'message': e.message},
+      });
+    } catch (e) {
+      // A malformed request or a bob going away mid-call fails this message
+      // only, the next ones still get answers
+      _send({
+        'jsonrpc': '2.0',
+        'id': id,
+        'error': {'code': _internalError, 'message': e.toString()},
+      });
+    }
+  }
+  return 0;
+}
+
+class _RpcError implements Exception {
+  final int code;
+  final String message;
+
+  const _RpcError(this.code, this.message);
+}
+
+void _send(Map<String, dynamic> message) {
+  stdout.writeln(jsonEncode(message));
+}
+
+Future<Object?> _handle(String? method, Map<String, dynamic> params) async {
+  switch (method) {
+    case 'initialize':
+      return {
+        'protocolVersion': _protocolVersion,
+        'capabilities': {'tools': {}},
+        'serverInfo': {'name': 'bob', 'version': '1.0.0'},
+      };
+    case 'ping':
+      return {};
+    case 'tools/list':
+      return {'tools': _tools};
+    case 'tools/call':
+      final name = params['name'];
+      if (!_tools.any((tool) => tool['name'] == name)) {
+        throw _RpcError(_invalidParams, 'Unknown tool $name');
+      }
+      final arguments = (params['arguments'] as Map<String, dynamic>? ?? {})
+          .map((key, value) => MapEntry(key, value.toString()));
+      try {
+        final result = await _callTool(name as String, arguments);
+        return {
+          'content': [
+            {'type': 'text', 'text': result},
+          ],
+        };
+      } on ControlException catch (e) {
+        // Failed tools are told to the assistant rather than as protocol errors
+        return {
+          'content': [
+            {'type': 'text', 'text': e.message},
+          ],
+          'isError': true,
+        };
+      }
+    default:
+      throw _RpcError(_methodNotFound, 'Unknown method $method');
+  }
+}
+
+Future<String> _callTool(String name, Map<String, String> arguments) async {
+  if (name != 'list_projects' && (arguments['project'] == null || arguments['task'] == null)) {
+    throw const ControlException('Give the project and the task');
+  }
+
+  if (name == 'restart_task') {
+    await _forward('stop', arguments);
+    return _describe(await _forward('run', arguments));
+  }
+
+  final command = switch (name) {
+    'list_projects' => 'list',
+    'start_task' => 'run',
+    'stop_task' => 'stop',
+    'task_status' => 'status',
+    _ => 'output',
+  };
+  final result = await _forward(command, arguments);
+  return switch (command) {
+    'list' => const JsonEncoder.withIndent('  ').convert(result),
+    'output' => _describeOutput(result as Map<String, dynamic>),
+    _ => _describe(result),
+  };
+}
+
+/// Send a command to the running bob, which runs the tasks
+Future<Object?> _forward(String command, Map<String, String> arguments) async {
+  final result = await sendToRunningBob(command, arguments);
+  if (result == null) {
+    throw const ControlException('bob is not running. Start it to control tasks.');
+  }
+  return result;
+}
+
+String _describe(Object? result) {
+  final status = result as Map<String, dynamic>;
+  final exitCode = status['exitCode'];
+  return exitCode != null && status['status'] != 'running'
+      ? '${status['status']} (exit code $exitCode)'
+      : status['status'] as String;
+}
+
+String _describeOutput(Map<String, dynamic> result) {
+  final output = result['output'] as String;
+  return '${_describe(result)}\n\n${output.isEmpty ? '(no output)' : output}';
+}
@@ -0,0 +1,16 @@
+/// An installed dependency that declares lifecycle scripts run at install time
+class InstallScriptDependency {
+  final String name;
+
+  /// Lifecycle script names and commands, e.g. postinstall: node install.js
+  final Map<String, String> scripts;
+
+  /// Listed in the project's trustedDependencies, so bun runs its scripts
+  final bool trusted;
+
+  const InstallScriptDependency({
+    required this.name,
+    required this.scripts,
+    required this.trusted,
+  });
+}
@@ -0,0 +1,20 @@
+/// A dependency declared in a project's package.json
+class PackageDependency {
+  final String name;
+
+  /// Version range from package.json, e.g. ^18.2.0
+  final String requested;
+
+  /// Version actually installed, null when it isn't
+  final String? installed;
+
+  /// Declared in devDependencies rather than dependencies
+  final bool isDev;
+
+  const PackageDependency({
+    required this.name,
+    required this.requested,
+    required this.installed,
+    required this.isDev,
+  });
+}
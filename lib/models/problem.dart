@@ -0,0 +1,31 @@
+/// An error or warning a compiler reported at a location in a file
+class Problem {
+  /// Path as the tool printed it, relative to where the task runs or absolute
+  final String file;
+  final int line;
+  final int? column;
+  final String message;
+  final bool isWarning;
+
+  const Problem({
+    required this.file,
+    required this.line,
+    this.column,
+    required this.message,
+    this.isWarning = false,
+  });
+
+  String get location => column == null ? '$file:$line' : '$file:$line:$column';
+
+  @override
+  bool operator ==(Object other) =>
+      other is Problem &&
+      other.file == file &&
+      other.line == line &&
+      other.column == column &&
+      other.message == message &&
+      other.isWarning == isWarning;
+
+  @override
+  int get hashCode => Object.hash(file, line, column, message, isWarning);
+}
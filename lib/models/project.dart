@@ -11,6 +11,30 @@ enum TaskType {
   script,
   create,
   install,
+  group,
+  pipeline,
+  make,
+  procfile,
+  python,
+  taskfile,
+  custom,
+}
+
+/// When dependencies get installed before running a script
+enum InstallPolicy {
+  /// Before every run, unless an install just succeeded
+  always('Always'),
+
+  /// When package.json or the lockfile changed since the last install, or
+  /// nothing is installed
+  lockfileChanged('If lockfile changed'),
+
+  /// Only when the install task is run
+  never('Never');
+
+  final String displayName;
+
+  const InstallPolicy(this.displayName);
 }
 
 enum LaunchStepStatus {
@@ -103,6 +127,50 @@ class Site {
     );
   }
 }
+/// Where the tasks of a project run in Docker, from bob.docker: a new
+/// container of [image] for each run, the running [container], or the
+/// project's devcontainer
+class DockerConfig {
+  final String? image;
+  final String? container;
+
+  /// Run in the container of .devcontainer/devcontainer.json, started when
+  /// a task needs it
+  final bool devcontainer;
+
+  /// Where the project folder is in the container
+  final String workdir;
+
+  static const defaultWorkdir = '/workspace';
+
+  const DockerConfig({this.image, this.container, this.devcontainer = false, this.workdir = defaultWorkdir});
+
+  /// Null when neither an image, a container nor the devcontainer is set
+  static DockerConfig? fromJson(Map<String, dynamic>? json) {
+    final image = json?['image'] as String?;
+    final container = json?['container'] as String?;
+    final devcontainer = json?['devcontainer'] as bool? ?? false;
+    if ((image == null || image.isEmpty) && (container == null || container.isEmpty) && !devcontainer) {
+      return null;
+    }
+    return DockerConfig(
+      image: image,
+      container: container,
+      devcontainer: devcontainer,
+      workdir: json?['workdir'] as String? ?? defaultWorkdir,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    if (devcontainer) {
+      return {'devcontainer': true};
+    }
+    return {
+      if (container != null) 'container': container else 'image': image,
+      if (workdir != defaultWorkdir) 'workdir': workdir,
+    };
+  }
+}
 
 class Project {
   final String name;
@@ -111,13 +179,83 @@ class Project {
   final List<Site> sites;
   final String? launchDirectory;
 
+  /// Install without updating the lockfile, failing when it's out of date
+  final bool frozenLockfile;
+
+  /// Install without running lifecycle scripts of the project or its dependencies
+  final bool ignoreScripts;
+
+  /// When to install before running a script
+  final InstallPolicy installPolicy;
+
+  /// Environment variables for tasks, overriding those inherited from workspaces
+  final Map<String, String> environment;
+
+  /// Names of environment variables whose values are kept in the keychain
+  /// of the OS, and only given to tasks when they start
+  final List<String> secretEnvironment;
+
+  /// Read .env, .env.local and [envFiles] into the environment of tasks
+  final bool loadEnvFiles;
+
+  /// Env files read after .env and .env.local, relative to the project folder
+  final List<String> envFiles;
+
+  /// Globs of files whose changes count, empty for all of them
+  final List<String> watchInclude;
+
+  /// Globs of files whose changes are ignored, besides node_modules and .git
+  final List<String> watchExclude;
+
+  /// Also ignore changes to files matched by the project's .gitignore
+  final bool watchGitignore;
+
+  /// SSH host of a remote project, whose tasks run there. Its [path] is then
+  /// `host:/path/on/host`, like scp writes it.
+  final String? host;
+
+  /// Run tasks in Docker instead of on this computer
+  final DockerConfig? docker;
+
+  /// Whether the project has a .devcontainer/devcontainer.json to run its
+  /// tasks in
+  final bool hasDevcontainer;
+
+  /// Run tasks in WSL with a Linux bun, on Windows. By default for projects
+  /// kept in a WSL distribution.
+  final bool runInWsl;
+
+  /// WSL distribution to run tasks in, when not the one the project is in
+  /// or the default one
+  final String? wslDistro;
+
   Project({
     required this.name,
     required String path,
     required this.tasks,
     this.sites = const [],
     this.launchDirectory,
-  }) : path = _normalizePath(path);
+    this.frozenLockfile = false,
+    this.ignoreScripts = false,
+    this.installPolicy = InstallPolicy.never,
+    this.environment = const {},
+    this.secretEnvironment = const [],
+    this.loadEnvFiles = true,
+    this.envFiles = const [],
+    this.watchInclude = const [],
+    this.watchExclude = const [],
+    this.watchGitignore = true,
+    this.host,
+    this.docker,
+    this.hasDevcontainer = false,
+    this.runInWsl = false,
+    this.wslDistro,
+  }) : path = host != null ? path : _normalizePath(path);
+
+  bool get isRemote => host != null;
+
+  /// Folder of a remote project on its host
+  String get remotePath => path.substring(host!.length + 1);
 
   /// Normalize path and remove trailing separators
   static String _normalizePath(String path) {
@@ -130,7 +268,13 @@ class Project {
     return normalized;
   }
 
-  factory Project.fromPackageJson(String path, Map<String, dynamic> json) {
+  factory Project.fromPackageJson(
+    String path,
+    Map<String, dynamic> json, {
+    String? host,
+    bool hasDevcontainer = false,
+    bool isWslPath = false,
+  }) {
     final name = json['name'] as String? ?? 'Unnamed Project';
     final scripts = json['scripts'] as Map<String, dynamic>? ?? {};
 
@@ -142,25 +286,63 @@ class Project {
     // Create install task only if there are dependencies
     final List<Task> tasks = [];
 
+    // Load launch configuration from bob
+    final bob = json['bob'] as Map<String, dynamic>?;
+    final frozenLockfile = bob?['frozenLockfile'] as bool? ?? false;
+    final ignoreScripts = bob?['ignoreScripts'] as bool? ?? false;
+    final installPolicy = InstallPolicy.values.where((policy) => policy.name == bob?['install']).firstOrNull ??
+        InstallPolicy.never;
+
     if (hasDependencies) {
       tasks.add(Task(
         name: 'install',
-        command: 'bun install',
+        command: [
+          'bun install',
+          if (frozenLockfile) '--frozen-lockfile',
+          if (ignoreScripts) '--ignore-scripts',
+        ].join(' '),
         type: TaskType.install,
       ));
     }
 
+    // Scripts expanded into groups from bob.groups, keeping children that still exist
+    final groups = bob?['groups'] as Map<String, dynamic>? ?? {};
+
+    // Scripts to run before others from bob.dependsOn
+    final dependencies = bob?['dependsOn'] as Map<String, dynamic>? ?? {};
+
+    // Descriptions from scripts-info, as npm-scripts-info reads them, overridden by bob.descriptions
+    final descriptions = {
+      if (json['scripts-info'] case final Map<String, dynamic> info) ...info,
+      if (bob?['descriptions'] case final Map<String, dynamic> bobDescriptions) ...bobDescriptions,
+    }.map((key, value) => MapEntry(key, value.toString()));
+
+    // Display names from bob.aliases, the script names stay as they are
+    final aliases = (bob?['aliases'] as Map<String, dynamic>? ?? {})
+        .map((key, value) => MapEntry(key, value.toString()));
+
     // Add script tasks
     tasks.addAll(
-      scripts.entries.map((entry) => Task(
-        name: entry.key,
-        command: entry.value as String,
-      )),
+      scripts.entries.map((entry) {
+        final children = (groups[entry.key] as List<dynamic>? ?? [])
+            .whereType<String>()
+            .where((child) => child != entry.key && scripts.containsKey(child))
+            .toList();
+        return Task(
+          name: entry.key,
+          command: entry.value as String,
+          type: children.isEmpty ? TaskType.script : TaskType.group,
+          children: children,
+          dependsOn: (dependencies[entry.key] as List<dynamic>? ?? [])
+              .whereType<String>()
+              .where((dependency) => dependency != entry.key && scripts.containsKey(dependency))
+              .toList(),
+          description: descriptions[entry.key],
+          alias: aliases[entry.key],
+        );
+      }),
     );
 
-    // Load launch configuration from bob
-    final bob = json['bob'] as Map<String, dynamic>?;
-
     // Load sites from bob.sites
     final sm = bob?['sites'] as Map<String, dynamic>? ?? {};
     final sites = sm.entries
@@ -181,15 +363,60 @@ class Project {
         })
         .toList();
 
+    // Add pipelines from bob.pipelines, unless a script already has the name
+    final pipelines = bob?['pipelines'] as Map<String, dynamic>? ?? {};
+    for (final entry in pipelines.entries) {
+      if (scripts.containsKey(entry.key)) {
+        continue;
+      }
+      final steps = (entry.value as List<dynamic>? ?? [])
+          .whereType<String>()
+          .where((step) => scripts.containsKey(step))
+          .toList();
+      tasks.add(Task(
+        name: entry.key,
+        command: steps.join(' → '),
+        type: TaskType.pipeline,
+        children: steps,
+        description: descriptions[entry.key],
+        alias: aliases[entry.key],
+      ));
+    }
+
     // Load launch directory from bob.directory
     final launchDirectory = bob?['directory'] as String?;
 
+    // Load environment variables from bob.env
+    final environment = (bob?['env'] as Map<String, dynamic>? ?? {})
+        .map((key, value) => MapEntry(key, value.toString()));
+    final secretEnvironment = (bob?['secretEnv'] as List<dynamic>? ?? []).whereType<String>().toList();
+    final loadEnvFiles = bob?['loadEnvFiles'] as bool? ?? true;
+    final envFiles = (bob?['envFiles'] as List<dynamic>? ?? []).whereType<String>().toList();
+
+    // Load file watching configuration from bob.watch
+    final watch = bob?['watch'] as Map<String, dynamic>? ?? {};
+
     return Project(
       name: name,
       path: path,
       tasks: tasks,
       sites: sites,
       launchDirectory: launchDirectory,
+      frozenLockfile: frozenLockfile,
+      ignoreScripts: ignoreScripts,
+      installPolicy: installPolicy,
+      environment: environment,
+      secretEnvironment: secretEnvironment,
+      loadEnvFiles: loadEnvFiles,
+      envFiles: envFiles,
+      watchInclude: (watch['include'] as List<dynamic>? ?? []).whereType<String>().toList(),
+      watchExclude: (watch['exclude'] as List<dynamic>? ?? []).whereType<String>().toList(),
+      watchGitignore: watch['gitignore'] as bool? ?? true,
+      host: host,
+      docker: DockerConfig.fromJson(bob?['docker'] as Map<String, dynamic>?),
+      hasDevcontainer: hasDevcontainer,
+      runInWsl: bob?['wsl'] as bool? ?? isWslPath,
+      wslDistro: bob?['wslDistro'] as String?,
     );
   }
 
@@ -197,6 +424,7 @@ class Project {
     return {
       'name': name,
       'path': path,
+      if (host != null) 'host': host,
     };
   }
 
@@ -205,6 +433,7 @@ class Project {
       name: json['name'] as String,
       path: json['path'] as String,
       tasks: [], // Tasks will be loaded from package.json when needed
+      host: json['host'] as String?,
     );
   }
 }
@@ -217,6 +446,28 @@ class Task {
   final int? lastExitCode;
   final String output;
 
+  /// Scripts started and stopped together by a group task, or run one
+  /// after the other by a pipeline
+  final List<String> children;
+
+  /// Scripts that run, in order, before this task starts
+  final List<String> dependsOn;
+
+  /// What the task does, in words, from scripts-info or bob.descriptions
+  final String? description;
+
+  /// Name shown instead of [name], from bob.aliases
+  final String? alias;
+
+  /// Asked to stop and given the grace period to exit before being killed
+  final bool isStopping;
+
+  /// When the current or last run started
+  final DateTime? startedAt;
+
+  /// How long the last finished run took
+  final Duration? lastDuration;
+
   Task({
     required this.name,
     required this.command,
@@ -224,8 +475,18 @@ class Task {
     this.status = TaskStatus.idle,
     this.lastExitCode,
     this.output = '',
+    this.children = const [],
+    this.dependsOn = const [],
+    this.description,
+    this.alias,
+    this.isStopping = false,
+    this.startedAt,
+    this.lastDuration,
   });
 
+  /// What the task is called in the UI
+  String get displayName => alias ?? name;
+
   /// Create a copy of this task with updated fields
   Task copyWith({
     String? name,
@@ -234,6 +495,13 @@ class Task {
     TaskStatus? status,
     int? lastExitCode,
     String? output,
+    List<String>? children,
+    List<String>? dependsOn,
+    String? description,
+    String? alias,
+    bool? isStopping,
+    DateTime? startedAt,
+    Duration? lastDuration,
   }) {
     return Task(
       name: name ?? this.name,
@@ -242,6 +510,13 @@ class Task {
       status: status ?? this.status,
       lastExitCode: lastExitCode ?? this.lastExitCode,
       output: output ?? this.output,
+      children: children ?? this.children,
+      dependsOn: dependsOn ?? this.dependsOn,
+      description: description ?? this.description,
+      alias: alias ?? this.alias,
+      isStopping: isStopping ?? this.isStopping,
+      startedAt: startedAt ?? this.startedAt,
+      lastDuration: lastDuration ?? this.lastDuration,
     );
   }
 }
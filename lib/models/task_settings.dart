@@ -0,0 +1,185 @@
+/// What an [ExitAction] does
+enum ExitActionKind {
+  /// POST a JSON description of the run to a URL
+  webhook,
+
+  /// Run a shell command from the project folder
+  command,
+}
+
+/// Which exits an [ExitAction] fires on
+enum ExitTrigger { success, failure, always }
+
+/// Something done outside bob when a task exits on its own, e.g. posting to
+/// Slack or triggering a deploy. The target can hold `{project}`, `{path}`,
+/// `{task}`, `{status}`, `{exitCode}` and `{duration}`, URL-encoded in
+/// webhooks and read from quoted BOB_* environment variables by commands.
+class ExitAction {
+  final ExitActionKind kind;
+  final ExitTrigger trigger;
+
+  /// URL of a webhook, or the command to run
+  final String target;
+
+  const ExitAction({
+    required this.kind,
+    required this.trigger,
+    required this.target,
+  });
+
+  bool firesOn(int exitCode) =>
+      trigger == ExitTrigger.always || (exitCode == 0) == (trigger == ExitTrigger.success);
+
+  Map<String, dynamic> toJson() {
+    return {'kind': kind.name, 'trigger': trigger.name, 'target': target};
+  }
+
+  factory ExitAction.fromJson(Map<String, dynamic> json) {
+    return ExitAction(
+      kind: ExitActionKind.values.asNameMap()[json['kind']] ?? ExitActionKind.command,
+      trigger: ExitTrigger.values.asNameMap()[json['trigger']] ?? ExitTrigger.always,
+      target: json['target'] as String? ?? '',
+    );
+  }
+}
+
+/// Per-task run configuration, stored in preferences by task key
+class TaskSettings {
+  /// CPU cores the task is pinned to, empty to use all cores
+  final List<int> cpuCores;
+
+  /// Run the task with raised scheduling priority
+  final bool highPriority;
+
+  /// Task of the same project to run when this one exits with code 0
+  final String? onSuccess;
+
+  /// Task of the same project to run when this one exits with another code
+  final String? onFailure;
+
+  /// Directory the task runs from, relative to the project folder
+  final String? workingDirectory;
+
+  /// Restart the task when files of the project change
+  final bool restartOnChange;
+
+  /// Append the output of each run to that of previous runs instead of
+  /// clearing it when the task starts
+  final bool keepOutput;
+
+  /// Times a run that fails is started again, waiting twice as long before
+  /// each attempt
+  final int maxRetries;
+
+  /// Webhooks and commands fired when the task exits on its own
+  final List<ExitAction> exitActions;
+
+  /// Shell command run before the task starts, which doesn't start when
+  /// it fails, e.g. to bring up a database
+  final String? preRun;
+
+  /// Shell command run after the task stops, however it stops
+  final String? postRun;
+
+  /// File the output is also written to, relative to the project folder,
+  /// moved aside when it grows large
+  final String? teeFile;
+
+  const TaskSettings({
+    this.cpuCores = const [],
+    this.highPriority = false,
+    this.onSuccess,
+    this.onFailure,
+    this.workingDirectory,
+    this.restartOnChange = false,
+    this.keepOutput = false,
+    this.maxRetries = 0,
+    this.exitActions = const [],
+    this.preRun,
+    this.postRun,
+    this.teeFile,
+  });
+
+  bool get isDefault =>
+      cpuCores.isEmpty &&
+      !highPriority &&
+      onSuccess == null &&
+      onFailure == null &&
+      workingDirectory == null &&
+      !restartOnChange &&
+      !keepOutput &&
+      maxRetries == 0 &&
+      exitActions.isEmpty &&
+      preRun == null &&
+      postRun == null &&
+      teeFile == null;
+
+  TaskSettings copyWith({
+    List<int>? cpuCores,
+    bool? highPriority,
+    String? onSuccess,
+    String? onFailure,
+    String? workingDirectory,
+    bool? restartOnChange,
+    bool? keepOutput,
+    int? maxRetries,
+    List<ExitAction>? exitActions,
+    String? preRun,
+    String? postRun,
+    String? teeFile,
+  }) {
+    return TaskSettings(
+      cpuCores: cpuCores ?? this.cpuCores,
+      highPriority: highPriority ?? this.highPriority,
+      onSuccess: onSuccess ?? this.onSuccess,
+      onFailure: onFailure ?? this.onFailure,
+      workingDirectory: workingDirectory ?? this.workingDirectory,
+      restartOnChange: restartOnChange ?? this.restartOnChange,
+      keepOutput: keepOutput ?? this.keepOutput,
+      maxRetries: maxRetries ?? this.maxRetries,
+      exitActions: exitActions ?? this.exitActions,
+      preRun: preRun ?? this.preRun,
+      postRun: postRun ?? this.postRun,
+      teeFile: teeFile ?? this.teeFile,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      if (cpuCores.isNotEmpty) 'cpuCores': cpuCores,
+      if (highPriority) 'highPriority': true,
+      if (onSuccess != null) 'onSuccess': onSuccess,
+      if (onFailure != null) 'onFailure': onFailure,
+      if (workingDirectory != null) 'workingDirectory': workingDirectory,
+      if (restartOnChange) 'restartOnChange': true,
+      if (keepOutput) 'keepOutput': true,
+      if (maxRetries > 0) 'maxRetries': maxRetries,
+      if (exitActions.isNotEmpty) 'exitActions': [for (final action in exitActions) action.toJson()],
+      if (preRun != null) 'preRun': preRun,
+      if (postRun != null) 'postRun': postRun,
+      if (teeFile != null) 'teeFile': teeFile,
+    };
+  }
+
+  factory TaskSettings.fromJson(Map<String, dynamic> json) {
+    return TaskSettings(
+      cpuCores: (json['cpuCores'] as List<dynamic>? ?? [])
+          .whereType<int>()
+          .toList(),
+      highPriority: json['highPriority'] as bool? ?? false,
+      onSuccess: json['onSuccess'] as String?,
+      onFailure: json['onFailure'] as String?,
+      workingDirectory: json['workingDirectory'] as String?,
+      restartOnChange: json['restartOnChange'] as bool? ?? false,
+      keepOutput: json['keepOutput'] as bool? ?? false,
+      maxRetries: json['maxRetries'] as int? ?? 0,
+      exitActions: (json['exitActions'] as List<dynamic>? ?? [])
+          .whereType<Map<String, dynamic>>()
+          .map(ExitAction.fromJson)
+          .toList(),
+      preRun: json['preRun'] as String?,
+      postRun: json['postRun'] as String?,
+      teeFile: json['teeFile'] as String?,
+    );
+  }
+}
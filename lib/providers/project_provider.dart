@@ -1,15 +1,41 @@
 import 'dart:async';
+import 'dart:collection';
 import 'dart:io';
+import 'dart:math';
 import 'package:flutter/foundation.dart';
 import 'package:path/path.dart' as path;
 import '../config/constants.dart';
+import '../models/problem.dart';
 import '../models/project.dart';
 import '../models/project_template.dart';
+import '../models/task_settings.dart';
+import '../services/binary_manager.dart';
+import '../services/control_server.dart';
+import '../services/devcontainer_service.dart';
+import '../services/editor_service.dart';
+import '../services/exit_action_service.dart';
+import '../services/git_service.dart';
 import '../services/preferences_service.dart';
+import '../services/resource_monitor.dart';
+import '../services/secret_store.dart';
 import '../services/project_service.dart';
 import '../services/task_service.dart';
 import '../services/launch_service.dart';
+import '../services/log_file_service.dart';
+import '../services/perf_monitor.dart';
+import '../services/source_watcher.dart';
+import '../utils/ansi_parser.dart';
 import '../utils/circular_buffer.dart';
+import '../utils/env_utils.dart';
+import '../utils/log_level_utils.dart';
+import '../utils/makefile_utils.dart';
+import '../utils/problem_matcher.dart';
+import '../utils/procfile_utils.dart';
+import '../utils/process_utils.dart';
+import '../utils/pyproject_utils.dart';
+import '../utils/registry_utils.dart';
+import '../utils/secret_masker.dart';
+import '../utils/taskfile_utils.dart';
 import '../utils/task_utils.dart';
 
 class ProjectProvider with ChangeNotifier {
@@ -17,19 +43,75 @@ class ProjectProvider with ChangeNotifier {
   final PreferencesService _preferencesService = PreferencesService();
   final TaskService _taskService = TaskService();
   final LaunchService _launchService = LaunchService();
+  final LogFileService _logFileService = LogFileService();
+  final GitService _gitService = GitService();
+  final DevcontainerService _devcontainerService = DevcontainerService();
+  final EditorService _editorService = EditorService();
+  final ExitActionService _exitActionService = ExitActionService();
+  final SecretStore _secretStore = SecretStore();
+
+  List<Project> _projectList = [];
+
+  // What is shown of the active profile, started over when switching
+  _ProfileState _state = _ProfileState();
+
+  // Saves the open tabs and the selected task once they settle
+  Timer? _uiStateSaveTimer;
+
+  // Whether the split task is stacked under the selected one
+  bool _splitVertically = false;
 
-  List<Project> _projects = [];
-  Task? _selectedTask;
-  Site? _selectedLaunch;
-  bool _showingCreationForm = false;
-  Project? _configuringProject;
-  Project? _creatingLaunchFor;
   bool _showingSettings = false;
   double _leftPaneWidth = AppConstants.leftPaneDefaultWidth;
   bool _isLoadingProjects = true;
 
-  // Circular buffers for task output to prevent memory issues
-  final Map<String, CircularBuffer> _taskOutputBuffers = {};
+  // Projects whose tasks are hidden in the sidebar, by path
+  Set<String> _collapsedProjects = {};
+  Map<String, String> _projectIcons = {};
+  List<String> _favoriteTasks = [];
+  List<String> _recentTasks = [];
+
+  // Read-only mode shows projects and logs but disables start/stop/remove.
+  // When forced from the command line it can't be turned off from the UI.
+  final bool _forceReadOnly;
+  bool _readOnly = false;
+
+  // Answers the command line while bob runs
+  late final ControlServer _controlServer = ControlServer(_handleControlCommand);
+
+  /// Shows the window when another bob starts, set by the app
+  VoidCallback? onAttach;
+
+  // Output of tasks as it arrives, for viewers outside the window, by task key
+  final Map<String, StreamController<String>> _outputWatchers = {};
+
+  // Named profiles besides the default one, each with its own projects and settings
+  List<String> _profiles = [];
+
+  // Hides secrets in logs, and the secrets of env files read when tasks start
+  SecretMasker _secretMasker = SecretMasker.none;
+  final Map<String, Iterable<String>> _envFileSecrets = {};
+
+  // Font of log views, the family is null for the default
+  double _logFontSize = AppConstants.terminalFontSize;
+  String? _logFontFamily;
+
+  // Tasks between the start click and their process being spawned,
+  // skipped by reconciliation since they have no process yet
+  final Set<String> _startingTaskKeys = {};
+  Timer? _reconcileTimer;
+
+  // Open log files for running tasks when log persistence is enabled
+  final Map<String, IOSink> _logSinks = {};
+
+  // Files of the user's choosing running tasks also write to, by task key
+  final Map<String, RotatingLogWriter> _teeWriters = {};
+
+  // Files followed by tasks popped out into their own window, by task key
+  final Map<String, IOSink> _popOutSinks = {};
+
+  // Hides secrets in output before it leaves bob, by task key
+  final Map<String, LineSecretMasker> _sinkMaskers = {};
 
   // Track projects currently being removed to prevent concurrent removal
   final Set<String> _projectsBeingRemoved = {};
@@ -37,12 +119,72 @@ class ProjectProvider with ChangeNotifier {
   // Track projects currently being added to prevent concurrent addition
   final Set<String> _projectsBeingAdded = {};
 
-  // Track import errors for projects being added
-  Map<String, String> _importErrors = {};
+  // Track projects being created. Unlike imports these stay visible in the
+  // list so the create task's output can be followed.
+  final Set<String> _projectsBeingCreated = {};
+
+  // Latest bun download state; cleared once bun is ready
+  BunDownloadEvent? _bunDownload;
+  StreamSubscription<BunDownloadEvent>? _bunDownloadSubscription;
+
+  // Dependency and pipeline runs: exits awaited by dependents, output
+  // forwarded to them, and when each task last succeeded, all by task key
+  final Map<String, Completer<int>> _taskExitWaiters = {};
+  final Map<String, void Function(String)> _forwardedOutputListeners = {};
+  final Map<String, DateTime> _taskSucceededAt = {};
+  final Set<String> _resolvingDependencies = {};
+
+  // Runs made of failed tasks set to retry, and the retries waiting for
+  // their backoff to pass, by task key
+  final Map<String, int> _retryAttempts = {};
+  final Map<String, Timer> _retryTimers = {};
+
+  // Running pipelines and the step each is on, and the ones asked to stop
+  final Map<String, String> _pipelineSteps = {};
+  final Set<String> _cancelledPipelines = {};
+
+  // CPU and memory of running tasks by task key, with recent samples for sparklines
+  final ResourceMonitor _resourceMonitor = ResourceMonitor();
+  Map<String, ProcessUsage> _taskUsage = {};
+  final Map<String, List<ProcessUsage>> _taskUsageHistory = {};
+  Timer? _usageTimer;
+  bool _samplingUsage = false;
+
+  // Dev server URL found in the output of running tasks, by task key
+  Map<String, String> _serverUrls = {};
+
+  // Installs a registry refused credentials to, by task key, with the
+  // registry configuration to fix them in when one was found
+  Map<String, String?> _registryAuthFailures = {};
+
+  // Counts errors and warnings a moment after output changes rather than
+  // on every chunk
+  Timer? _problemCountTimer;
+
+  // Run configuration per task key, loaded from preferences at startup
+  Map<String, TaskSettings> _taskSettings = {};
 
   // File watchers for package.json changes
   final Map<String, StreamSubscription<FileSystemEvent>> _packageJsonWatchers = {};
 
+  // Projects the watchers were last synced with
+  Set<String> _watchedProjectPaths = {};
+
+  // Git status per project path, refreshed periodically and when the git directory changes
+  Map<String, GitStatus> _gitStatuses = {};
+  final Map<String, StreamSubscription<FileSystemEvent>> _gitWatchers = {};
+  final Map<String, Timer> _gitRefreshDebounces = {};
+  Timer? _gitRefreshTimer;
+
+  // Devcontainer state per project path, refreshed periodically and when tasks start and exit
+  Map<String, DevcontainerStatus> _devcontainerStatuses = {};
+  Timer? _devcontainerRefreshTimer;
+
+  // Source tree watchers of projects with a task that restarts on file changes
+  final Map<String, SourceWatcher> _sourceWatchers = {};
+  final Map<String, Timer> _sourceChangeDebounces = {};
+  final Map<String, Set<String>> _pendingSourceChanges = {};
+
   // Team selection state for launch
   Completer<TeamSelectionResult>? _pendingTeamSelection;
   List<Team> _availableTeams = [];
@@ -57,27 +199,419 @@ class ProjectProvider with ChangeNotifier {
   Site? _pendingLaunchSite;
 
   List<Project> get projects => _projects;
+
+  // Selecting a task opens it in a tab
+  Task? get _selectedTask => _state.selectedTask;
+  set _selectedTask(Task? task) {
+    _state.selectedTask = task;
+    final project = task == null ? null : projectForTask(task);
+    if (project != null && !_openTabs.contains((project.path, task!.name))) {
+      _openTabs = [..._openTabs, (project.path, task.name)];
+    }
+    _scheduleUiStateSave();
+  }
+
+  List<(String, String)> get _openTabs => _state.openTabs;
+  set _openTabs(List<(String, String)> tabs) {
+    _state.openTabs = tabs;
+    _scheduleUiStateSave();
+  }
+
+  // Every change to the project list goes through here, so watchers follow
+  // projects being added, removed or replaced
+  List<Project> get _projects => _projectList;
+  set _projects(List<Project> projects) {
+    _projectList = projects;
+    _syncWatchers();
+  }
   Task? get selectedTask => _selectedTask;
-  Site? get selectedLaunch => _selectedLaunch;
-  bool get showingCreationForm => _showingCreationForm;
-  Project? get configuringProject => _configuringProject;
-  Project? get creatingLaunchFor => _creatingLaunchFor;
+  Site? get selectedLaunch => _state.selectedLaunch;
+  bool get showingCreationForm => _state.showingCreationForm;
+  Project? get configuringProject => _state.configuringProject;
+  Project? get creatingLaunchFor => _state.creatingLaunchFor;
+  Project? get browsingLogsFor => _state.browsingLogsFor;
+  Project? get browsingDependenciesFor => _state.browsingDependenciesFor;
   bool get showingSettings => _showingSettings;
   double get leftPaneWidth => _leftPaneWidth;
   bool get isLoadingProjects => _isLoadingProjects;
+  bool get isReadOnly => _forceReadOnly || _readOnly;
+  bool get isReadOnlyForced => _forceReadOnly;
+  double get logFontSize => _logFontSize;
+  String? get logFontFamily => _logFontFamily;
+  SecretMasker get secretMasker => _secretMasker;
   Set<String> get projectsBeingImported => _projectsBeingAdded;
-  Map<String, String> get importErrors => _importErrors;
+  Map<String, String> get importErrors => _state.importErrors;
+  Map<String, String> get manifestErrors => _state.manifestErrors;
+  Map<String, GitStatus> get gitStatuses => _gitStatuses;
+  Map<String, DevcontainerStatus> get devcontainerStatuses => _devcontainerStatuses;
+  Set<String> get collapsedProjects => _collapsedProjects;
+  Map<String, String> get projectIcons => _projectIcons;
+
+  /// Starred tasks, by task key, pinned at the top of the sidebar
+  List<String> get favoriteTasks => _favoriteTasks;
+
+  /// Tasks started last, by task key, the latest first
+  List<String> get recentTasks => _recentTasks;
   String? get settingsBannerMessage => _settingsBannerMessage;
   String? get settingsPrefilledService => _settingsPrefilledService;
   PreferencesService get preferencesService => _preferencesService;
+  LogFileService get logFileService => _logFileService;
+  EditorService get editorService => _editorService;
+
+  Map<String, TaskSettings> get taskSettings => _taskSettings;
+  Map<String, String> get serverUrls => _serverUrls;
+  Map<String, String?> get registryAuthFailures => _registryAuthFailures;
+  Map<String, ProcessUsage> get taskUsage => _taskUsage;
+  Map<String, (int, int)> get problemCounts => _state.problemCounts;
+  int get runningTaskCount => _taskService.runningPids.length;
+
+  /// Recent usage samples of a running task, oldest first
+  List<ProcessUsage> taskUsageHistory(String taskKey) => _taskUsageHistory[taskKey] ?? const [];
+
+  Future<void> _sampleUsage() async {
+    final pids = _taskService.runningPids;
+    if (_samplingUsage || (pids.isEmpty && _taskUsage.isEmpty)) {
+      return;
+    }
+
+    _samplingUsage = true;
+    try {
+      final usage = await _resourceMonitor.sample(pids);
+      for (final entry in usage.entries) {
+        final history = _taskUsageHistory.putIfAbsent(entry.key, () => []);
+        history.add(entry.value);
+        if (history.length > AppConstants.resourceHistoryLength) {
+          history.removeAt(0);
+        }
+      }
+      _taskUsageHistory.removeWhere((taskKey, _) => !usage.containsKey(taskKey));
+
+      // Idle tasks read the same from one sample to the next
+      final changed = usage.length != _taskUsage.length ||
+          usage.entries.any((entry) {
+            final previous = _taskUsage[entry.key];
+            return previous == null ||
+                previous.formattedCpu != entry.value.formattedCpu ||
+                previous.formattedRss != entry.value.formattedRss;
+          });
+      _taskUsage = usage;
+      if (changed) {
+        notifyListeners();
+      }
+    } finally {
+      _samplingUsage = false;
+    }
+  }
+
+  BunDownloadEvent? get bunDownload => _bunDownload;
+
+  void _onBunDownloadEvent(BunDownloadEvent event) {
+    _bunDownload = event is BunReady ? null : event;
+    notifyListeners();
+  }
+
+  /// Download bun again after a failure. The outcome arrives as download events.
+  Future<void> retryBunDownload() async {
+    final failed = _bunDownload;
+    if (failed is! BunDownloadFailed) {
+      return;
+    }
+
+    try {
+      await BinaryManager().downloadBun(failed.version);
+    } catch (e) {
+      // Reported through BunDownloadFailed
+    }
+  }
+
+  void dismissBunDownloadError() {
+    if (_bunDownload is BunDownloadFailed) {
+      _bunDownload = null;
+      notifyListeners();
+    }
+  }
+
+  /// Run configuration of a task
+  TaskSettings taskSettingsFor(Project project, Task task) {
+    return _taskSettings[TaskUtils.getTaskKey(project, task)] ?? const TaskSettings();
+  }
+
+  Future<void> setTaskSettings(Project project, Task task, TaskSettings settings) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+
+    // Create new map instance so Selector detects change
+    final updated = Map<String, TaskSettings>.from(_taskSettings);
+    if (settings.isDefault) {
+      updated.remove(taskKey);
+    } else {
+      updated[taskKey] = settings;
+    }
+    _taskSettings = updated;
+    _updateSourceWatcher(project);
+    notifyListeners();
+
+    await _preferencesService.setTaskSettings(taskKey, settings);
+  }
+
+  Future<void> _loadTaskSettings() async {
+    _taskSettings = await _preferencesService.getAllTaskSettings();
+    notifyListeners();
+  }
+
+  Future<void> _loadCollapsedProjects() async {
+    _collapsedProjects = await _preferencesService.getCollapsedProjects();
+    notifyListeners();
+  }
+
+  Future<void> _loadProjectIcons() async {
+    _projectIcons = await _preferencesService.getProjectIcons();
+    notifyListeners();
+  }
+
+  Future<void> _loadFavoriteTasks() async {
+    _favoriteTasks = await _preferencesService.getFavoriteTasks();
+    notifyListeners();
+  }
+
+  Future<void> _loadRecentTasks() async {
+    _recentTasks = await _preferencesService.getRecentTasks();
+    notifyListeners();
+  }
+
+  /// Move a task to the top of the recently run ones
+  void _recordRecentTask(Project project, Task task) {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    // Create new list instance so Selector detects change
+    _recentTasks = [
+      taskKey,
+      ..._recentTasks.where((key) => key != taskKey),
+    ].take(AppConstants.recentTasksLimit).toList();
+    _preferencesService.setRecentTasks(_recentTasks);
+  }
+
+  Future<void> _loadLeftPaneWidth() async {
+    final width = await _preferencesService.getLeftPaneWidth();
+    if (width != null) {
+      _leftPaneWidth = width;
+      notifyListeners();
+    }
+  }
+
+  /// Show or hide a project's tasks in the sidebar
+  Future<void> toggleProjectCollapsed(Project project) async {
+    // Create new set instance so Selector detects change
+    final updated = Set<String>.from(_collapsedProjects);
+    if (!updated.remove(project.path)) {
+      updated.add(project.path);
+    }
+    _collapsedProjects = updated;
+    notifyListeners();
+
+    await _preferencesService.setCollapsedProjects(updated);
+  }
+
+  bool isFavorite(Project project, Task task) => _favoriteTasks.contains(TaskUtils.getTaskKey(project, task));
+
+  /// Star a task, or unstar it when it already is
+  Future<void> toggleFavorite(Project project, Task task) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    // Create new list instance so Selector detects change
+    final updated = _favoriteTasks.contains(taskKey)
+        ? _favoriteTasks.where((key) => key != taskKey).toList()
+        : [..._favoriteTasks, taskKey];
+    _favoriteTasks = updated;
+    notifyListeners();
+
+    await _preferencesService.setFavoriteTasks(updated);
+  }
+
+  /// Show [icon] before a project's name, or the folder icon again when null
+  Future<void> setProjectIcon(Project project, String? icon) async {
+    // Create new map instance so Selector detects change
+    final updated = Map<String, String>.from(_projectIcons);
+    if (icon == null || icon.isEmpty) {
+      updated.remove(project.path);
+    } else {
+      updated[project.path] = icon;
+    }
+    _projectIcons = updated;
+    notifyListeners();
+
+    await _preferencesService.setProjectIcons(updated);
+  }
+
+  /// Find the project a task belongs to
+  Project? projectForTask(Task task) {
+    for (final project in _projects) {
+      if (project.tasks.contains(task)) {
+        return project;
+      }
+    }
+    return null;
+  }
 
   // Team selection getters
   bool get isWaitingForTeamSelection => _pendingTeamSelection != null;
   List<Team> get availableTeams => _availableTeams;
   String get teamManageUrl => _teamManageUrl;
 
-  ProjectProvider() {
+  ProjectProvider({bool forceReadOnly = false}) : _forceReadOnly = forceReadOnly {
+    _loadProfiles();
+    _loadReadOnly();
+    _loadLogFont();
+    _loadTaskSettings();
+    _loadCollapsedProjects();
+    _loadProjectIcons();
+    _loadFavoriteTasks();
+    _loadRecentTasks();
+    _loadLeftPaneWidth();
+    _bunDownloadSubscription = BinaryManager.downloadEvents.listen(_onBunDownloadEvent);
     loadProjects();
+    _controlServer.start();
+    _reconcileTimer = Timer.periodic(
+      AppConstants.processReconcileInterval,
+      (_) => _reconcileProcesses(),
+    );
+    _usageTimer = Timer.periodic(
+      AppConstants.resourceSampleInterval,
+      (_) => _sampleUsage(),
+    );
+  }
+
+  Future<void> _loadReadOnly() async {
+    final readOnly = await _preferencesService.getReadOnly();
+    if (readOnly != _readOnly) {
+      _readOnly = readOnly;
+      notifyListeners();
+    }
+  }
+
+  Future<void> setReadOnly(bool readOnly) async {
+    _readOnly = readOnly;
+    notifyListeners();
+    await _preferencesService.setReadOnly(readOnly);
+  }
+
+  Future<void> _loadLogFont() async {
+    _logFontSize = await _preferencesService.getLogFontSize() ?? AppConstants.terminalFontSize;
+    _logFontFamily = await _preferencesService.getLogFontFamily();
+    notifyListeners();
+  }
+
+  /// Change the size of log text, within what stays readable
+  Future<void> setLogFontSize(double size) async {
+    _logFontSize = size.clamp(AppConstants.terminalMinFontSize, AppConstants.terminalMaxFontSize);
+    notifyListeners();
+    await _preferencesService.setLogFontSize(
+      _logFontSize == AppConstants.terminalFontSize ? null : _logFontSize,
+    );
+  }
+
+  Future<void> setLogFontFamily(String? family) async {
+    _logFontFamily = family == null || family.isEmpty ? null : family;
+    notifyListeners();
+    await _preferencesService.setLogFontFamily(_logFontFamily);
+  }
+
+  /// The profile whose projects and settings are shown, null for the default one
+  String? get profile => _preferencesService.profile;
+
+  List<String> get profiles => _profiles;
+
+  Future<void> _loadProfiles() async {
+    _profiles = await _preferencesService.getProfiles();
+    notifyListeners();
+  }
+
+  /// Answer `bob list`, `bob run`, `bob stop` and `bob status`, and other
+  /// bobs starting
+  Future<Object?> _handleControlCommand(String command, Map<String, String> arguments) async {
+    if (command == 'show') {
+      onAttach?.call();
+      return {'shown': onAttach != null};
+    }
+
+    if (command == 'list') {
+      return [
+        for (final project in _projects)
+          {
+            'name': project.name,
+            'path': project.path,
+            'tasks': [
+              for (final task in project.tasks) {'name': task.name, 'status': task.status.name},
+            ],
+          },
+      ];
+    }
+
+    final project = ControlServer.matchProject(_projects, arguments['project'] ?? '');
+    var task = ControlServer.matchTask(project, arguments['task'] ?? '');
+    final running = _taskService.isTaskRunning(project, task);
+    switch (command) {
+      case 'run' || 'stop':
+        if (isReadOnly) {
+          throw const ControlException('bob is in read-only mode');
+        }
+        if (running != (command == 'run')) {
+          await toggleTask(project, task, focus: false);
+          task = ControlServer.matchTask(project, task.name);
+        }
+      case 'status':
+        break;
+      // Output leaving bob has its secrets hidden, like in the window
+      case 'logs':
+        return (_secretMasker.apply(task.output), watchTaskOutput(project, task));
+      case 'output':
+        final lines = _secretMasker.apply(AnsiParser.stripAnsi(task.output)).split('\n');
+        final count = max(1, int.tryParse(arguments['lines'] ?? '') ?? AppConstants.controlOutputLines);
+        return {
+          'status': task.status.name,
+          'exitCode': task.lastExitCode,
+          'output': lines.sublist(lines.length > count ? lines.length - count : 0).join('\n'),
+        };
+      default:
+        throw ControlException('Unknown command $command');
+    }
+    return {'status': task.status.name, 'exitCode': task.lastExitCode};
+  }
+
+  /// Output of a task from now on, across its runs, its secrets hidden
+  Stream<String> watchTaskOutput(Project project, Task task) {
+    return _outputWatchers
+        .putIfAbsent(TaskUtils.getTaskKey(project, task), () => StreamController<String>.broadcast())
+        .stream;
+  }
+
+  /// Apply changed settings of the local API
+  Future<void> restartControlServer() => _controlServer.restart();
+
+  /// Stop every task and swap in the projects and settings of another
+  /// profile, creating it when new. Not while read-only, which would end
+  /// with the other profile.
+  Future<void> switchProfile(String? profile) async {
+    if (profile == this.profile || isReadOnly) {
+      return;
+    }
+
+    for (final timer in _retryTimers.values) {
+      timer.cancel();
+    }
+    _retryTimers.clear();
+    _retryAttempts.clear();
+    await _taskService.stopAll();
+    await _preferencesService.useProfile(profile);
+
+    _state = _ProfileState();
+
+    await _loadProfiles();
+    await _loadReadOnly();
+    await _loadLogFont();
+    await _loadTaskSettings();
+    await _loadCollapsedProjects();
+    await _loadProjectIcons();
+    await _loadFavoriteTasks();
+    await _loadRecentTasks();
+    await loadProjects();
   }
 
   Future<void> loadProjects() async {
@@ -92,8 +626,6 @@ class ProjectProvider with ChangeNotifier {
       final result = await _projectService.reloadProject(project);
       if (result.isSuccess) {
         loadedProjects.add(result.data!);
-        // Start watching package.json for this project
-        _startWatchingPackageJson(result.data!);
       } else {
         // Store reload error
         errors[project.path] = result.error!;
@@ -101,9 +633,120 @@ class ProjectProvider with ChangeNotifier {
     }
 
     _projects = loadedProjects;
-    _importErrors = errors;
+    _state.importErrors = errors;
     _isLoadingProjects = false;
     notifyListeners();
+
+    await loadSecretMasker();
+    await _restoreLastRuns();
+    await _restoreTabs();
+  }
+
+  /// Reopen the tabs and the task selected when bob last closed, those of
+  /// projects and tasks that are still around
+  Future<void> _restoreTabs() async {
+    final tabs = await _preferencesService.getOpenTabs();
+    final selected = await _preferencesService.getSelectedTask();
+
+    Task? find((String, String) tab) {
+      final (projectPath, taskName) = tab;
+      final project = _projects.where((p) => p.path == projectPath).firstOrNull;
+      return project?.tasks.where((t) => t.name == taskName).firstOrNull;
+    }
+
+    _openTabs = [
+      ..._openTabs,
+      ...tabs.where((tab) => !_openTabs.contains(tab) && find(tab) != null),
+    ];
+    if (selected != null && _selectedTask == null) {
+      _selectedTask = find(selected);
+    }
+    notifyListeners();
+  }
+
+  // Not while projects load, whose tabs come back once they're loaded
+  void _scheduleUiStateSave() {
+    _uiStateSaveTimer?.cancel();
+    _uiStateSaveTimer = Timer(AppConstants.uiStateSaveDebounce, () {
+      if (!_isLoadingProjects) {
+        _saveUiState();
+      }
+    });
+  }
+
+  Future<void> _saveUiState() async {
+    final task = _state.selectedTask;
+    final project = task == null ? null : projectForTask(task);
+    await _preferencesService.setOpenTabs(_openTabs);
+    await _preferencesService.setSelectedTask(project == null ? null : (project.path, task!.name));
+  }
+
+  /// Gather the secrets to hide in logs: patterns from the settings, and
+  /// API keys and environment variables named like secrets
+  Future<void> loadSecretMasker() async {
+    if (!await _preferencesService.getMaskSecrets()) {
+      _secretMasker = SecretMasker.none;
+      notifyListeners();
+      return;
+    }
+
+    final workspaces = await _preferencesService.getWorkspaceEnvironments();
+    _secretMasker = SecretMasker(
+      patterns: await _preferencesService.getSecretPatterns(),
+      values: [
+        ...(await _preferencesService.getApiKeys()).values,
+        for (final environment in workspaces.values) ...SecretMasker.secretsOf(environment),
+        for (final project in _projects) ...SecretMasker.secretsOf(project.environment),
+        for (final project in _projects)
+          ...(await _secretStore.readAll(project.path, project.secretEnvironment)).values,
+        for (final secrets in _envFileSecrets.values) ...secrets,
+      ],
+    );
+    notifyListeners();
+  }
+
+  /// Add the secrets of a project's env files and keychain, which may have
+  /// changed since it last ran
+  Future<void> _loadEnvFileSecrets(Project project) async {
+    if (project.loadEnvFiles && !project.isRemote) {
+      try {
+        final variables =
+            await EnvUtils.readEnvFiles(project.path, [...EnvUtils.defaultEnvFiles, ...project.envFiles]);
+        _envFileSecrets[project.path] = SecretMasker.secretsOf(variables).toList();
+      } catch (e) {
+        // The task reports env files it can't read
+      }
+    }
+    await loadSecretMasker();
+  }
+
+  /// Show the end of each task's last run logged before bob was closed, so
+  /// its output survives a restart
+  Future<void> _restoreLastRuns() async {
+    if (!await _preferencesService.getPersistLogs()) {
+      return;
+    }
+
+    for (final project in _projects) {
+      final Map<String, String> tails;
+      try {
+        tails = await _logFileService.readLastRunTails(project);
+      } catch (e) {
+        // Restoring logs is best effort
+        continue;
+      }
+
+      for (final MapEntry(key: taskName, value: tail) in tails.entries) {
+        final task = project.tasks.where((t) => t.name == taskName).firstOrNull;
+        if (task == null || task.output.isNotEmpty || _taskService.isTaskRunning(project, task)) {
+          continue;
+        }
+        final buffer = _getOrCreateBuffer(project, task);
+        buffer.append('[bob] Last run before bob restarted\n$tail');
+        _updateTask(project, task, task.copyWith(output: buffer.content));
+      }
+    }
+    notifyListeners();
   }
 
   Future<void> _saveProjects() async {
@@ -112,8 +755,8 @@ class ProjectProvider with ChangeNotifier {
 
   void dismissImportError(String projectPath) {
     // Remove the error from display - create new map instance so Selector detects change
-    _importErrors = Map.fromEntries(
-      _importErrors.entries.where((e) => e.key != projectPath)
+    _state.importErrors = Map.fromEntries(
+      _state.importErrors.entries.where((e) => e.key != projectPath)
     );
 
     // Also remove from saved projects list in background so it doesn't come back
@@ -135,8 +778,8 @@ class ProjectProvider with ChangeNotifier {
   Future<void> retryImportProject(String projectPath) async {
     // Show as importing and remove error
     _projectsBeingAdded.add(projectPath);
-    _importErrors = Map.fromEntries(
-      _importErrors.entries.where((e) => e.key != projectPath)
+    _state.importErrors = Map.fromEntries(
+      _state.importErrors.entries.where((e) => e.key != projectPath)
     );
     notifyListeners();
 
@@ -147,18 +790,55 @@ class ProjectProvider with ChangeNotifier {
       final result = await _projectService.reloadProject(project);
       if (result.isSuccess) {
         _projects = [result.data!, ..._projects];
-        _startWatchingPackageJson(result.data!);
       } else {
-        _importErrors = {..._importErrors, projectPath: 'Failed to reload: ${result.error}'};
+        _state.importErrors = {..._state.importErrors, projectPath: 'Failed to reload: ${result.error}'};
       }
     } catch (e) {
-      _importErrors = {..._importErrors, projectPath: 'Retry failed: $e'};
+      _state.importErrors = {..._state.importErrors, projectPath: 'Retry failed: $e'};
     } finally {
       _projectsBeingAdded.remove(projectPath);
       notifyListeners();
     }
   }
 
+  /// Start watchers for projects new to the list and stop those of
+  /// projects that left it
+  void _syncWatchers() {
+    final current = {for (final project in _projectList) project.path: project};
+
+    for (final projectPath in _watchedProjectPaths.difference(current.keys.toSet())) {
+      _packageJsonWatchers.remove(projectPath)?.cancel();
+      _stopWatchingGit(projectPath);
+      _sourceWatchers.remove(projectPath)?.cancel();
+      _sourceChangeDebounces.remove(projectPath)?.cancel();
+      _pendingSourceChanges.remove(projectPath);
+      if (_devcontainerStatuses.containsKey(projectPath)) {
+        _devcontainerStatuses = Map.fromEntries(
+          _devcontainerStatuses.entries.where((e) => e.key != projectPath)
+        );
+      }
+    }
+
+    for (final project in current.values) {
+      // Files of remote projects are out of reach
+      if (project.isRemote) {
+        continue;
+      }
+      if (!_watchedProjectPaths.contains(project.path)) {
+        _refreshGitStatus(project.path);
+      }
+      if (project.hasDevcontainer && !_devcontainerStatuses.containsKey(project.path)) {
+        _refreshDevcontainerStatus(project.path);
+      }
+      // Retried on every sync, the folder of a project being created may not exist yet
+      _startWatchingPackageJson(project);
+      _sourceWatchers[project.path]?.updateProject(project);
+      _updateSourceWatcher(project);
+    }
+
+    _watchedProjectPaths = current.keys.toSet();
+  }
+
   void _startWatchingPackageJson(Project project) {
     // Don't watch if already watching
     if (_packageJsonWatchers.containsKey(project.path)) {
@@ -166,23 +846,32 @@ class ProjectProvider with ChangeNotifier {
     }
 
     final packageJsonPath = path.join(project.path, 'package.json');
-    final packageJsonFile = File(packageJsonPath);
-
-    // Only watch if package.json exists
-    if (!packageJsonFile.existsSync()) {
-      return;
-    }
 
     // Watch the project directory instead of the file itself
-    // This catches all editor save strategies (direct write, temp + rename, delete + create)
+    // This catches all editor save strategies (direct write, temp + rename, delete + create),
+    // and package.json appearing after the project was added
     final projectDir = Directory(project.path);
+    if (!projectDir.existsSync()) {
+      return;
+    }
     final watcher = projectDir.watch(events: FileSystemEvent.all, recursive: false);
     final subscription = watcher.listen((event) async {
-      // Only react to package.json changes - normalize path for comparison
-      final eventPath = path.normalize(event.path);
+      // Only react to changes of package.json and the other manifests - normalize path for comparison.
+      // Atomic saves rename a temporary file over the manifest, so check move destinations too.
+      final eventPath = path.normalize(
+        event is FileSystemMoveEvent && event.destination != null ? event.destination! : event.path,
+      );
       final expectedPath = path.normalize(packageJsonPath);
 
-      if (eventPath != expectedPath) {
+      final manifestNames = [
+        ...MakefileUtils.fileNames,
+        ...TaskfileUtils.fileNames,
+        ProcfileUtils.fileName,
+        PyprojectUtils.fileName,
+      ];
+      if (eventPath != expectedPath &&
+          !(path.dirname(eventPath) == path.normalize(project.path) &&
+              manifestNames.contains(path.basename(eventPath)))) {
         return;
       }
 
@@ -191,7 +880,7 @@ class ProjectProvider with ChangeNotifier {
         return;
       }
 
-      // Reload the project when package.json changes
+      // Reload the project when one of its manifests changes
       await _reloadProjectFromPath(project.path);
     }, onError: (error) {
       // Silently ignore watcher errors
@@ -200,9 +889,105 @@ class ProjectProvider with ChangeNotifier {
     _packageJsonWatchers[project.path] = subscription;
   }
 
-  void _stopWatchingPackageJson(Project project) {
-    final subscription = _packageJsonWatchers.remove(project.path);
-    subscription?.cancel();
+  /// Re-read the git status of a project and start watching its git directory
+  Future<void> _refreshGitStatus(String projectPath) async {
+    final status = await _gitService.readStatus(projectPath);
+
+    // Project may have been removed while git was running
+    if (!_projects.any((p) => p.path == projectPath)) {
+      return;
+    }
+
+    if (status != null) {
+      _startWatchingGit(projectPath, status.gitDirectory);
+    }
+
+    if (status == _gitStatuses[projectPath]) {
+      return;
+    }
+
+    // Create new map instance so Selector detects change
+    final updated = Map<String, GitStatus>.from(_gitStatuses);
+    if (status == null) {
+      updated.remove(projectPath);
+    } else {
+      updated[projectPath] = status;
+    }
+    _gitStatuses = updated;
+    notifyListeners();
+  }
+
+  void _startWatchingGit(String projectPath, String gitDirectory) {
+    if (_gitWatchers.containsKey(projectPath)) {
+      return;
+    }
+
+    try {
+      // Git rewrites files through a .lock file + rename, so check move destinations too.
+      // HEAD changes on checkout, index on staging and commits, FETCH_HEAD on fetch.
+      final watcher = Directory(gitDirectory).watch(events: FileSystemEvent.all);
+      _gitWatchers[projectPath] = watcher.where((event) {
+        final eventPath = event is FileSystemMoveEvent && event.destination != null
+            ? event.destination!
+            : event.path;
+        return const {'HEAD', 'index', 'FETCH_HEAD', 'ORIG_HEAD'}.contains(path.basename(eventPath));
+      }).listen((_) {
+        // Git touches several files per operation, refresh once it settles
+        _gitRefreshDebounces[projectPath]?.cancel();
+        _gitRefreshDebounces[projectPath] = Timer(AppConstants.gitStatusDebounce, () {
+          _gitRefreshDebounces.remove(projectPath);
+          _refreshGitStatus(projectPath);
+        });
+      }, onError: (error) {
+        // Silently ignore watcher errors
+      });
+    } catch (e) {
+      // Git directory can't be watched, status will refresh on the timer
+    }
+
+    // Working tree edits don't touch the git directory, so also poll
+    _gitRefreshTimer ??= Timer.periodic(AppConstants.gitStatusRefreshInterval, (_) {
+      for (final projectPath in _gitStatuses.keys.toList()) {
+        _refreshGitStatus(projectPath);
+      }
+    });
+  }
+
+  /// Re-read the state of a project's devcontainer, and poll those of all
+  /// projects with one since it also changes outside of bob
+  Future<void> _refreshDevcontainerStatus(String projectPath) async {
+    _devcontainerRefreshTimer ??= Timer.periodic(AppConstants.devcontainerStatusRefreshInterval, (_) {
+      for (final project in _projects.where((p) => p.hasDevcontainer)) {
+        _refreshDevcontainerStatus(project.path);
+      }
+    });
+
+    final status = await _devcontainerService.readStatus(projectPath);
+
+    // Project may have been removed while docker was running
+    if (!_projects.any((p) => p.path == projectPath) || status == _devcontainerStatuses[projectPath]) {
+      return;
+    }
+
+    // Create new map instance so Selector detects change
+    final updated = Map<String, DevcontainerStatus>.from(_devcontainerStatuses);
+    if (status == null) {
+      updated.remove(projectPath);
+    } else {
+      updated[projectPath] = status;
+    }
+    _devcontainerStatuses = updated;
+    notifyListeners();
+  }
+
+  void _stopWatchingGit(String projectPath) {
+    _gitWatchers.remove(projectPath)?.cancel();
+    _gitRefreshDebounces.remove(projectPath)?.cancel();
+    if (_gitStatuses.containsKey(projectPath)) {
+      _gitStatuses = Map.fromEntries(
+        _gitStatuses.entries.where((e) => e.key != projectPath)
+      );
+    }
   }
 
   Future<void> _reloadProjectFromPath(String projectPath) async {
@@ -220,10 +1005,30 @@ class ProjectProvider with ChangeNotifier {
         result.data!,
         ..._projects.sublist(projectIndex + 1),
       ];
+      _setManifestError(projectPath, null);
+      notifyListeners();
+    } else {
+      // Keep the last valid tasks and flag the project until the file is fixed
+      _setManifestError(projectPath, result.error);
       notifyListeners();
     }
   }
 
+  void _setManifestError(String projectPath, String? error) {
+    if (_state.manifestErrors[projectPath] == error) {
+      return;
+    }
+
+    // Create new map instance so Selector detects change
+    final updated = Map<String, String>.from(_state.manifestErrors);
+    if (error == null) {
+      updated.remove(projectPath);
+    } else {
+      updated[projectPath] = error;
+    }
+    _state.manifestErrors = updated;
+  }
+
   Future<void> addProject(Project project) async {
     // Check if this project is already being added or already exists
     if (_projectsBeingAdded.contains(project.path) ||
@@ -233,18 +1038,15 @@ class ProjectProvider with ChangeNotifier {
 
     // Mark project as being added and clear any previous error
     _projectsBeingAdded.add(project.path);
-    _importErrors = Map.fromEntries(
-      _importErrors.entries.where((e) => e.key != project.path)
+    _state.importErrors = Map.fromEntries(
+      _state.importErrors.entries.where((e) => e.key != project.path)
     );
     notifyListeners(); // Notify to show importing state
 
     try {
       // Add to projects list
       _projects = [project, ..._projects];
-      _showingCreationForm = false;
-
-      // Start watching package.json for this project
-      _startWatchingPackageJson(project);
+      _state.showingCreationForm = false;
 
       // Immediately hide importing state now that project is in the list
       _projectsBeingAdded.remove(project.path);
@@ -254,13 +1056,37 @@ class ProjectProvider with ChangeNotifier {
       await _saveProjects();
     } catch (e) {
       // Store the error for display
-      _importErrors = {..._importErrors, project.path: e.toString()};
+      _state.importErrors = {..._state.importErrors, project.path: e.toString()};
       // Remove from projects list if save failed
       _projects = _projects.where((p) => p.path != project.path).toList();
       notifyListeners();
     }
   }
 
+  /// Add several projects at once (e.g. from a workspace scan) with a single save
+  Future<void> addProjects(List<Project> projects) async {
+    final newProjects = projects
+        .where((project) =>
+            !_projectsBeingAdded.contains(project.path) &&
+            !_projects.any((p) => p.path == project.path))
+        .toList();
+
+    if (newProjects.isEmpty) {
+      return;
+    }
+
+    final newPaths = newProjects.map((p) => p.path).toSet();
+    _state.importErrors = Map.fromEntries(
+      _state.importErrors.entries.where((e) => !newPaths.contains(e.key))
+    );
+
+    _projects = [...newProjects, ..._projects];
+    _state.showingCreationForm = false;
+    notifyListeners();
+
+    await _saveProjects();
+  }
+
   Future<void> createAndAddProject({
     required String projectName,
     required String parentDirectory,
@@ -270,12 +1096,13 @@ class ProjectProvider with ChangeNotifier {
 
     // Check if this project is already being added or already exists
     if (_projectsBeingAdded.contains(projectPath) ||
+        _projectsBeingCreated.contains(projectPath) ||
         _projects.any((p) => p.path == projectPath)) {
       return;
     }
 
-    // Mark project as being added
-    _projectsBeingAdded.add(projectPath);
+    // Mark project as being created
+    _projectsBeingCreated.add(projectPath);
 
     try {
       // Create a stub project with a "create" task
@@ -295,8 +1122,8 @@ class ProjectProvider with ChangeNotifier {
       // Add stub project to list immediately
       _projects = [stubProject, ..._projects];
       _selectedTask = createTask;
-      _selectedLaunch = null;
-      _showingCreationForm = false;
+      _state.selectedLaunch = null;
+      _state.showingCreationForm = false;
       notifyListeners();
 
       // Clear buffer for create task
@@ -338,8 +1165,8 @@ class ProjectProvider with ChangeNotifier {
           ..._projects.sublist(projectIndex + 1),
         ];
         _selectedTask = null;
-        // Remove from importing state immediately, before slow save operation
-        _projectsBeingAdded.remove(projectPath);
+        // Creation may have initialized a git repository
+        _refreshGitStatus(projectPath);
         notifyListeners();
         await _saveProjects();
       } else {
@@ -371,7 +1198,7 @@ class ProjectProvider with ChangeNotifier {
         notifyListeners();
       }
     } finally {
-      _projectsBeingAdded.remove(projectPath);
+      _projectsBeingCreated.remove(projectPath);
     }
   }
 
@@ -448,6 +1275,8 @@ class ProjectProvider with ChangeNotifier {
         ..._projects.sublist(finalProjectIndex + 1),
       ];
       _selectedTask = null;
+      // Creation may have initialized a git repository
+      _refreshGitStatus(project.path);
       notifyListeners();
       await _saveProjects();
     } else {
@@ -481,6 +1310,10 @@ class ProjectProvider with ChangeNotifier {
   }
 
   Future<void> reorderProjects(int oldIndex, int newIndex) async {
+    if (isReadOnly) {
+      return;
+    }
+
     if (newIndex > oldIndex) {
       newIndex -= 1;
     }
@@ -497,7 +1330,85 @@ class ProjectProvider with ChangeNotifier {
     }
   }
 
+  /// Export the sidebar layout without machine-specific paths,
+  /// so it can be shared with teammates who keep projects elsewhere
+  Map<String, dynamic> exportLayout() {
+    return {
+      'version': 1,
+      'projects': _projects.map((p) => p.name).toList(),
+    };
+  }
+
+  /// Reorder projects to follow a shared layout, matching projects by name.
+  /// Projects missing from the layout keep their relative order after the
+  /// matched ones. Returns the number of matched projects.
+  Future<int> importLayout(Map<String, dynamic> layout) async {
+    final names = (layout['projects'] as List<dynamic>? ?? [])
+        .map((name) => name.toString())
+        .toList();
+
+    final remaining = List<Project>.from(_projects);
+    final ordered = <Project>[];
+    for (final name in names) {
+      final index = remaining.indexWhere((p) => p.name == name);
+      if (index != -1) {
+        ordered.add(remaining.removeAt(index));
+      }
+    }
+
+    final matched = ordered.length;
+    if (matched > 0) {
+      _projects = [...ordered, ...remaining];
+      notifyListeners();
+      await _saveProjects();
+    }
+    return matched;
+  }
+
+  /// Export projects, settings and per-project configuration, to sync them
+  /// with another computer
+  Future<Map<String, dynamic>> exportConfiguration() {
+    return _preferencesService.exportConfig();
+  }
+
+  /// Merge a configuration exported on another computer. Returns how many of
+  /// its projects were added, and how many aren't on this computer.
+  Future<(int, int)> importConfiguration(Map<String, dynamic> config) async {
+    final imported = await _preferencesService.importConfig(config);
+
+    final added = <Project>[];
+    var missing = 0;
+    for (final project in imported) {
+      if (_projects.any((p) => p.path == project.path)) {
+        continue;
+      }
+      final result = await _projectService.reloadProject(project);
+      if (result.isSuccess) {
+        added.add(result.data!);
+      } else {
+        missing++;
+      }
+    }
+
+    // Projects already here may have gained custom tasks
+    for (final project in List<Project>.from(_projects)) {
+      await _reloadCustomTasks(project);
+    }
+    await _loadLogFont();
+    await _loadTaskSettings();
+    await _loadCollapsedProjects();
+    await _loadProjectIcons();
+    await _loadFavoriteTasks();
+    await _loadRecentTasks();
+    await addProjects(added);
+    return (added.length, missing);
+  }
+
   Future<void> removeProject(Project project) async {
+    if (isReadOnly) {
+      return;
+    }
+
     // Check if this project is already being removed
     if (_projectsBeingRemoved.contains(project.path)) {
       return; // Skip if already being removed
@@ -530,21 +1441,21 @@ class ProjectProvider with ChangeNotifier {
 
       await Future.wait(stopFutures);
 
-      // Stop watching package.json for this project
-      _stopWatchingPackageJson(project);
+      _setManifestError(project.path, null);
 
       // Clear all buffers for this project
       _clearProjectBuffers(project);
 
       // Create new list instance to trigger Selector rebuild
+      _state.removedProject = (project, _projects.indexOf(project));
       _projects = _projects.where((p) => p != project).toList();
       // Clear selected task if it belongs to the removed project
       if (_selectedTask != null && project.tasks.contains(_selectedTask)) {
         _selectedTask = null;
       }
       // Clear selected launch if it belongs to the removed project
-      if (_selectedLaunch != null && project.sites.contains(_selectedLaunch)) {
-        _selectedLaunch = null;
+      if (_state.selectedLaunch != null && project.sites.contains(_state.selectedLaunch)) {
+        _state.selectedLaunch = null;
       }
       notifyListeners();
       await _saveProjects();
@@ -554,251 +1465,1266 @@ class ProjectProvider with ChangeNotifier {
     }
   }
 
-  void selectTask(Task task) {
-    _selectedTask = task;
-    _selectedLaunch = null;
-    _showingCreationForm = false;
-    _configuringProject = null;
-    _showingSettings = false;
-    notifyListeners();
+  /// Put the last removed project back where it was. Task settings are kept
+  /// by path, so its customizations come back with it.
+  Future<void> undoRemoveProject(Project project) async {
+    final removed = _state.removedProject;
+    if (isReadOnly || removed == null || removed.$1 != project) {
+      return;
+    }
+    _state.removedProject = null;
+    if (_projects.any((p) => p.path == project.path)) {
+      return;
+    }
+
+    // Its tasks were stopped on removal, whatever the exit handlers made of it
+    for (var i = 0; i < project.tasks.length; i++) {
+      final task = project.tasks[i];
+      if (task.status == TaskStatus.running || task.isStopping) {
+        project.tasks[i] = task.copyWith(status: TaskStatus.idle, isStopping: false, output: '');
+      }
+    }
+
+    final index = removed.$2.clamp(0, _projects.length);
+    _projects = [..._projects.sublist(0, index), project, ..._projects.sublist(index)];
+    notifyListeners();
+    await _saveProjects();
+  }
+
+  void selectTask(Task task) {
+    _selectedTask = task;
+    _state.selectedLaunch = null;
+    _state.showingCreationForm = false;
+    _state.configuringProject = null;
+    _state.browsingLogsFor = null;
+    _state.browsingDependenciesFor = null;
+    _showingSettings = false;
+    notifyListeners();
+  }
+
+  /// Tasks open as tabs, in the order they were opened. Tabs of tasks that
+  /// no longer exist are left out.
+  List<(Project, Task)> get openTabs => [
+        for (final (projectPath, taskName) in _openTabs)
+          if (_projects.where((p) => p.path == projectPath).firstOrNull case final project?)
+            if (project.tasks.where((t) => t.name == taskName).firstOrNull case final task?) (project, task),
+      ];
+
+  /// Close the tab of a task, selecting the next one when it was selected
+  void closeTab(Project project, Task task) {
+    final wasSelected = project.tasks.contains(_selectedTask) && _selectedTask!.name == task.name;
+    final index = openTabs.indexWhere((tab) => tab.$1.path == project.path && tab.$2.name == task.name);
+    _openTabs = [..._openTabs]..remove((project.path, task.name));
+    if (_state.splitTask?.name == task.name && project.tasks.contains(_state.splitTask)) {
+      _state.splitTask = null;
+    }
+
+    if (wasSelected) {
+      final remaining = openTabs;
+      _state.selectedTask = remaining.isEmpty ? null : remaining[index.clamp(0, remaining.length - 1)].$2;
+    }
+    notifyListeners();
+  }
+
+  Task? get splitTask => _state.splitTask;
+  bool get splitVertically => _splitVertically;
+
+  /// Watch [task] beside the selected task
+  void splitWith(Task task) {
+    _state.splitTask = task;
+    notifyListeners();
+  }
+
+  void setSplitVertically(bool vertically) {
+    _splitVertically = vertically;
+    notifyListeners();
+  }
+
+  void closeSplit() {
+    _state.splitTask = null;
+    notifyListeners();
+  }
+
+  void selectLaunch(Site launch) {
+    _state.selectedLaunch = launch;
+    _selectedTask = null;
+    _state.showingCreationForm = false;
+    _state.configuringProject = null;
+    _state.browsingLogsFor = null;
+    _state.browsingDependenciesFor = null;
+    _showingSettings = false;
+    notifyListeners();
+  }
+
+  void showCreationForm() {
+    _state.showingCreationForm = true;
+    _selectedTask = null;
+    _state.selectedLaunch = null;
+    _state.configuringProject = null;
+    _state.browsingLogsFor = null;
+    _state.browsingDependenciesFor = null;
+    _showingSettings = false;
+    notifyListeners();
+  }
+
+  void hideCreationForm() {
+    _state.showingCreationForm = false;
+    notifyListeners();
+  }
+
+  void showProjectConfiguration(Project project) {
+    _state.configuringProject = project;
+    _selectedTask = null;
+    _state.selectedLaunch = null;
+    _state.showingCreationForm = false;
+    _state.browsingLogsFor = null;
+    _state.browsingDependenciesFor = null;
+    _showingSettings = false;
+    notifyListeners();
+  }
+
+  void hideProjectConfiguration() {
+    _state.configuringProject = null;
+    notifyListeners();
+  }
+
+  void showSettings({String? bannerMessage, String? prefilledService}) {
+    _showingSettings = true;
+    _settingsBannerMessage = bannerMessage;
+    _settingsPrefilledService = prefilledService;
+    _selectedTask = null;
+    _state.selectedLaunch = null;
+    _state.showingCreationForm = false;
+    _state.configuringProject = null;
+    _state.browsingLogsFor = null;
+    _state.browsingDependenciesFor = null;
+
+    // Clear pending launch if this is a manual settings open (no banner/prefilled)
+    if (bannerMessage == null && prefilledService == null) {
+      _pendingLaunchProject = null;
+      _pendingLaunchSite = null;
+    }
+
+    notifyListeners();
+  }
+
+  void hideSettings() {
+    _settingsBannerMessage = null;
+    _settingsPrefilledService = null;
+    _showingSettings = false;
+
+    // Check if we need to auto-return to a pending launch
+    final pendingProject = _pendingLaunchProject;
+    final pendingSite = _pendingLaunchSite;
+    _pendingLaunchProject = null;
+    _pendingLaunchSite = null;
+
+    notifyListeners();
+
+    // Trigger the pending launch if there was one
+    if (pendingProject != null && pendingSite != null) {
+      toggleLaunch(pendingProject, pendingSite);
+    }
+  }
+
+  void showLaunchCreation(Project project) {
+    _state.creatingLaunchFor = project;
+    _selectedTask = null;
+    _state.selectedLaunch = null;
+    _state.showingCreationForm = false;
+    _state.configuringProject = null;
+    _showingSettings = false;
+    notifyListeners();
+  }
+
+  void hideLaunchCreation() {
+    _state.creatingLaunchFor = null;
+    notifyListeners();
+  }
+
+  void showLogBrowser(Project project) {
+    _state.browsingLogsFor = project;
+    _state.browsingDependenciesFor = null;
+    _selectedTask = null;
+    _state.selectedLaunch = null;
+    _state.showingCreationForm = false;
+    _state.configuringProject = null;
+    _state.creatingLaunchFor = null;
+    _showingSettings = false;
+    notifyListeners();
+  }
+
+  void hideLogBrowser() {
+    _state.browsingLogsFor = null;
+    notifyListeners();
+  }
+
+  void showDependencyBrowser(Project project) {
+    _state.browsingDependenciesFor = project;
+    _selectedTask = null;
+    _state.selectedLaunch = null;
+    _state.showingCreationForm = false;
+    _state.configuringProject = null;
+    _state.creatingLaunchFor = null;
+    _state.browsingLogsFor = null;
+    _showingSettings = false;
+    notifyListeners();
+  }
+
+  void hideDependencyBrowser() {
+    _state.browsingDependenciesFor = null;
+    notifyListeners();
+  }
+
+  Future<void> updateProjectAfterLaunchCreation(Project updatedProject) async {
+    final index = _projects.indexWhere((p) => p.path == updatedProject.path);
+    if (index != -1) {
+      // Create new list instance to trigger Selector rebuild
+      _projects = [
+        ..._projects.sublist(0, index),
+        updatedProject,
+        ..._projects.sublist(index + 1),
+      ];
+      _state.creatingLaunchFor = null;
+      // Auto-select the newly created site
+      if (updatedProject.sites.isNotEmpty) {
+        _state.selectedLaunch = updatedProject.sites.last;
+        _selectedTask = null;
+        // Auto-start the launch
+        toggleLaunch(updatedProject, updatedProject.sites.last);
+      }
+      notifyListeners();
+      await _saveProjects();
+    }
+  }
+
+  Future<void> updateProjectAfterConfiguration(Project updatedProject) async {
+    final index = _projects.indexWhere((p) => p.path == updatedProject.path);
+    if (index != -1) {
+      // Create new list instance to trigger Selector rebuild
+      _projects = [
+        ..._projects.sublist(0, index),
+        updatedProject,
+        ..._projects.sublist(index + 1),
+      ];
+      _state.configuringProject = null;
+      notifyListeners();
+      await _saveProjects();
+    }
+  }
+
+  void setLeftPaneWidth(double width, double windowWidth) {
+    // Ensure left pane is at least leftMinPaneWidth
+    // Ensure right pane is at least rightMinPaneWidth (so left pane max is windowWidth - rightMinPaneWidth - separator)
+    final minWidth = AppConstants.leftMinPaneWidth;
+    final maxWidth = windowWidth - AppConstants.rightMinPaneWidth - AppConstants.paneSeparatorWidth;
+
+    // Ensure maxWidth is at least minWidth to avoid clamp errors
+    final safeMaxWidth = maxWidth < minWidth ? minWidth : maxWidth;
+    _leftPaneWidth = width.clamp(minWidth, safeMaxWidth);
+    notifyListeners();
+  }
+
+  /// Keep the sidebar width for the next launch, once done resizing
+  Future<void> saveLeftPaneWidth() => _preferencesService.setLeftPaneWidth(_leftPaneWidth);
+
+  Future<void> resetLeftPaneWidth() async {
+    _leftPaneWidth = AppConstants.leftPaneDefaultWidth;
+    notifyListeners();
+    await saveLeftPaneWidth();
+  }
+
+  /// Gets or creates a circular buffer for a task
+  CircularBuffer _getOrCreateBuffer(Project project, Task task) {
+    final key = TaskUtils.getTaskKey(project, task);
+    return _state.taskOutputBuffers.putIfAbsent(key, () => CircularBuffer());
+  }
+
+  /// Clears the buffer for a task
+  void _clearBuffer(Project project, Task task) {
+    final key = TaskUtils.getTaskKey(project, task);
+    _state.taskOutputBuffers[key]?.clear();
+  }
+
+  /// Output a task starts a run with: nothing, or the output of previous
+  /// runs followed by a separator when the task keeps them
+  String _startOutput(Project project, Task task) {
+    // Retries keep the output of the attempts before them
+    final attempt = _retryAttempts[TaskUtils.getTaskKey(project, task)];
+    if ((attempt == null && !taskSettingsFor(project, task).keepOutput) || task.output.isEmpty) {
+      _clearBuffer(project, task);
+      return '';
+    }
+
+    final time = DateTime.now().toIso8601String().substring(11, 19);
+    final buffer = _getOrCreateBuffer(project, task);
+    buffer.append('\n[bob] Run started at $time${attempt != null ? ' (attempt $attempt)' : ''}\n');
+    return buffer.content;
+  }
+
+  /// Compiler errors and warnings in the output of a task
+  List<Problem> problemsFor(Project project, Task task) {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    if (_state.problems[taskKey] case (final output, final problems) when output == task.output) {
+      return problems;
+    }
+    final problems = task.output.isEmpty ? const <Problem>[] : ProblemMatcher.parse(task.output);
+    _state.problems[taskKey] = (task.output, problems);
+    return problems;
+  }
+
+  void _countProblemsSoon(Project project, Task task) {
+    _state.problemCountsDue[TaskUtils.getTaskKey(project, task)] = (project, task.name);
+    _problemCountTimer ??= Timer(AppConstants.problemCountDebounce, _countProblems);
+  }
+
+  void _countProblems() {
+    _problemCountTimer = null;
+    final counts = Map.of(_state.problemCounts);
+    for (final MapEntry(key: taskKey, value: (project, taskName)) in _state.problemCountsDue.entries) {
+      final task = project.tasks.where((t) => t.name == taskName).firstOrNull;
+      if (task == null || task.output.isEmpty) {
+        counts.remove(taskKey);
+        continue;
+      }
+
+      // Compiler problems when the output has any, otherwise lines that look like errors and warnings
+      final problems = problemsFor(project, task);
+      var errors = problems.where((problem) => !problem.isWarning).length;
+      var warnings = problems.length - errors;
+      if (problems.isEmpty) {
+        for (final line in task.output.split('\n')) {
+          switch (LogLevelUtils.classify(AnsiParser.stripAnsi(line))) {
+            case LogLevel.error:
+              errors++;
+            case LogLevel.warning:
+              warnings++;
+            case LogLevel.info:
+              break;
+          }
+        }
+      }
+      if (errors == 0 && warnings == 0) {
+        counts.remove(taskKey);
+      } else {
+        counts[taskKey] = (errors, warnings);
+      }
+    }
+    _state.problemCountsDue.clear();
+    _state.problemCounts = counts;
+    notifyListeners();
+  }
+
+  /// Indices of the lines of a task's output that came from stderr
+  Set<int> stderrLinesFor(Project project, Task task) {
+    return _state.taskOutputBuffers[TaskUtils.getTaskKey(project, task)]?.stderrLines ?? const {};
+  }
+
+  /// Lines dropped from the start of a task's output to bound it
+  int droppedLinesFor(Project project, Task task) {
+    return _state.taskOutputBuffers[TaskUtils.getTaskKey(project, task)]?.droppedLines ?? 0;
+  }
+
+  /// Clear the output of a task, running or not
+  void clearTaskOutput(Project project, Task task) {
+    _clearBuffer(project, task);
+    _updateTask(project, task, task.copyWith(output: ''));
+    notifyListeners();
+  }
+
+  /// Removes all buffers for a project
+  void _clearProjectBuffers(Project project) {
+    _state.taskOutputBuffers.removeWhere((key, _) => key.startsWith('${project.path}:'));
+  }
+
+  void _updateTask(Project project, Task oldTask, Task newTask) {
+    final index = project.tasks.indexOf(oldTask);
+    if (index != -1) {
+      project.tasks[index] = newTask;
+      if (!identical(oldTask.output, newTask.output)) {
+        _countProblemsSoon(project, newTask);
+      }
+      // Update selectedTask reference if it's the same task, its tab is open already
+      if (_selectedTask == oldTask) {
+        _state.selectedTask = newTask;
+      }
+      if (_state.splitTask == oldTask) {
+        _state.splitTask = newTask;
+      }
+      if (newTask.type != TaskType.group) {
+        _refreshGroupStatuses(project);
+      }
+    }
+  }
+
+  /// Derive each group's status and summary output from its children
+  void _refreshGroupStatuses(Project project) {
+    for (final group in List<Task>.from(project.tasks)) {
+      if (group.type != TaskType.group) {
+        continue;
+      }
+
+      final children = project.tasks.where((t) => group.children.contains(t.name)).toList();
+      final TaskStatus status;
+      if (children.any((t) => t.status == TaskStatus.running)) {
+        status = TaskStatus.running;
+      } else if (children.any((t) => t.status == TaskStatus.failed)) {
+        status = TaskStatus.failed;
+      } else if (children.isNotEmpty && children.every((t) => t.status == TaskStatus.success)) {
+        status = TaskStatus.success;
+      } else {
+        status = TaskStatus.idle;
+      }
+      final output = children
+          .map((t) => '${t.name.padRight(24)} ${t.status.name}'
+              '${t.lastExitCode != null && t.status != TaskStatus.running ? ' (exit ${t.lastExitCode})' : ''}\n')
+          .join();
+
+      if (status != group.status || output != group.output) {
+        _updateTask(project, group, group.copyWith(status: status, output: output));
+      }
+    }
+  }
+
+  /// Start every child of a group, or stop the running ones
+  Future<void> _toggleGroup(Project project, Task group) async {
+    final children = project.tasks.where((t) => group.children.contains(t.name)).toList();
+    final running = children.where((t) => _taskService.isTaskRunning(project, t)).toList();
+
+    if (running.isNotEmpty) {
+      await Future.wait(running.map((t) => _taskService.stopTask(project, t)));
+      return;
+    }
+
+    await Future.wait(children.map((t) => toggleTask(project, t)));
+    _recordRecentTask(project, group);
+
+    // Starting children focuses them; keep the group selected instead
+    _selectedTask = project.tasks.where((t) => t.name == group.name).firstOrNull;
+    notifyListeners();
+  }
+
+  /// Add a command run through the shell as a task of [project], or replace
+  /// the one named [previousName]. Stored in preferences, not package.json.
+  Future<void> setCustomTask(Project project, String name, String command, {String? previousName}) async {
+    final all = await _preferencesService.getCustomTasks();
+    final tasks = Map<String, String>.from(all[project.path] ?? {});
+    if (previousName != null) {
+      tasks.remove(previousName);
+    }
+    tasks[name] = command;
+    all[project.path] = tasks;
+    await _preferencesService.setCustomTasks(all);
+    await _reloadCustomTasks(project);
+  }
+
+  Future<void> removeCustomTask(Project project, Task task) async {
+    final all = await _preferencesService.getCustomTasks();
+    all[project.path]?.remove(task.name);
+    await _preferencesService.setCustomTasks(all);
+    await _reloadCustomTasks(project);
+  }
+
+  Future<void> _reloadCustomTasks(Project project) async {
+    final result = await _projectService.reloadProject(project);
+    if (result.isSuccess) {
+      updateProjectAfterGrouping(result.data!);
+    }
+  }
+
+  /// Swap in a project whose script groups changed, keeping the state of
+  /// tasks that are still the same kind of task
+  void updateProjectAfterGrouping(Project updatedProject) {
+    final index = _projects.indexWhere((p) => p.path == updatedProject.path);
+    if (index == -1) {
+      return;
+    }
+
+    final oldTasks = {for (final task in _projects[index].tasks) task.name: task};
+    final project = Project(
+      name: updatedProject.name,
+      path: updatedProject.path,
+      tasks: updatedProject.tasks.map((task) {
+        final oldTask = oldTasks[task.name];
+        if (oldTask == null || oldTask.type != task.type) {
+          return task;
+        }
+        return task.copyWith(
+          status: oldTask.status,
+          lastExitCode: oldTask.lastExitCode,
+          output: oldTask.output,
+          isStopping: oldTask.isStopping,
+          startedAt: oldTask.startedAt,
+          lastDuration: oldTask.lastDuration,
+        );
+      }).toList(),
+      sites: updatedProject.sites,
+      launchDirectory: updatedProject.launchDirectory,
+      frozenLockfile: updatedProject.frozenLockfile,
+      ignoreScripts: updatedProject.ignoreScripts,
+      installPolicy: updatedProject.installPolicy,
+      environment: updatedProject.environment,
+      secretEnvironment: updatedProject.secretEnvironment,
+      loadEnvFiles: updatedProject.loadEnvFiles,
+      envFiles: updatedProject.envFiles,
+      watchInclude: updatedProject.watchInclude,
+      watchExclude: updatedProject.watchExclude,
+      watchGitignore: updatedProject.watchGitignore,
+      host: updatedProject.host,
+      docker: updatedProject.docker,
+      hasDevcontainer: updatedProject.hasDevcontainer,
+      runInWsl: updatedProject.runInWsl,
+      wslDistro: updatedProject.wslDistro,
+    );
+    _refreshGroupStatuses(project);
+
+    if (_selectedTask != null && oldTasks[_selectedTask!.name] == _selectedTask) {
+      _selectedTask = project.tasks.where((t) => t.name == _selectedTask!.name).firstOrNull;
+    }
+
+    // Create new list instance to trigger Selector rebuild
+    _projects = [
+      ..._projects.sublist(0, index),
+      project,
+      ..._projects.sublist(index + 1),
+    ];
+    notifyListeners();
+  }
+
+  /// Kill a task that is taking its time to stop. The exit handler of the
+  /// stop still updates its status.
+  Future<void> killTask(Project project, Task task) async {
+    if (isReadOnly || !task.isStopping) {
+      return;
+    }
+
+    await _taskService.killTask(project, task);
+  }
+
+  /// Let a task be stopped again once its stop timed out or failed, the
+  /// exit handler resetting it only when the process exits
+  void _clearStopping(Project project, Task task) {
+    final index = project.tasks.indexWhere((t) => t.name == task.name);
+    if (index == -1 || !project.tasks[index].isStopping) {
+      return;
+    }
+    _updateTask(project, project.tasks[index], project.tasks[index].copyWith(isStopping: false));
+    notifyListeners();
+  }
+
+  /// Start or stop a task. Starting focuses it, unless [focus] is false.
+  Future<void> toggleTask(Project project, Task task, {bool focus = true}) async {
+    if (isReadOnly) {
+      return;
+    }
+
+    if (task.type == TaskType.group) {
+      await _toggleGroup(project, task);
+      return;
+    }
+
+    if (task.type == TaskType.pipeline) {
+      await _togglePipeline(project, task);
+      return;
+    }
+
+    // Handle create tasks specially - they use retryCreateProject
+    if (task.type == TaskType.create) {
+      if (task.status == TaskStatus.failed) {
+        await retryCreateProject(project);
+      }
+      // Ignore clicks on running create tasks
+      return;
+    }
+
+    // Ignore clicks while the task waits for its dependencies
+    if (_startingTaskKeys.contains(TaskUtils.getTaskKey(project, task))) {
+      return;
+    }
+
+    // Starting a task waiting to be retried starts it over
+    if (_retryTimers.remove(TaskUtils.getTaskKey(project, task)) case final retry?) {
+      retry.cancel();
+      _retryAttempts.remove(TaskUtils.getTaskKey(project, task));
+    }
+
+    if (_taskService.isTaskRunning(project, task)) {
+      // Already stopping, the process gets killed once the grace period ends
+      if (task.isStopping) {
+        return;
+      }
+
+      // Stop the task - status will be updated in onExit callback
+      final stoppingTask = task.copyWith(isStopping: true);
+      _updateTask(project, task, stoppingTask);
+      notifyListeners();
+      var stopped = false;
+      try {
+        stopped = await _taskService.stopTask(project, stoppingTask);
+      } catch (e) {
+        // Handled like a stop that ran out of time
+      }
+      if (!stopped) {
+        await _taskService.killTask(project, stoppingTask);
+        _clearStopping(project, stoppingTask);
+      }
+    } else {
+      // Start the task - clear buffer and reset output, unless runs accumulate
+      final output = _startOutput(project, task);
+      Task currentTask = task;
+      final updatedTask = task.copyWith(
+        status: TaskStatus.running,
+        output: output,
+        lastExitCode: null,
+        startedAt: DateTime.now(),
+      );
+      _updateTask(project, task, updatedTask);
+      currentTask = updatedTask;
+      _recordRecentTask(project, currentTask);
+
+      // Focus the task when starting
+      if (focus) {
+        _selectedTask = currentTask;
+        _state.selectedLaunch = null;
+        _state.showingCreationForm = false;
+        _state.configuringProject = null;
+        _state.creatingLaunchFor = null;
+        _state.browsingLogsFor = null;
+        _state.browsingDependenciesFor = null;
+        _showingSettings = false;
+      }
+
+      _updateSourceWatcher(project);
+      notifyListeners();
+
+      final taskKey = TaskUtils.getTaskKey(project, currentTask);
+      _startingTaskKeys.add(taskKey);
+      if (_registryAuthFailures.containsKey(taskKey)) {
+        _registryAuthFailures = Map.of(_registryAuthFailures)..remove(taskKey);
+      }
+      await _openRunLog(taskKey, project, currentTask);
+      // Known before the first output, so none of it leaks
+      await _loadEnvFileSecrets(project);
+
+      if (!await _installBeforeRun(project, currentTask) ||
+          (currentTask.dependsOn.isNotEmpty && !await _runDependencies(project, currentTask))) {
+        _closeRunLog(taskKey);
+        _startingTaskKeys.remove(taskKey);
+        final taskInList = project.tasks.firstWhere((t) => t.name == currentTask.name);
+        _updateTask(project, taskInList, taskInList.copyWith(status: TaskStatus.failed));
+        notifyListeners();
+        _taskExitWaiters.remove(taskKey)?.complete(-1);
+        return;
+      }
+
+      final settings = taskSettingsFor(project, currentTask);
+
+      // Output of the task, lines from stderr are told apart when shown
+      void onOutput(String output, {bool stderr = false}) {
+        final stopwatch = Stopwatch()..start();
+        _writeToSinks(taskKey, output);
+        _forwardedOutputListeners[taskKey]?.call(output);
+
+        // Use circular buffer to prevent unlimited memory growth
+        final buffer = _getOrCreateBuffer(project, currentTask);
+        buffer.append(output, stderr: stderr);
+
+        final taskInList =
+            project.tasks.firstWhere((t) => t.name == currentTask.name);
+        final updatedTask = taskInList.copyWith(
+          output: buffer.content,
+        );
+        _updateTask(project, taskInList, updatedTask);
+        if (!_serverUrls.containsKey(taskKey)) {
+          final url = TaskUtils.detectServerUrl(output);
+          if (url != null) {
+            // Create new map instance so Selector detects change
+            _serverUrls = {..._serverUrls, taskKey: url};
+          }
+        }
+        if (currentTask.type == TaskType.install &&
+            !_registryAuthFailures.containsKey(taskKey) &&
+            RegistryUtils.isAuthFailure(output)) {
+          _registryAuthFailures = {..._registryAuthFailures, taskKey: null};
+          _explainRegistryAuthFailure(project, currentTask.name, taskKey);
+        }
+        PerfMonitor().recordOutput(output.length, stopwatch.elapsed);
+        notifyListeners();
+      }
+
+      _taskService.startTask(
+        project,
+        currentTask,
+        onOutput,
+        (exitCode) async {
+          if (_serverUrls.containsKey(taskKey)) {
+            _serverUrls = Map.of(_serverUrls)..remove(taskKey);
+          }
+
+          final taskInList =
+              project.tasks.firstWhere((t) => t.name == currentTask.name);
+
+          // Check if task was explicitly stopped by user
+          final wasStopped = _taskService.wasTaskExplicitlyStopped(project, taskInList);
+          final duration = taskInList.startedAt != null
+              ? DateTime.now().difference(taskInList.startedAt!)
+              : null;
+
+          // Say how the run ended at the bottom of the output and the log
+          final buffer = _getOrCreateBuffer(project, taskInList);
+          final footer = '\n[bob] ${wasStopped ? 'Stopped' : TaskUtils.describeExitCode(exitCode)}'
+              '${duration != null ? ' after ${TaskUtils.formatDuration(duration)}' : ''}\n';
+          buffer.append(footer);
+          _writeToSinks(taskKey, footer);
+          _closeRunLog(taskKey);
+
+          // Failed runs are retried with a growing delay while attempts remain
+          final exitSettings = taskSettingsFor(project, taskInList);
+          final attempt = _retryAttempts[taskKey] ?? 1;
+          final retryDelay = !wasStopped && exitCode != 0 && attempt <= exitSettings.maxRetries
+              ? AppConstants.retryBaseDelay * (1 << (attempt - 1))
+              : null;
+          if (retryDelay != null) {
+            buffer.append('[bob] Retrying in ${TaskUtils.formatDuration(retryDelay)}, '
+                'attempt ${attempt + 1} of ${exitSettings.maxRetries + 1}\n');
+          }
+
+          // Exit hooks chain a follow-up task, unless the user stopped this one
+          final followUp = wasStopped || retryDelay != null
+              ? null
+              : (exitCode == 0 ? exitSettings.onSuccess : exitSettings.onFailure);
+          if (followUp != null) {
+            buffer.append('[bob] Running $followUp\n');
+          }
+
+          final updatedTask = taskInList.copyWith(
+            status: wasStopped
+                ? TaskStatus.idle // User stopped it, return to idle
+                : (exitCode == 0 ? TaskStatus.success : TaskStatus.failed),
+            lastExitCode: wasStopped ? null : exitCode,
+            output: buffer.content,
+            isStopping: false,
+            lastDuration: duration,
+          );
+          _updateTask(project, taskInList, updatedTask);
+          _updateSourceWatcher(project);
+          notifyListeners();
+
+          if (!wasStopped && exitCode == 0) {
+            _taskSucceededAt[taskKey] = DateTime.now();
+            if (taskInList.type == TaskType.install && !project.isRemote) {
+              // Remember what got installed so unchanged installs are skipped
+              await _preferencesService.setInstallHash(
+                project.path,
+                await _projectService.installInputsHash(project.path),
+              );
+            }
+          }
+          // Whoever waits on the task waits for its last attempt
+          if (retryDelay != null) {
+            _retryAttempts[taskKey] = attempt + 1;
+            _retryTimers[taskKey] = Timer(retryDelay, () {
+              _retryTimers.remove(taskKey);
+              _runFollowUp(project.path, taskInList.name, focus: false);
+            });
+          } else {
+            _retryAttempts.remove(taskKey);
+            _taskExitWaiters.remove(taskKey)?.complete(wasStopped ? -1 : exitCode);
+          }
+
+          if (!wasStopped && retryDelay == null) {
+            _runExitActions(project, updatedTask, exitCode, duration, exitSettings.exitActions);
+          }
+          if (followUp != null) {
+            await _runFollowUp(project.path, followUp);
+          }
+        },
+        settings: settings,
+        onErrorOutput: (output) => onOutput(output, stderr: true),
+      ).whenComplete(() {
+        _startingTaskKeys.remove(taskKey);
+        // Starting the task started its devcontainer
+        if (project.docker?.devcontainer ?? false) {
+          _refreshDevcontainerStatus(project.path);
+        }
+      });
+    }
+  }
+
+  /// Install the project's dependencies before running [task] when its
+  /// install policy asks for it, streaming the install into the task's
+  /// output. Returns false when the install fails.
+  Future<bool> _installBeforeRun(Project project, Task task) async {
+    final install = project.tasks.where((t) => t.type == TaskType.install).firstOrNull;
+    if (task.type != TaskType.script ||
+        install == null ||
+        project.installPolicy == InstallPolicy.never ||
+        project.isRemote) {
+      return true;
+    }
+
+    final installKey = TaskUtils.getTaskKey(project, install);
+    final int exitCode;
+    if (_taskService.isTaskRunning(project, install) || _startingTaskKeys.contains(installKey)) {
+      _appendTaskOutput(project, task.name, '[bob] Waiting for install to finish\n');
+      exitCode = await _taskExitWaiters.putIfAbsent(installKey, Completer<int>.new).future;
+    } else {
+      final succeededAt = _taskSucceededAt[installKey];
+      if (succeededAt != null && DateTime.now().difference(succeededAt) < AppConstants.dependencyFreshness) {
+        return true;
+      }
+      if (project.installPolicy == InstallPolicy.lockfileChanged && !await _installInputsChanged(project)) {
+        return true;
+      }
+      _appendTaskOutput(project, task.name, '[bob] Installing dependencies\n');
+      exitCode = await _runAndWait(project, install, task.name);
+    }
+
+    if (exitCode != 0) {
+      _appendTaskOutput(project, task.name, '\n[bob] Install failed with exit code $exitCode\n');
+      return false;
+    }
+    return true;
+  }
+
+  /// Whether package.json or the lockfile changed since the last successful
+  /// install, or node_modules is missing
+  Future<bool> _installInputsChanged(Project project) async {
+    final hash = await _projectService.installInputsHash(project.path);
+    if (hash == null) {
+      return true;
+    }
+    final installedHashes = await _preferencesService.getInstallHashes();
+    return installedHashes[project.path] != hash;
+  }
+
+  /// Point at the registry configuration once a registry refused the
+  /// credentials of an install, since that's where they get fixed
+  Future<void> _explainRegistryAuthFailure(Project project, String taskName, String taskKey) async {
+    final config = await RegistryUtils.findConfig(project.path);
+    if (!_registryAuthFailures.containsKey(taskKey)) {
+      // Started again meanwhile
+      return;
+    }
+
+    _registryAuthFailures = {..._registryAuthFailures, taskKey: config};
+    _appendTaskOutput(
+      project,
+      taskName,
+      config == null
+          ? '\n[bob] The registry refused the credentials. Private registries and their tokens are '
+              'configured in .npmrc or bunfig.toml, in the project or your home folder.\n'
+          : '\n[bob] The registry refused the credentials. Check the registry and its token in $config\n',
+    );
+  }
+
+  /// Run the dependencies of [task] one after the other, streaming their
+  /// output into its own. Dependencies already running or that succeeded
+  /// recently are skipped. Returns false when one fails or they form a cycle.
+  Future<bool> _runDependencies(Project project, Task task) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    _resolvingDependencies.add(taskKey);
+    try {
+      for (final name in task.dependsOn) {
+        // Groups have no exit of their own to wait for
+        final dependency = project.tasks.where((t) => t.name == name).firstOrNull;
+        if (dependency == null || dependency.type != TaskType.script) {
+          continue;
+        }
+
+        final dependencyKey = TaskUtils.getTaskKey(project, dependency);
+        if (_resolvingDependencies.contains(dependencyKey)) {
+          _appendTaskOutput(project, task.name, '[bob] Dependency cycle through $name\n');
+          return false;
+        }
+        if (_taskService.isTaskRunning(project, dependency) || _startingTaskKeys.contains(dependencyKey)) {
+          _appendTaskOutput(project, task.name, '[bob] $name is already running, skipping\n');
+          continue;
+        }
+        final succeededAt = _taskSucceededAt[dependencyKey];
+        if (succeededAt != null && DateTime.now().difference(succeededAt) < AppConstants.dependencyFreshness) {
+          _appendTaskOutput(project, task.name, '[bob] $name succeeded recently, skipping\n');
+          continue;
+        }
+
+        _appendTaskOutput(project, task.name, '[bob] Running $name\n');
+        final exitCode = await _runAndWait(project, dependency, task.name);
+        if (exitCode != 0) {
+          _appendTaskOutput(project, task.name, '\n[bob] $name failed with exit code $exitCode\n');
+          return false;
+        }
+      }
+      return true;
+    } finally {
+      _resolvingDependencies.remove(taskKey);
+    }
+  }
+
+  /// How many tasks "Run all" starts at once, defaulting to half the cores
+  Future<int> getRunAllConcurrency() async {
+    return await _preferencesService.getRunAllConcurrency() ??
+        (Platform.numberOfProcessors / 2).ceil().clamp(1, Platform.numberOfProcessors);
   }
 
-  void selectLaunch(Site launch) {
-    _selectedLaunch = launch;
-    _selectedTask = null;
-    _showingCreationForm = false;
-    _configuringProject = null;
-    _showingSettings = false;
-    notifyListeners();
-  }
+  /// Start the named tasks of a project, keeping at most [concurrency] of
+  /// them running. Tasks already running are left alone.
+  Future<void> runAll(Project project, List<String> taskNames, int concurrency) async {
+    if (isReadOnly) {
+      return;
+    }
+    await _preferencesService.setRunAllConcurrency(concurrency);
+    if (taskNames.isEmpty) {
+      return;
+    }
 
-  void showCreationForm() {
-    _showingCreationForm = true;
-    _selectedTask = null;
-    _selectedLaunch = null;
-    _configuringProject = null;
-    _showingSettings = false;
-    notifyListeners();
-  }
+    final queue = Queue<String>.of(taskNames);
+    Future<void> worker() async {
+      while (queue.isNotEmpty) {
+        final name = queue.removeFirst();
+        final task = project.tasks.where((t) => t.name == name && t.type == TaskType.script).firstOrNull;
+        if (task == null ||
+            _taskService.isTaskRunning(project, task) ||
+            _startingTaskKeys.contains(TaskUtils.getTaskKey(project, task))) {
+          continue;
+        }
+        await _runAndWait(project, task, null);
+      }
+    }
 
-  void hideCreationForm() {
-    _showingCreationForm = false;
-    notifyListeners();
+    await Future.wait(List.generate(concurrency.clamp(1, taskNames.length), (_) => worker()));
   }
 
-  void showProjectConfiguration(Project project) {
-    _configuringProject = project;
-    _selectedTask = null;
-    _selectedLaunch = null;
-    _showingCreationForm = false;
-    _showingSettings = false;
-    notifyListeners();
-  }
+  /// Run the steps of a pipeline one after the other, stopping at the first
+  /// failure. Toggling a running pipeline stops its current step.
+  Future<void> _togglePipeline(Project project, Task pipeline) async {
+    final pipelineKey = TaskUtils.getTaskKey(project, pipeline);
+    final currentStep = _pipelineSteps[pipelineKey];
+    if (currentStep != null) {
+      _cancelledPipelines.add(pipelineKey);
+      final step = project.tasks.where((t) => t.name == currentStep).firstOrNull;
+      if (step != null && _taskService.isTaskRunning(project, step)) {
+        await _taskService.stopTask(project, step);
+      }
+      return;
+    }
 
-  void hideProjectConfiguration() {
-    _configuringProject = null;
+    final runningPipeline = pipeline.copyWith(status: TaskStatus.running, output: _startOutput(project, pipeline));
+    _updateTask(project, pipeline, runningPipeline);
+    _recordRecentTask(project, runningPipeline);
+    _selectedTask = runningPipeline;
+    _state.selectedLaunch = null;
     notifyListeners();
-  }
 
-  void showSettings({String? bannerMessage, String? prefilledService}) {
-    _showingSettings = true;
-    _settingsBannerMessage = bannerMessage;
-    _settingsPrefilledService = prefilledService;
-    _selectedTask = null;
-    _selectedLaunch = null;
-    _showingCreationForm = false;
-    _configuringProject = null;
+    var status = TaskStatus.success;
+    int? exitCode = 0;
+    _pipelineSteps[pipelineKey] = '';
+    try {
+      for (final (index, name) in pipeline.children.indexed) {
+        final step = project.tasks.where((t) => t.name == name && t.type == TaskType.script).firstOrNull;
+        if (step == null) {
+          continue;
+        }
+        if (_taskService.isTaskRunning(project, step) || _startingTaskKeys.contains(TaskUtils.getTaskKey(project, step))) {
+          _appendTaskOutput(project, pipeline.name, '[bob] $name is already running outside the pipeline\n');
+          status = TaskStatus.failed;
+          exitCode = null;
+          break;
+        }
 
-    // Clear pending launch if this is a manual settings open (no banner/prefilled)
-    if (bannerMessage == null && prefilledService == null) {
-      _pendingLaunchProject = null;
-      _pendingLaunchSite = null;
+        _pipelineSteps[pipelineKey] = name;
+        _appendTaskOutput(project, pipeline.name, '[bob] Step ${index + 1}/${pipeline.children.length}: $name\n');
+        exitCode = await _runAndWait(project, step, pipeline.name);
+        if (_cancelledPipelines.contains(pipelineKey)) {
+          _appendTaskOutput(project, pipeline.name, '\n[bob] Pipeline stopped\n');
+          status = TaskStatus.idle;
+          exitCode = null;
+          break;
+        }
+        if (exitCode != 0) {
+          _appendTaskOutput(project, pipeline.name, '\n[bob] $name failed with exit code $exitCode\n');
+          status = TaskStatus.failed;
+          break;
+        }
+      }
+    } finally {
+      _pipelineSteps.remove(pipelineKey);
+      _cancelledPipelines.remove(pipelineKey);
     }
 
-    notifyListeners();
+    if (status == TaskStatus.success) {
+      _appendTaskOutput(project, pipeline.name, '\n[bob] Pipeline succeeded\n');
+    }
+    final pipelineInList = project.tasks.where((t) => t.name == pipeline.name).firstOrNull;
+    if (pipelineInList != null) {
+      _updateTask(project, pipelineInList, pipelineInList.copyWith(status: status, lastExitCode: exitCode));
+      notifyListeners();
+    }
   }
 
-  void hideSettings() {
-    _settingsBannerMessage = null;
-    _settingsPrefilledService = null;
-    _showingSettings = false;
+  /// Start [task] and wait for it to exit, forwarding its output, prefixed
+  /// with its name, into the task named [intoTaskName] if given. Returns the
+  /// exit code, or -1 when it was stopped or couldn't start.
+  Future<int> _runAndWait(Project project, Task task, String? intoTaskName) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    final exit = _taskExitWaiters[taskKey] = Completer<int>();
+    if (intoTaskName == null) {
+      await toggleTask(project, task);
+      return exit.future;
+    }
 
-    // Check if we need to auto-return to a pending launch
-    final pendingProject = _pendingLaunchProject;
-    final pendingSite = _pendingLaunchSite;
-    _pendingLaunchProject = null;
-    _pendingLaunchSite = null;
+    var atLineStart = true;
+    _forwardedOutputListeners[taskKey] = (output) {
+      // Prefix each line with the forwarded task's name
+      final prefixed = StringBuffer();
+      final segments = output.split('\n');
+      for (var i = 0; i < segments.length; i++) {
+        if (atLineStart && segments[i].isNotEmpty) {
+          prefixed.write('[${task.name}] ');
+        }
+        prefixed.write(segments[i]);
+        if (segments[i].isNotEmpty) {
+          atLineStart = false;
+        }
+        if (i < segments.length - 1) {
+          prefixed.write('\n');
+          atLineStart = true;
+        }
+      }
+      _appendTaskOutput(project, intoTaskName, prefixed.toString());
+    };
 
+    await toggleTask(project, task);
+    // Starting the task focuses it; keep the one it reports to selected
+    _selectedTask = project.tasks.where((t) => t.name == intoTaskName).firstOrNull ?? _selectedTask;
     notifyListeners();
 
-    // Trigger the pending launch if there was one
-    if (pendingProject != null && pendingSite != null) {
-      toggleLaunch(pendingProject, pendingSite);
+    try {
+      return await exit.future;
+    } finally {
+      _forwardedOutputListeners.remove(taskKey);
     }
   }
 
-  void showLaunchCreation(Project project) {
-    _creatingLaunchFor = project;
-    _selectedTask = null;
-    _selectedLaunch = null;
-    _showingCreationForm = false;
-    _configuringProject = null;
-    _showingSettings = false;
-    notifyListeners();
-  }
+  void _appendTaskOutput(Project project, String taskName, String output) {
+    final task = project.tasks.where((t) => t.name == taskName).firstOrNull;
+    if (task == null) {
+      return;
+    }
 
-  void hideLaunchCreation() {
-    _creatingLaunchFor = null;
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    _writeToSinks(taskKey, output);
+    final buffer = _getOrCreateBuffer(project, task);
+    buffer.append(output);
+    _updateTask(project, task, task.copyWith(output: buffer.content));
     notifyListeners();
   }
 
-  Future<void> updateProjectAfterLaunchCreation(Project updatedProject) async {
-    final index = _projects.indexWhere((p) => p.path == updatedProject.path);
-    if (index != -1) {
-      // Create new list instance to trigger Selector rebuild
-      _projects = [
-        ..._projects.sublist(0, index),
-        updatedProject,
-        ..._projects.sublist(index + 1),
-      ];
-      _creatingLaunchFor = null;
-      // Auto-select the newly created site
-      if (updatedProject.sites.isNotEmpty) {
-        _selectedLaunch = updatedProject.sites.last;
-        _selectedTask = null;
-        // Auto-start the launch
-        toggleLaunch(updatedProject, updatedProject.sites.last);
-      }
-      notifyListeners();
-      await _saveProjects();
-    }
+  /// Fire the webhooks and commands of a task that exited on its own,
+  /// noting the ones that fail in its output
+  Future<void> _runExitActions(
+    Project project,
+    Task task,
+    int exitCode,
+    Duration? duration,
+    List<ExitAction> actions,
+  ) async {
+    await Future.wait([
+      for (final action in actions)
+        if (action.firesOn(exitCode))
+          _exitActionService.run(action, project, task, exitCode, duration).then((error) {
+            if (error != null) {
+              _appendTaskOutput(project, task.name, '[bob] $error\n');
+            }
+          }),
+    ]);
   }
 
-  Future<void> updateProjectAfterConfiguration(Project updatedProject) async {
-    final index = _projects.indexWhere((p) => p.path == updatedProject.path);
-    if (index != -1) {
-      // Create new list instance to trigger Selector rebuild
-      _projects = [
-        ..._projects.sublist(0, index),
-        updatedProject,
-        ..._projects.sublist(index + 1),
-      ];
-      _configuringProject = null;
-      notifyListeners();
-      await _saveProjects();
+  /// Start a task chained from another task's exit hook, or retried
+  Future<void> _runFollowUp(String projectPath, String taskName, {bool focus = true}) async {
+    final project = _projects.where((p) => p.path == projectPath).firstOrNull;
+    final task = project?.tasks.where((t) => t.name == taskName).firstOrNull;
+    if (project == null || task == null || _taskService.isTaskRunning(project, task)) {
+      return;
     }
-  }
-
-  void setLeftPaneWidth(double width, double windowWidth) {
-    // Ensure left pane is at least leftMinPaneWidth
-    // Ensure right pane is at least rightMinPaneWidth (so left pane max is windowWidth - rightMinPaneWidth - separator)
-    final minWidth = AppConstants.leftMinPaneWidth;
-    final maxWidth = windowWidth - AppConstants.rightMinPaneWidth - AppConstants.paneSeparatorWidth;
 
-    // Ensure maxWidth is at least minWidth to avoid clamp errors
-    final safeMaxWidth = maxWidth < minWidth ? minWidth : maxWidth;
-    _leftPaneWidth = width.clamp(minWidth, safeMaxWidth);
-    notifyListeners();
+    await toggleTask(project, task, focus: focus);
   }
 
-  /// Gets or creates a circular buffer for a task
-  CircularBuffer _getOrCreateBuffer(Project project, Task task) {
-    final key = TaskUtils.getTaskKey(project, task);
-    return _taskOutputBuffers.putIfAbsent(key, () => CircularBuffer());
-  }
+  /// Watch the source tree of a project while one of its running tasks
+  /// restarts on file changes, and stop watching once none does
+  void _updateSourceWatcher(Project project) {
+    final needed = !project.isRemote &&
+        project.tasks.any((t) => t.status == TaskStatus.running && taskSettingsFor(project, t).restartOnChange);
+    if (!needed) {
+      _sourceWatchers.remove(project.path)?.cancel();
+      _sourceChangeDebounces.remove(project.path)?.cancel();
+      _pendingSourceChanges.remove(project.path);
+      return;
+    }
+    if (_sourceWatchers.containsKey(project.path)) {
+      return;
+    }
 
-  /// Clears the buffer for a task
-  void _clearBuffer(Project project, Task task) {
-    final key = TaskUtils.getTaskKey(project, task);
-    _taskOutputBuffers[key]?.clear();
+    final projectPath = project.path;
+    _sourceWatchers[projectPath] = SourceWatcher(project, (changedPath) {
+      // Saves often touch several files, or the same file several times
+      // through delete, create and rename, so restart once they settle
+      _pendingSourceChanges.putIfAbsent(projectPath, () => {}).add(changedPath);
+      _sourceChangeDebounces[projectPath]?.cancel();
+      _sourceChangeDebounces[projectPath] = Timer(AppConstants.sourceChangeDebounce, () {
+        _sourceChangeDebounces.remove(projectPath);
+        final changes = _pendingSourceChanges.remove(projectPath);
+        if (changes != null) {
+          _restartOnSourceChange(projectPath, changes);
+        }
+      });
+    })..start();
   }
 
-  /// Removes all buffers for a project
-  void _clearProjectBuffers(Project project) {
-    _taskOutputBuffers.removeWhere((key, _) => key.startsWith('${project.path}:'));
-  }
+  /// Restart the running tasks of a project that restart on file changes
+  void _restartOnSourceChange(String projectPath, Set<String> changedPaths) {
+    final project = _projects.where((p) => p.path == projectPath).firstOrNull;
+    if (project == null) {
+      return;
+    }
 
-  void _updateTask(Project project, Task oldTask, Task newTask) {
-    final index = project.tasks.indexOf(oldTask);
-    if (index != -1) {
-      project.tasks[index] = newTask;
-      // Update selectedTask reference if it's the same task
-      if (_selectedTask == oldTask) {
-        _selectedTask = newTask;
+    for (final task in List<Task>.from(project.tasks)) {
+      if (task.status != TaskStatus.running ||
+          task.isStopping ||
+          !taskSettingsFor(project, task).restartOnChange) {
+        continue;
       }
+      final changed = changedPaths.length == 1
+          ? changedPaths.first
+          : '${changedPaths.first} and ${changedPaths.length - 1} more files';
+      _appendTaskOutput(project, task.name, '\n[bob] $changed changed, restarting\n');
+      _restartTask(project, task.name);
     }
   }
 
-  Future<void> toggleTask(Project project, Task task) async {
-    // Handle create tasks specially - they use retryCreateProject
-    if (task.type == TaskType.create) {
-      if (task.status == TaskStatus.failed) {
-        await retryCreateProject(project);
-      }
-      // Ignore clicks on running create tasks
+  /// Stop a task and start it again once its process has exited
+  Future<void> _restartTask(Project project, String taskName) async {
+    final task = project.tasks.where((t) => t.name == taskName).firstOrNull;
+    if (task == null) {
       return;
     }
 
-    if (_taskService.isTaskRunning(project, task)) {
-      // Stop the task - status will be updated in onExit callback
-      await _taskService.stopTask(project, task);
-    } else {
-      // Start the task - clear buffer and reset output
-      _clearBuffer(project, task);
-      Task currentTask = task;
-      final updatedTask = task.copyWith(
-        status: TaskStatus.running,
-        output: '',
-        lastExitCode: null,
-      );
-      _updateTask(project, task, updatedTask);
-      currentTask = updatedTask;
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    final exit = _taskExitWaiters.putIfAbsent(taskKey, () => Completer<int>());
+    await toggleTask(project, task);
+    await exit.future;
 
-      // Focus the task when starting
-      _selectedTask = currentTask;
-      _selectedLaunch = null;
-      _showingCreationForm = false;
-      _configuringProject = null;
-      _creatingLaunchFor = null;
-      _showingSettings = false;
+    // The user may have started it again or removed it meanwhile
+    final stopped = project.tasks.where((t) => t.name == taskName).firstOrNull;
+    if (stopped == null ||
+        !_projects.contains(project) ||
+        _taskService.isTaskRunning(project, stopped)) {
+      return;
+    }
+    await toggleTask(project, stopped, focus: false);
+  }
+
+  /// Fix drift between task statuses and the processes actually alive,
+  /// e.g. a task stuck in "running" after its process vanished
+  Future<void> _reconcileProcesses() async {
+    await _taskService.reconcile();
+
+    var changed = false;
+    for (final project in _projects) {
+      for (final task in List<Task>.from(project.tasks)) {
+        // Only tasks backed by a process can drift
+        if (task.type == TaskType.create || task.type == TaskType.group || task.type == TaskType.pipeline) {
+          continue;
+        }
+
+        final taskKey = TaskUtils.getTaskKey(project, task);
+        if (_startingTaskKeys.contains(taskKey)) {
+          continue;
+        }
 
+        final isRunning = _taskService.isTaskRunning(project, task);
+        String? correction;
+        Task? updatedTask;
+        if (task.status == TaskStatus.running && !isRunning) {
+          correction = 'Process is no longer running, marking task as failed';
+          _closeRunLog(taskKey);
+          updatedTask = task.copyWith(status: TaskStatus.failed);
+        } else if (task.status != TaskStatus.running && isRunning) {
+          correction = 'Process is still running, marking task as running';
+          updatedTask = task.copyWith(status: TaskStatus.running);
+        }
+
+        if (updatedTask != null) {
+          final buffer = _getOrCreateBuffer(project, task);
+          buffer.append('\n[bob] $correction\n');
+          _updateTask(project, task, updatedTask.copyWith(output: buffer.content));
+          changed = true;
+        }
+      }
+    }
+
+    if (changed) {
       notifyListeners();
+    }
+  }
 
-      _taskService.startTask(
-        project,
-        currentTask,
-        (output) {
-          // Use circular buffer to prevent unlimited memory growth
-          final buffer = _getOrCreateBuffer(project, currentTask);
-          buffer.append(output);
+  /// Start writing the task's output to a log file if log persistence is
+  /// enabled, and to the file its settings name if any
+  Future<void> _openRunLog(String taskKey, Project project, Task task) async {
+    _closeRunLog(taskKey);
 
-          final taskInList =
-              project.tasks.firstWhere((t) => t.name == currentTask.name);
-          final updatedTask = taskInList.copyWith(
-            output: buffer.content,
-          );
-          _updateTask(project, taskInList, updatedTask);
-          notifyListeners();
-        },
-        (exitCode) async {
-          final taskInList =
-              project.tasks.firstWhere((t) => t.name == currentTask.name);
+    if (taskSettingsFor(project, task).teeFile case final teeFile? when !project.isRemote) {
+      _teeWriters[taskKey] = RotatingLogWriter(path.normalize(path.join(project.path, teeFile)));
+    }
 
-          // Check if task was explicitly stopped by user
-          final wasStopped = _taskService.wasTaskExplicitlyStopped(project, taskInList);
+    if (!await _preferencesService.getPersistLogs()) {
+      return;
+    }
 
-          final updatedTask = taskInList.copyWith(
-            status: wasStopped
-                ? TaskStatus.idle // User stopped it, return to idle
-                : (exitCode == 0 ? TaskStatus.success : TaskStatus.failed),
-            lastExitCode: wasStopped ? null : exitCode,
-          );
-          _updateTask(project, taskInList, updatedTask);
-          notifyListeners();
+    try {
+      _logSinks[taskKey] = await _logFileService.openRunLog(project, task);
+      await _logFileService.pruneLogs(project, task);
+    } catch (e) {
+      // Logging to disk is best effort, the task still runs
+    }
+  }
 
-        },
-      );
+  /// Follow the output of [task] in a terminal window of its own, starting
+  /// with what it printed so far and carrying on across restarts
+  Future<void> popOutTask(Project project, Task task) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    await _popOutSinks.remove(taskKey)?.close();
+    final (filePath, sink) =
+        await _logFileService.openPopOut(project, task, _secretMasker.apply(_getOrCreateBuffer(project, task).content));
+    _popOutSinks[taskKey] = sink;
+    await ProcessUtils.followInTerminal(filePath);
+  }
+
+  /// Write output to the log file, tee file, pop-out terminal and API
+  /// watchers of a task, its secrets hidden
+  void _writeToSinks(String taskKey, String output) {
+    final masked = _sinkMaskers.putIfAbsent(taskKey, () => LineSecretMasker(() => _secretMasker)).add(output);
+    if (masked.isEmpty) {
+      return;
+    }
+    _logSinks[taskKey]?.write(masked);
+    _teeWriters[taskKey]?.write(masked);
+    _popOutSinks[taskKey]?.write(masked);
+    _outputWatchers[taskKey]?.add(masked);
+  }
+
+  void _closeRunLog(String taskKey) {
+    // Out with the unfinished last line, the run is over
+    final rest = _sinkMaskers.remove(taskKey)?.flush() ?? '';
+    if (rest.isNotEmpty) {
+      _logSinks[taskKey]?.write(rest);
+      _teeWriters[taskKey]?.write(rest);
+      _popOutSinks[taskKey]?.write(rest);
+      _outputWatchers[taskKey]?.add(rest);
     }
+    final sink = _logSinks.remove(taskKey);
+    sink?.close();
+    _teeWriters.remove(taskKey)?.close();
   }
 
   void _updateLaunchTarget(Project project, Site oldTarget, Site newTarget) {
@@ -806,13 +2732,17 @@ class ProjectProvider with ChangeNotifier {
     if (index != -1) {
       project.sites[index] = newTarget;
       // Update selectedLaunch reference if it's the same target
-      if (_selectedLaunch == oldTarget) {
-        _selectedLaunch = newTarget;
+      if (_state.selectedLaunch == oldTarget) {
+        _state.selectedLaunch = newTarget;
       }
     }
   }
 
   Future<void> toggleLaunch(Project project, Site target) async {
+    if (isReadOnly) {
+      return;
+    }
+
     if (target.status == TaskStatus.running) {
       // Stop the launch
       final launchId = '${project.path}:${target.name}';
@@ -850,11 +2780,11 @@ class ProjectProvider with ChangeNotifier {
     currentTarget = updatedTarget;
 
     // Focus the launch when starting
-    _selectedLaunch = currentTarget;
+    _state.selectedLaunch = currentTarget;
     _selectedTask = null;
-    _showingCreationForm = false;
-    _configuringProject = null;
-    _creatingLaunchFor = null;
+    _state.showingCreationForm = false;
+    _state.configuringProject = null;
+    _state.creatingLaunchFor = null;
     _showingSettings = false;
 
     notifyListeners();
@@ -951,11 +2881,66 @@ class ProjectProvider with ChangeNotifier {
     }
   }
 
+  /// Time listener dispatch when measuring performance with --perf-report
+  @override
+  void notifyListeners() {
+    final monitor = PerfMonitor();
+    if (!monitor.enabled) {
+      super.notifyListeners();
+      return;
+    }
+
+    final stopwatch = Stopwatch()..start();
+    super.notifyListeners();
+    monitor.recordNotify(stopwatch.elapsed);
+  }
+
+  /// Before bob quits, stop every running task and wait for them to exit,
+  /// or leave them running on their own
+  Future<void> shutdown({required bool stopTasks}) async {
+    if (_uiStateSaveTimer?.isActive ?? false) {
+      _uiStateSaveTimer!.cancel();
+      await _saveUiState();
+    }
+    await _controlServer.stop();
+    if (stopTasks) {
+      await _taskService.stopAll();
+    } else {
+      _taskService.releaseAll();
+    }
+  }
+
   @override
   void dispose() {
+    _reconcileTimer?.cancel();
+    _usageTimer?.cancel();
+    _resourceMonitor.dispose();
+    _uiStateSaveTimer?.cancel();
+    _problemCountTimer?.cancel();
+    for (final timer in _retryTimers.values) {
+      timer.cancel();
+    }
+    _bunDownloadSubscription?.cancel();
+    _controlServer.stop();
+    for (final watcher in _outputWatchers.values) {
+      watcher.close();
+    }
     _taskService.dispose();
     _launchService.dispose();
-    _taskOutputBuffers.clear();
+    _state.taskOutputBuffers.clear();
+
+    for (final sink in _logSinks.values) {
+      sink.close();
+    }
+    _logSinks.clear();
+    for (final writer in _teeWriters.values) {
+      writer.close();
+    }
+    _teeWriters.clear();
+    for (final sink in _popOutSinks.values) {
+      sink.close();
+    }
+    _popOutSinks.clear();
 
     // Cancel all package.json watchers
     for (final subscription in _packageJsonWatchers.values) {
@@ -963,6 +2948,66 @@ class ProjectProvider with ChangeNotifier {
     }
     _packageJsonWatchers.clear();
 
+    for (final subscription in _gitWatchers.values) {
+      subscription.cancel();
+    }
+    _gitWatchers.clear();
+    for (final timer in _gitRefreshDebounces.values) {
+      timer.cancel();
+    }
+    _gitRefreshDebounces.clear();
+    _gitRefreshTimer?.cancel();
+    _devcontainerRefreshTimer?.cancel();
+
+    for (final watcher in _sourceWatchers.values) {
+      watcher.cancel();
+    }
+    _sourceWatchers.clear();
+    for (final timer in _sourceChangeDebounces.values) {
+      timer.cancel();
+    }
+    _sourceChangeDebounces.clear();
+
     super.dispose();
   }
 }
+
+/// What the window shows of a profile: the tabs and the view open, the output
+/// of tasks and what was found in it. Switching profiles starts over with a
+/// new one.
+class _ProfileState {
+  Task? selectedTask;
+
+  // Tasks open as tabs in the right pane, as project path and task name
+  List<(String, String)> openTabs = [];
+
+  // Task watched beside the selected one
+  Task? splitTask;
+  Site? selectedLaunch;
+  bool showingCreationForm = false;
+  Project? configuringProject;
+  Project? creatingLaunchFor;
+  Project? browsingLogsFor;
+  Project? browsingDependenciesFor;
+
+  // Circular buffers for task output to prevent memory issues
+  final Map<String, CircularBuffer> taskOutputBuffers = {};
+
+  // The last removed project and where it was in the list, to undo its removal
+  (Project, int)? removedProject;
+
+  // Track import errors for projects being added
+  Map<String, String> importErrors = {};
+
+  // package.json errors of loaded projects, which keep their last valid tasks
+  Map<String, String> manifestErrors = {};
+
+  // Problems found in the output of tasks by task key, with the output they
+  // were found in so they're only looked for again when it changes
+  final Map<String, (String, List<Problem>)> problems = {};
+
+  // Errors and warnings in the output of tasks by task key, and the outputs
+  // waiting to be counted
+  Map<String, (int, int)> problemCounts = {};
+  final Map<String, (Project, String)> problemCountsDue = {};
+}
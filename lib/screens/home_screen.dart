@@ -1,18 +1,36 @@
+import 'dart:io';
+
+import 'package:file_picker/file_picker.dart';
 import 'package:flutter/material.dart';
+import 'package:path/path.dart' as path;
 import 'package:provider/provider.dart';
 
 import '../config/constants.dart';
 import '../models/project.dart';
 import '../providers/project_provider.dart';
+import '../services/devcontainer_service.dart';
+import '../services/git_service.dart';
 import '../services/project_service.dart';
+import '../services/resource_monitor.dart';
 import '../utils/process_utils.dart';
 import '../utils/ui_utils.dart';
+import '../widgets/bun_download_banner.dart';
+import '../widgets/custom_task_dialog.dart';
+import '../widgets/dependency_browser.dart';
 import '../widgets/left_pane.dart';
+import '../widgets/log_browser.dart';
+import '../widgets/project_drop_target.dart';
 import '../widgets/project_creation_form.dart';
 import '../widgets/project_config_form.dart';
+import '../widgets/project_icon_dialog.dart';
+import '../widgets/remote_project_dialog.dart';
 import '../widgets/launch_target_form.dart';
 import '../widgets/settings_form.dart';
+import '../widgets/task_tabs.dart';
 import '../widgets/right_pane.dart';
+import '../widgets/run_all_dialog.dart';
+import '../widgets/scripts_editor_dialog.dart';
+import '../widgets/workspace_import_dialog.dart';
 
 class HomeScreen extends StatelessWidget {
   const HomeScreen({super.key});
@@ -33,6 +51,109 @@ class HomeScreen extends StatelessWidget {
     }
   }
 
+  Future<void> _handleImportWorkspace(BuildContext context) async {
+    final projectService = ProjectService();
+    final provider = context.read<ProjectProvider>();
+
+    final rootPath = await FilePicker.platform.getDirectoryPath(
+      dialogTitle: 'Select workspace directory',
+    );
+
+    if (rootPath == null || !context.mounted) return;
+
+    final selectedPaths = await showDialog<List<String>>(
+      context: context,
+      builder: (_) => WorkspaceImportDialog(
+        rootPath: rootPath,
+        projectService: projectService,
+        existingPaths: provider.projects.map((p) => p.path).toSet(),
+      ),
+    );
+
+    if (selectedPaths == null || selectedPaths.isEmpty) return;
+
+    final imported = <Project>[];
+    final failures = <String>[];
+    for (final projectPath in selectedPaths) {
+      final result = await projectService.importProjectFromPath(projectPath);
+      if (result.isSuccess) {
+        imported.add(result.data!);
+      } else {
+        failures.add(result.error!);
+      }
+    }
+
+    await provider.addProjects(imported);
+
+    if (!context.mounted) return;
+
+    if (failures.isNotEmpty) {
+      UiUtils.showErrorSnackbar(
+        context,
+        'Failed to import ${failures.length} project(s): ${failures.first}',
+      );
+    } else {
+      UiUtils.showSuccessSnackbar(context, 'Imported ${imported.length} project(s)');
+    }
+  }
+
+  Future<void> _handleAddRemoteProject(BuildContext context) async {
+    final provider = context.read<ProjectProvider>();
+
+    final remote = await showDialog<(String, String)>(
+      context: context,
+      builder: (_) => const RemoteProjectDialog(),
+    );
+    if (remote == null || !context.mounted) return;
+
+    final (host, remotePath) = remote;
+    final result = await ProjectService().importRemoteProject(host, remotePath);
+
+    if (!context.mounted) return;
+
+    if (result.isSuccess) {
+      await provider.addProject(result.data!);
+    } else {
+      UiUtils.showErrorSnackbar(context, result.error!);
+    }
+  }
+
+  /// Import folders or package.json files dropped onto the window
+  Future<void> _handleDroppedPaths(BuildContext context, List<String> paths) async {
+    final projectService = ProjectService();
+    final provider = context.read<ProjectProvider>();
+    if (provider.isReadOnly) {
+      return;
+    }
+
+    for (final droppedPath in paths) {
+      final String directoryPath;
+      if (await FileSystemEntity.isDirectory(droppedPath)) {
+        directoryPath = droppedPath;
+      } else if (path.basename(droppedPath) == 'package.json') {
+        directoryPath = path.dirname(droppedPath);
+      } else {
+        if (context.mounted) {
+          UiUtils.showErrorSnackbar(
+            context,
+            'Drop a folder or a package.json to import a project',
+          );
+        }
+        continue;
+      }
+
+      final result = await projectService.importProjectFromPath(directoryPath);
+
+      if (!context.mounted) return;
+
+      if (result.isSuccess) {
+        await provider.addProject(result.data!);
+      } else {
+        UiUtils.showErrorSnackbar(context, result.error!);
+      }
+    }
+  }
+
   Future<void> _openInExplorer(BuildContext context, Project project) async {
     try {
       await ProcessUtils.openInFileExplorer(project.path);
@@ -47,12 +168,150 @@ class HomeScreen extends StatelessWidget {
     }
   }
 
+  Future<void> _openTerminal(BuildContext context, Project project) async {
+    try {
+      final command = await context.read<ProjectProvider>().preferencesService.getTerminalCommand();
+      await ProcessUtils.openTerminal(project.path, command: command);
+    } catch (e) {
+      if (context.mounted) {
+        UiUtils.showErrorSnackbar(
+          context,
+          'Failed to open terminal: ${e.toString()}',
+          duration: const Duration(seconds: 3),
+        );
+      }
+    }
+  }
+
+  Future<void> _removeProject(BuildContext context, Project project) async {
+    final provider = context.read<ProjectProvider>();
+    final messenger = ScaffoldMessenger.of(context);
+    await provider.removeProject(project);
+    if (!context.mounted || provider.projects.contains(project)) return;
+
+    messenger.showSnackBar(
+      SnackBar(
+        content: Text('${project.name} removed'),
+        duration: AppConstants.undoRemoveDuration,
+        action: SnackBarAction(
+          label: 'Undo',
+          onPressed: () => provider.undoRemoveProject(project),
+        ),
+      ),
+    );
+  }
+
+  Future<void> _runAll(BuildContext context, Project project) async {
+    final provider = context.read<ProjectProvider>();
+    final concurrency = await provider.getRunAllConcurrency();
+    if (!context.mounted) return;
+
+    final request = await showDialog<RunAllRequest>(
+      context: context,
+      builder: (_) => RunAllDialog(project: project, concurrency: concurrency),
+    );
+    if (request != null) {
+      await provider.runAll(project, request.taskNames, request.concurrency);
+    }
+  }
+
+  Future<void> _editScripts(BuildContext context, Project project) async {
+    final projectService = ProjectService();
+    final current = await projectService.readScripts(project.path);
+    if (!context.mounted) return;
+    if (current.isFailure) {
+      UiUtils.showErrorSnackbar(context, current.error!);
+      return;
+    }
+
+    final scripts = await showDialog<Map<String, String>>(
+      context: context,
+      builder: (_) => ScriptsEditorDialog(project: project, scripts: current.data!),
+    );
+    if (scripts == null) return;
+
+    final result = await projectService.writeScripts(project.path, scripts);
+    if (result.isFailure && context.mounted) {
+      UiUtils.showErrorSnackbar(context, result.error!);
+    }
+  }
+
+  /// Add a command as a task of [project], or edit [task] when set
+  Future<void> _editCustomTask(BuildContext context, Project project, Task? task) async {
+    final provider = context.read<ProjectProvider>();
+    final edit = await showDialog<CustomTaskEdit>(
+      context: context,
+      builder: (_) => CustomTaskDialog(project: project, task: task),
+    );
+    if (edit == null) return;
+
+    if (edit.remove) {
+      await provider.removeCustomTask(project, task!);
+    } else {
+      await provider.setCustomTask(project, edit.name, edit.command, previousName: task?.name);
+    }
+  }
+
+  /// Expand a composite script into a group of [children], or collapse it when null
+  Future<void> _setScriptGroup(
+    BuildContext context,
+    Project project,
+    Task task,
+    List<String>? children,
+  ) async {
+    final provider = context.read<ProjectProvider>();
+    final result = await ProjectService().setScriptGroup(project, task.name, children);
+
+    if (!context.mounted) return;
+
+    if (result.isSuccess) {
+      provider.updateProjectAfterGrouping(result.data!);
+    } else {
+      UiUtils.showErrorSnackbar(context, result.error!);
+    }
+  }
+
+  /// Run the project's tasks in its devcontainer, or on this computer again
+  Future<void> _toggleDevcontainer(BuildContext context, Project project) async {
+    final provider = context.read<ProjectProvider>();
+    final result =
+        await ProjectService().setUseDevcontainer(project, !(project.docker?.devcontainer ?? false));
+
+    if (!context.mounted) return;
+
+    if (result.isSuccess) {
+      provider.updateProjectAfterGrouping(result.data!);
+    } else {
+      UiUtils.showErrorSnackbar(context, result.error!);
+    }
+  }
+
+  /// Pick the emoji shown before a project's name
+  Future<void> _setProjectIcon(BuildContext context, Project project) async {
+    final provider = context.read<ProjectProvider>();
+    final icon = await showDialog<String>(
+      context: context,
+      builder: (context) => ProjectIconDialog(project: project, icon: provider.projectIcons[project.path]),
+    );
+    if (icon != null) {
+      await provider.setProjectIcon(project, icon);
+    }
+  }
+
+  Future<void> _openInEditor(BuildContext context, Project project) async {
+    final result = await context.read<ProjectProvider>().editorService.openProject(project.path);
+    if (result.isFailure && context.mounted) {
+      UiUtils.showErrorSnackbar(context, result.error!);
+    }
+  }
+
   @override
   Widget build(BuildContext context) {
     final provider = context.read<ProjectProvider>();
 
     return Scaffold(
-      body: Selector<ProjectProvider, bool>(
+      body: BunDownloadBanner(
+        child: Selector<ProjectProvider, bool>(
         selector: (_, provider) => provider.isLoadingProjects,
         builder: (context, isLoadingProjects, _) {
           // Show loading screen while projects are loading
@@ -87,11 +346,13 @@ class HomeScreen extends StatelessWidget {
                 return const SizedBox.shrink();
               }
 
-              return Row(
+              return ProjectDropTarget(
+                onDrop: (paths) => _handleDroppedPaths(context, paths),
+                builder: (context, isDragging) => Row(
             children: [
               // Left pane - only rebuilds when projects, selection, or form visibility changes
               Selector<ProjectProvider,
-                  (List<Project>, Task?, Site?, bool, Project?, Project?, bool, Set<String>, Map<String, String>)>(
+                  (List<Project>, Task?, Site?, bool, Project?, Project?, bool, Set<String>, Map<String, String>, Map<String, GitStatus>, bool, Map<String, String>, Set<String>, Map<String, ProcessUsage>, Map<String, (int, int)>, Map<String, DevcontainerStatus>, Map<String, String>, List<String>, List<String>)>(
                 selector: (_, provider) => (
                   provider.projects,
                   provider.selectedTask,
@@ -102,9 +363,19 @@ class HomeScreen extends StatelessWidget {
                   provider.showingSettings,
                   provider.projectsBeingImported,
                   provider.importErrors,
+                  provider.gitStatuses,
+                  provider.isReadOnly,
+                  provider.manifestErrors,
+                  provider.collapsedProjects,
+                  provider.taskUsage,
+                  provider.problemCounts,
+                  provider.devcontainerStatuses,
+                  provider.projectIcons,
+                  provider.favoriteTasks,
+                  provider.recentTasks,
                 ),
                 builder: (context, data, _) {
-                  final (projects, selectedTask, selectedLaunch, showingForm, configuringProject, creatingLaunchFor, showingSettings, projectsBeingImported, importErrors) = data;
+                  final (projects, selectedTask, selectedLaunch, showingForm, configuringProject, creatingLaunchFor, showingSettings, projectsBeingImported, importErrors, gitStatuses, readOnly, manifestErrors, collapsedProjects, taskUsage, problemCounts, devcontainerStatuses, projectIcons, favoriteTasks, recentTasks) = data;
                   return Selector<ProjectProvider, double>(
                     selector: (_, provider) => provider.leftPaneWidth,
                     builder: (context, leftPaneWidth, _) {
@@ -116,26 +387,62 @@ class HomeScreen extends StatelessWidget {
                       final safeMaxWidth = maxWidth < minWidth ? minWidth : maxWidth;
                       final clampedWidth = leftPaneWidth.clamp(minWidth, safeMaxWidth);
 
-                      return SizedBox(
+                      return Container(
                         width: clampedWidth,
+                        // Highlight the sidebar while files are dragged over the window
+                        foregroundDecoration: isDragging
+                            ? BoxDecoration(
+                                color: Theme.of(context).colorScheme.primary.withValues(alpha: 0.1),
+                                border: Border.all(
+                                  color: Theme.of(context).colorScheme.primary,
+                                  width: 2,
+                                ),
+                              )
+                            : null,
                         child: LeftPane(
                           projects: projects,
                           selectedTask: selectedTask,
                           selectedLaunch: selectedLaunch,
                           isCreationFormVisible: showingForm,
                           showingSettings: showingSettings,
+                          readOnly: readOnly,
                           projectsBeingImported: projectsBeingImported,
                           importErrors: importErrors,
+                          gitStatuses: gitStatuses,
+                          devcontainerStatuses: devcontainerStatuses,
+                          manifestErrors: manifestErrors,
+                          collapsedProjects: collapsedProjects,
+                          projectIcons: projectIcons,
+                          favoriteTasks: favoriteTasks,
+                          recentTasks: recentTasks,
+                          taskUsage: taskUsage,
+                          problemCounts: problemCounts,
                           onImportProject: () => _handleImportProject(context),
+                          onImportWorkspace: () => _handleImportWorkspace(context),
+                          onAddRemoteProject: () => _handleAddRemoteProject(context),
                           onCreateProject: provider.showCreationForm,
                           onTaskSelected: provider.selectTask,
                           onLaunchSelected: provider.selectLaunch,
                           onReorderProjects: provider.reorderProjects,
-                          onRemoveProject: provider.removeProject,
+                          onToggleProjectCollapsed: provider.toggleProjectCollapsed,
+                          onRemoveProject: (project) => _removeProject(context, project),
                           onTaskToggle: provider.toggleTask,
+                          onTaskKill: provider.killTask,
+                          onToggleFavorite: provider.toggleFavorite,
+                          onRunAll: (project) => _runAll(context, project),
+                          onSetScriptGroup: (project, task, children) =>
+                              _setScriptGroup(context, project, task, children),
                           onLaunchToggle: provider.toggleLaunch,
                           onOpenInExplorer: (project) => _openInExplorer(context, project),
+                          onOpenInEditor: (project) => _openInEditor(context, project),
+                          onOpenTerminal: (project) => _openTerminal(context, project),
                           onConfigureProject: provider.showProjectConfiguration,
+                          onBrowseLogs: provider.showLogBrowser,
+                          onBrowseDependencies: provider.showDependencyBrowser,
+                          onEditScripts: (project) => _editScripts(context, project),
+                          onToggleDevcontainer: (project) => _toggleDevcontainer(context, project),
+                          onSetProjectIcon: (project) => _setProjectIcon(context, project),
+                          onEditCustomTask: (project, task) => _editCustomTask(context, project, task),
                           onCreateLaunchTarget: provider.showLaunchCreation,
                           onOpenSettings: provider.showSettings,
                           onDismissError: provider.dismissImportError,
@@ -157,6 +464,9 @@ class HomeScreen extends StatelessWidget {
                   windowWidth,
                 );
               },
+              onHorizontalDragEnd: (_) => provider.saveLeftPaneWidth(),
+              // Double-click puts the sidebar back to its default width
+              onDoubleTap: provider.resetLeftPaneWidth,
               child: Container(
                 width: AppConstants.paneSeparatorWidth,
                 color: Theme.of(context).colorScheme.primaryContainer,
@@ -165,65 +475,96 @@ class HomeScreen extends StatelessWidget {
           ),
           // Right pane - only rebuilds when selected task or form visibility changes
           Expanded(
-            child: Selector<ProjectProvider, (Task?, Site?, bool, Project?, Project?, bool, String?, String?)>(
-              selector: (_, provider) => (
-                provider.selectedTask,
-                provider.selectedLaunch,
-                provider.showingCreationForm,
-                provider.configuringProject,
-                provider.creatingLaunchFor,
-                provider.showingSettings,
-                provider.settingsBannerMessage,
-                provider.settingsPrefilledService,
-              ),
-              builder: (context, data, _) {
-                final (selectedTask, selectedLaunch, showingForm, configuringProject, creatingLaunchFor, showingSettings, settingsBannerMessage, settingsPrefilledService) = data;
-
-                // Determine custom content
-                Widget? customContent;
-                if (showingForm) {
-                  customContent = ProjectCreationForm(
-                    onCancel: provider.hideCreationForm,
-                  );
-                } else if (configuringProject != null) {
-                  customContent = ProjectConfigForm(
-                    key: ValueKey(configuringProject.path),
-                    project: configuringProject,
-                    projectService: ProjectService(),
-                    onCancel: provider.hideProjectConfiguration,
-                    onSaved: provider.updateProjectAfterConfiguration,
-                  );
-                } else if (creatingLaunchFor != null) {
-                  customContent = LaunchTargetForm(
-                    key: ValueKey(creatingLaunchFor.path),
-                    project: creatingLaunchFor,
-                    projectService: ProjectService(),
-                    onCancel: provider.hideLaunchCreation,
-                    onSaved: provider.updateProjectAfterLaunchCreation,
-                  );
-                } else if (showingSettings) {
-                  customContent = SettingsForm(
-                    preferencesService: provider.preferencesService,
-                    onCancel: provider.hideSettings,
-                    bannerMessage: settingsBannerMessage,
-                    prefilledService: settingsPrefilledService,
-                  );
-                }
+            child: Column(
+              children: [
+                const TaskTabs(),
+                Expanded(
+                  child: Selector<ProjectProvider, (Task?, Site?, bool, Project?, Project?, Project?, Project?, bool, String?, String?, Task?, bool)>(
+                    selector: (_, provider) => (
+                      provider.selectedTask,
+                      provider.selectedLaunch,
+                      provider.showingCreationForm,
+                      provider.configuringProject,
+                      provider.creatingLaunchFor,
+                      provider.browsingLogsFor,
+                      provider.browsingDependenciesFor,
+                      provider.showingSettings,
+                      provider.settingsBannerMessage,
+                      provider.settingsPrefilledService,
+                      provider.splitTask,
+                      provider.splitVertically,
+                    ),
+                    builder: (context, data, _) {
+                      final (selectedTask, selectedLaunch, showingForm, configuringProject, creatingLaunchFor, browsingLogsFor, browsingDependenciesFor, showingSettings, settingsBannerMessage, settingsPrefilledService, splitTask, splitVertically) = data;
 
-                return RightPane(
-                  selectedTask: selectedTask,
-                  selectedLaunch: selectedLaunch,
-                  customContent: customContent,
-                );
-              },
+                      // Determine custom content
+                      Widget? customContent;
+                      if (showingForm) {
+                        customContent = ProjectCreationForm(
+                          onCancel: provider.hideCreationForm,
+                        );
+                      } else if (configuringProject != null) {
+                        customContent = ProjectConfigForm(
+                          key: ValueKey(configuringProject.path),
+                          project: configuringProject,
+                          projectService: ProjectService(),
+                          onCancel: provider.hideProjectConfiguration,
+                          onSaved: provider.updateProjectAfterConfiguration,
+                        );
+                      } else if (creatingLaunchFor != null) {
+                        customContent = LaunchTargetForm(
+                          key: ValueKey(creatingLaunchFor.path),
+                          project: creatingLaunchFor,
+                          projectService: ProjectService(),
+                          onCancel: provider.hideLaunchCreation,
+                          onSaved: provider.updateProjectAfterLaunchCreation,
+                        );
+                      } else if (browsingLogsFor != null) {
+                        customContent = LogBrowser(
+                          key: ValueKey(browsingLogsFor.path),
+                          project: browsingLogsFor,
+                          logFileService: provider.logFileService,
+                          preferencesService: provider.preferencesService,
+                          editorService: provider.editorService,
+                          onClose: provider.hideLogBrowser,
+                        );
+                      } else if (browsingDependenciesFor != null) {
+                        customContent = DependencyBrowser(
+                          key: ValueKey(browsingDependenciesFor.path),
+                          project: browsingDependenciesFor,
+                          projectService: ProjectService(),
+                          onClose: provider.hideDependencyBrowser,
+                        );
+                      } else if (showingSettings) {
+                        customContent = SettingsForm(
+                          preferencesService: provider.preferencesService,
+                          onCancel: provider.hideSettings,
+                          bannerMessage: settingsBannerMessage,
+                          prefilledService: settingsPrefilledService,
+                        );
+                      }
+
+                      return RightPane(
+                        selectedTask: selectedTask,
+                        selectedLaunch: selectedLaunch,
+                        customContent: customContent,
+                        splitTask: splitTask,
+                        splitVertically: splitVertically,
+                      );
+                    },
+                  ),
+                ),
+              ],
             ),
           ),
               ],
+            ),
             );
           },
         );
         },
       ),
+      ),
     );
   }
 }
@@ -0,0 +1,72 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+import 'package:path_provider/path_provider.dart';
+
+/// Where bob keeps its preferences, downloaded binaries and logs. Normally
+/// the directories of the OS; in portable mode a `bob-data` directory next
+/// to the executable, so bob can live on a USB stick. Each can also be moved
+/// to keep several instances of bob apart.
+class AppPaths {
+  // Name of the marker file next to the executable that turns portable mode
+  // on, and of the directory portable data goes to
+  static const String _portableMarker = 'portable';
+  static const String _portableDirectory = 'bob-data';
+
+  static String? _configDirectory;
+  static String? _dataDirectory;
+
+  /// Whether preferences are kept in a file rather than where the OS keeps
+  /// them for apps
+  static bool get isPortable => _configDirectory != null;
+
+  /// Read the directories from `--config-dir` and `--data-dir`, or the
+  /// BOB_CONFIG_DIR and BOB_DATA_DIR environment variables. Turn portable
+  /// mode on for the others with `--portable`, or when a `portable` file
+  /// sits next to the executable.
+  static void configure(List<String> args) {
+    final installDirectory = _installDirectory();
+    final portable = args.contains('--portable') ||
+        File(path.join(installDirectory, _portableMarker)).existsSync();
+    final portableDirectory = portable ? path.join(installDirectory, _portableDirectory) : null;
+
+    _configDirectory = _directoryFrom(args, '--config-dir', 'BOB_CONFIG_DIR') ?? portableDirectory;
+    _dataDirectory = _directoryFrom(args, '--data-dir', 'BOB_DATA_DIR') ?? portableDirectory;
+  }
+
+  /// A directory passed as `--flag <dir>` or `--flag=<dir>`, or else in an
+  /// environment variable
+  static String? _directoryFrom(List<String> args, String flag, String variable) {
+    String? directory;
+    for (var i = 0; i < args.length; i++) {
+      if (args[i] == flag && i + 1 < args.length) {
+        directory = args[i + 1];
+      } else if (args[i].startsWith('$flag=')) {
+        directory = args[i].substring(flag.length + 1);
+      }
+    }
+    directory ??= Platform.environment[variable];
+    return directory == null || directory.isEmpty ? null : path.absolute(directory);
+  }
+
+  /// The directory holding the executable, or the app bundle on macOS
+  static String _installDirectory() {
+    var directory = path.dirname(Platform.resolvedExecutable);
+    if (Platform.isMacOS && directory.endsWith(path.join('.app', 'Contents', 'MacOS'))) {
+      directory = path.dirname(path.dirname(path.dirname(directory)));
+    }
+    return directory;
+  }
+
+  /// Directory for binaries, logs and reports
+  static Future<Directory> supportDirectory() async {
+    if (_dataDirectory case final directory?) {
+      return Directory(directory).create(recursive: true);
+    }
+    return getApplicationSupportDirectory();
+  }
+
+  /// File preferences are kept in, when not where the OS keeps them
+  static String? get preferencesFile =>
+      _configDirectory != null ? path.join(_configDirectory!, 'preferences.json') : null;
+}
@@ -1,23 +1,93 @@
+import 'dart:async';
+import 'dart:convert';
 import 'dart:io';
 import 'dart:ffi';
+import 'package:crypto/crypto.dart';
+import 'package:flutter/foundation.dart';
 import 'package:http/http.dart' as http;
+import 'package:http/io_client.dart';
 import 'package:path/path.dart' as path;
-import 'package:path_provider/path_provider.dart';
 import 'package:archive/archive_io.dart';
 import '../config/constants.dart';
+import 'preferences_service.dart';
+import 'app_paths.dart';
 
 enum BinaryType { bun }
 
+/// Where bun comes from
+enum BunMode {
+  /// Use bun from PATH when available, download otherwise
+  auto('Auto (prefer system)'),
+
+  /// Always use a downloaded bun
+  download('Always download'),
+
+  /// Use the bun executable at a configured path
+  custom('Custom path');
+
+  final String displayName;
+
+  const BunMode(this.displayName);
+}
+
+/// A bun executable found on PATH
+class SystemBun {
+  final String path;
+  final String version;
+
+  const SystemBun(this.path, this.version);
+}
+
+/// State changes of a bun download, published on [BinaryManager.downloadEvents]
+sealed class BunDownloadEvent {
+  final String version;
+
+  const BunDownloadEvent(this.version);
+}
+
+class BunDownloadProgress extends BunDownloadEvent {
+  final int received;
+
+  /// Size of the download, null when the server doesn't send it
+  final int? total;
+
+  const BunDownloadProgress(super.version, this.received, this.total);
+
+  double? get fraction => total == null || total == 0 ? null : received / total!;
+}
+
+class BunDownloadVerifying extends BunDownloadEvent {
+  const BunDownloadVerifying(super.version);
+}
+
+class BunReady extends BunDownloadEvent {
+  const BunReady(super.version);
+}
+
+class BunDownloadFailed extends BunDownloadEvent {
+  final String error;
+
+  const BunDownloadFailed(super.version, this.error);
+}
+
 class BinaryConfig {
   final String version;
   final String name;
-  final String Function(String version, bool isArm) getDownloadUrl;
+
+  /// Base URL of the releases, replaced by a configured mirror
+  final String releasesUrl;
+  final String Function(String version) getReleaseTag;
+  final String Function(bool isArm) getArchiveName;
+  final String checksumsName;
   final String archiveExtension;
 
   const BinaryConfig({
     required this.version,
     required this.name,
-    required this.getDownloadUrl,
+    required this.releasesUrl,
+    required this.getReleaseTag,
+    required this.getArchiveName,
+    required this.checksumsName,
     required this.archiveExtension,
   });
 }
@@ -25,35 +95,54 @@ class BinaryConfig {
 class BinaryManager {
   static const String bunVersion = AppConstants.bunVersion;
 
-  static final Map<BinaryType, String?> _cachedPaths = {
-    BinaryType.bun: null,
-  };
+  // Resolved executable paths, keyed by '<name>-<version>'
+  static final Map<String, String> _cachedPaths = {};
+
+  // Downloads in progress, so concurrent tasks share one download per version
+  static final Map<String, Future<void>> _pendingDownloads = {};
+
+  // Result of looking up bun on PATH; null entry means not found
+  static Future<SystemBun?>? _systemBun;
+
+  /// Bun used in place of any other, for integration tests running
+  /// tool/fake_bun.dart
+  @visibleForTesting
+  static String? bunOverride;
+
+  static final StreamController<BunDownloadEvent> _downloadEvents =
+      StreamController<BunDownloadEvent>.broadcast();
+
+  // Minimum bytes between two progress events
+  static const int _progressStepBytes = 256 * 1024;
+
+  // Exact versions like 1.1.38 or v1.1.38; ranges aren't supported
+  static final _exactVersionRegex = RegExp(r'^v?(\d+\.\d+\.\d+)$');
 
   static final Map<BinaryType, BinaryConfig> _configs = {
     BinaryType.bun: BinaryConfig(
       version: bunVersion,
       name: 'bun',
       archiveExtension: 'zip',
-      getDownloadUrl: (version, isArm) {
+      releasesUrl: 'https://github.com/oven-sh/bun/releases/download',
+      getReleaseTag: (version) => 'bun-v$version',
+      checksumsName: 'SHASUMS256.txt',
+      getArchiveName: (isArm) {
         if (Platform.isWindows) {
-          return isArm
-              ? 'https://github.com/oven-sh/bun/releases/download/bun-v$version/bun-windows-aarch64.zip'
-              : 'https://github.com/oven-sh/bun/releases/download/bun-v$version/bun-windows-x64.zip';
+          return isArm ? 'bun-windows-aarch64.zip' : 'bun-windows-x64.zip';
         } else if (Platform.isMacOS) {
-          return isArm
-              ? 'https://github.com/oven-sh/bun/releases/download/bun-v$version/bun-darwin-aarch64.zip'
-              : 'https://github.com/oven-sh/bun/releases/download/bun-v$version/bun-darwin-x64.zip';
+          return isArm ? 'bun-darwin-aarch64.zip' : 'bun-darwin-x64.zip';
         } else {
-          return isArm
-              ? 'https://github.com/oven-sh/bun/releases/download/bun-v$version/bun-linux-aarch64.zip'
-              : 'https://github.com/oven-sh/bun/releases/download/bun-v$version/bun-linux-x64.zip';
+          return isArm ? 'bun-linux-aarch64.zip' : 'bun-linux-x64.zip';
         }
       },
     ),
   };
 
+  /// Progress, completion and failures of bun downloads
+  static Stream<BunDownloadEvent> get downloadEvents => _downloadEvents.stream;
+
   Future<String> _getBinariesDirectory() async {
-    final appDir = await getApplicationSupportDirectory();
+    final appDir = await AppPaths.supportDirectory();
     final binDir = Directory(path.join(appDir.path, 'binaries'));
     if (!await binDir.exists()) {
       await binDir.create(recursive: true);
@@ -66,58 +155,239 @@ class BinaryManager {
     return abi == Abi.macosArm64 || abi == Abi.linuxArm64 || abi == Abi.windowsArm64;
   }
 
-  Future<String> _getBinaryPath(BinaryType type) async {
-    final cachedPath = _cachedPaths[type];
+  Future<String> _getBinaryPath(BinaryType type, {String? version}) async {
+    final config = _configs[type]!;
+    final resolvedVersion = version ?? config.version;
+    final cacheKey = '${config.name}-$resolvedVersion';
+
+    final cachedPath = _cachedPaths[cacheKey];
     if (cachedPath != null && await File(cachedPath).exists()) {
       return cachedPath;
     }
 
-    final config = _configs[type]!;
     final binDir = await _getBinariesDirectory();
-    final binaryDir = path.join(binDir, '${config.name}-${config.version}');
+    final binaryDir = path.join(binDir, cacheKey);
 
     final executableName = Platform.isWindows ? '${config.name}.exe' : config.name;
     final executablePath = path.join(binaryDir, executableName);
 
     if (await File(executablePath).exists()) {
-      _cachedPaths[type] = executablePath;
+      _cachedPaths[cacheKey] = executablePath;
       return executablePath;
     }
 
-    // Download binary
-    await _downloadBinary(type, binaryDir);
-    _cachedPaths[type] = executablePath;
+    // Download binary, reusing a download already in progress
+    final download = _pendingDownloads[cacheKey] ??= _downloadAndReport(type, resolvedVersion, binaryDir)
+        .whenComplete(() => _pendingDownloads.remove(cacheKey));
+    await download;
+    _cachedPaths[cacheKey] = executablePath;
     return executablePath;
   }
 
-  Future<String> getBunPath() async {
-    return _getBinaryPath(BinaryType.bun);
+  Future<void> _downloadAndReport(BinaryType type, String version, String targetDir) async {
+    try {
+      await _downloadBinary(type, version, targetDir);
+      _downloadEvents.add(BunReady(version));
+    } catch (e) {
+      _downloadEvents.add(BunDownloadFailed(version, e.toString().replaceFirst('Exception: ', '')));
+      rethrow;
+    }
+  }
+
+  /// Download the managed bun of [version] unless it's already present.
+  /// Used to retry after a [BunDownloadFailed].
+  Future<String> downloadBun(String version) {
+    return _getBinaryPath(BinaryType.bun, version: version);
+  }
+
+  /// Path to bun according to the configured [BunMode], downloading it
+  /// first if needed. Uses the bundled default version unless [version] is given.
+  Future<String> getBunPath({String? version}) async {
+    if (bunOverride case final bun?) {
+      return bun;
+    }
+
+    final preferences = PreferencesService();
+    final mode = BunMode.values.asNameMap()[await preferences.getBunMode()] ?? BunMode.auto;
+
+    switch (mode) {
+      case BunMode.custom:
+        final customPath = await preferences.getBunCustomPath();
+        if (customPath == null || customPath.isEmpty) {
+          throw Exception('No custom bun path configured. Set one in Settings.');
+        }
+        if (!await File(customPath).exists()) {
+          throw Exception('Custom bun not found at $customPath');
+        }
+        return customPath;
+      case BunMode.auto:
+        final systemBun = await findSystemBun();
+        // A project pinning another version still gets a download of that version
+        if (systemBun != null && (version == null || systemBun.version == version)) {
+          return systemBun.path;
+        }
+        break;
+      case BunMode.download:
+        break;
+    }
+
+    return _getBinaryPath(BinaryType.bun, version: version);
+  }
+
+  /// Look up bun on PATH, caching the result until [clearCache]
+  Future<SystemBun?> findSystemBun() {
+    return _systemBun ??= _detectSystemBun();
   }
 
-  Future<void> _downloadBinary(BinaryType type, String targetDir) async {
+  Future<SystemBun?> _detectSystemBun() async {
+    try {
+      final which = await Process.run(
+        Platform.isWindows ? 'where' : 'which',
+        ['bun'],
+        runInShell: Platform.isWindows,
+      );
+      if (which.exitCode != 0) {
+        return null;
+      }
+
+      final bunPath = (which.stdout as String).split('\n').first.trim();
+      if (bunPath.isEmpty) {
+        return null;
+      }
+
+      final versionResult = await Process.run(bunPath, ['--version']);
+      if (versionResult.exitCode != 0) {
+        return null;
+      }
+      return SystemBun(bunPath, (versionResult.stdout as String).trim());
+    } catch (e) {
+      return null;
+    }
+  }
+
+  /// Bun version a project asks for, from the `packageManager` field of
+  /// package.json (e.g. "bun@1.1.38") or a `.bun-version` file.
+  /// Returns null when the project doesn't pin an exact version.
+  Future<String?> getProjectBunVersion(String projectPath) async {
+    try {
+      final packageJsonFile = File(path.join(projectPath, 'package.json'));
+      if (await packageJsonFile.exists()) {
+        final json = jsonDecode(await packageJsonFile.readAsString());
+        final packageManager = json is Map<String, dynamic> ? json['packageManager'] : null;
+        if (packageManager is String && packageManager.startsWith('bun@')) {
+          // Strip the optional integrity hash: bun@1.1.38+sha512.abc
+          final version = packageManager.substring('bun@'.length).split('+').first;
+          final match = _exactVersionRegex.firstMatch(version.trim());
+          if (match != null) {
+            return match.group(1);
+          }
+        }
+      }
+
+      final bunVersionFile = File(path.join(projectPath, '.bun-version'));
+      if (await bunVersionFile.exists()) {
+        final match = _exactVersionRegex.firstMatch((await bunVersionFile.readAsString()).trim());
+        if (match != null) {
+          return match.group(1);
+        }
+      }
+    } catch (e) {
+      // Unreadable manifest, fall back to the default version
+    }
+    return null;
+  }
+
+  Future<void> _downloadBinary(BinaryType type, String version, String targetDir) async {
     final config = _configs[type]!;
     final dir = Directory(targetDir);
     if (!await dir.exists()) {
       await dir.create(recursive: true);
     }
 
-    final isArm = _isArm64();
-    final downloadUrl = config.getDownloadUrl(config.version, isArm);
-    final tempFile = path.join(targetDir, '${config.name}.${config.archiveExtension}');
+    final preferences = PreferencesService();
+    final mirror = (await preferences.getBunMirror())?.trim();
+    final releasesUrl = mirror == null || mirror.isEmpty ? config.releasesUrl : mirror;
+    final releaseTag = config.getReleaseTag(version);
+    final archiveName = config.getArchiveName(_isArm64());
+    final tempFile = File(path.join(targetDir, '${config.name}.${config.archiveExtension}'));
 
-    // Download
-    final response = await http.get(Uri.parse(downloadUrl));
-    if (response.statusCode != 200) {
-      throw Exception('Failed to download ${config.name}: ${response.statusCode}');
+    final source = _isHttpUrl(releasesUrl)
+        ? _HttpReleaseSource(releasesUrl, releaseTag, await preferences.getDownloadProxy())
+        : _DirectoryReleaseSource(releasesUrl, releaseTag);
+    try {
+      final checksums = await source.readText(config.checksumsName);
+      final expectedChecksum = _findChecksum(checksums, archiveName);
+
+      // Stream to disk, hashing as we go
+      final (stream, total) = await source.open(archiveName);
+      final digest = _DigestSink();
+      final hashInput = sha256.startChunkedConversion(digest);
+      final fileSink = tempFile.openWrite();
+      var received = 0;
+      var lastReported = 0;
+      _downloadEvents.add(BunDownloadProgress(version, 0, total));
+      try {
+        await for (final chunk in stream) {
+          fileSink.add(chunk);
+          hashInput.add(chunk);
+          received += chunk.length;
+          if (received - lastReported >= _progressStepBytes) {
+            lastReported = received;
+            _downloadEvents.add(BunDownloadProgress(version, received, total));
+          }
+        }
+      } finally {
+        await fileSink.close();
+      }
+      hashInput.close();
+      _downloadEvents.add(BunDownloadProgress(version, received, total ?? received));
+
+      // Verify
+      _downloadEvents.add(BunDownloadVerifying(version));
+      final actualChecksum = digest.value.toString();
+      if (actualChecksum != expectedChecksum) {
+        throw Exception(
+          'Checksum mismatch for $archiveName: expected $expectedChecksum, got $actualChecksum',
+        );
+      }
+
+      // Extract
+      await _extractArchive(tempFile.path, targetDir, config);
+    } on SocketException catch (e) {
+      throw Exception(_describeUnreachable(releasesUrl, e.message));
+    } on HandshakeException catch (e) {
+      throw Exception(_describeUnreachable(releasesUrl, e.message));
+    } on http.ClientException catch (e) {
+      throw Exception(_describeUnreachable(releasesUrl, e.message));
+    } finally {
+      source.close();
+      // Clean up
+      if (await tempFile.exists()) {
+        await tempFile.delete();
+      }
     }
+  }
 
-    await File(tempFile).writeAsBytes(response.bodyBytes);
+  static bool _isHttpUrl(String location) {
+    return location.startsWith('http://') || location.startsWith('https://');
+  }
 
-    // Extract
-    await _extractArchive(tempFile, targetDir, config);
+  static String _describeUnreachable(String releasesUrl, String reason) {
+    final where = _isHttpUrl(releasesUrl) ? Uri.parse(releasesUrl).host : releasesUrl;
+    return "Couldn't reach $where ($reason). Behind a proxy or without access to GitHub? "
+        'Set a proxy or mirror in Settings, or use a bun already installed.';
+  }
 
-    // Clean up
-    await File(tempFile).delete();
+  /// SHA256 of [archiveName] from a SHASUMS256.txt listing
+  String _findChecksum(String checksums, String archiveName) {
+    // Lines look like "<sha256>  bun-linux-x64.zip"
+    for (final line in const LineSplitter().convert(checksums)) {
+      final parts = line.trim().split(RegExp(r'\s+'));
+      if (parts.length == 2 && parts[1].replaceFirst('*', '') == archiveName) {
+        return parts[0].toLowerCase();
+      }
+    }
+    throw Exception('No checksum published for $archiveName');
   }
 
   Future<void> _extractArchive(String archivePath, String targetDir, BinaryConfig config) async {
@@ -152,6 +422,111 @@ class BinaryManager {
   }
 
   void clearCache() {
-    _cachedPaths[BinaryType.bun] = null;
+    _cachedPaths.clear();
+    _systemBun = null;
+  }
+}
+
+/// Where release files are read from: GitHub, an HTTP mirror or a local directory
+sealed class _ReleaseSource {
+  Future<String> readText(String fileName);
+
+  /// Content of a release file and its size when known
+  Future<(Stream<List<int>>, int?)> open(String fileName);
+
+  void close() {}
+}
+
+class _HttpReleaseSource extends _ReleaseSource {
+  final String releaseUrl;
+  final http.Client _client;
+
+  _HttpReleaseSource(String releasesUrl, String releaseTag, String? proxy)
+      : releaseUrl = '${releasesUrl.replaceFirst(RegExp(r'/+$'), '')}/$releaseTag',
+        _client = IOClient(_createHttpClient(proxy));
+
+  /// Use the configured proxy, or HTTPS_PROXY/HTTP_PROXY/NO_PROXY from the environment
+  static HttpClient _createHttpClient(String? proxy) {
+    final httpClient = HttpClient();
+    if (proxy == null || proxy.trim().isEmpty) {
+      httpClient.findProxy = HttpClient.findProxyFromEnvironment;
+      return httpClient;
+    }
+
+    final proxyUri = Uri.parse(proxy.contains('://') ? proxy.trim() : 'http://${proxy.trim()}');
+    httpClient.findProxy = (_) => 'PROXY ${proxyUri.host}:${proxyUri.port}';
+    if (proxyUri.userInfo.isNotEmpty) {
+      final [user, password] = '${proxyUri.userInfo}:'.split(':').take(2).toList();
+      httpClient.addProxyCredentials(
+        proxyUri.host,
+        proxyUri.port,
+        '',
+        HttpClientBasicCredentials(Uri.decodeComponent(user), Uri.decodeComponent(password)),
+      );
+    }
+    return httpClient;
+  }
+
+  @override
+  Future<String> readText(String fileName) async {
+    final response = await _client.get(Uri.parse('$releaseUrl/$fileName'));
+    if (response.statusCode != 200) {
+      throw Exception('Failed to fetch $releaseUrl/$fileName: HTTP ${response.statusCode}');
+    }
+    return response.body;
+  }
+
+  @override
+  Future<(Stream<List<int>>, int?)> open(String fileName) async {
+    final response = await _client.send(http.Request('GET', Uri.parse('$releaseUrl/$fileName')));
+    if (response.statusCode != 200) {
+      throw Exception('Failed to download $releaseUrl/$fileName: HTTP ${response.statusCode}');
+    }
+    return (response.stream, response.contentLength);
+  }
+
+  @override
+  void close() {
+    _client.close();
   }
 }
+
+/// A directory laid out like the releases, e.g. <dir>/bun-v1.3.2/bun-linux-x64.zip
+class _DirectoryReleaseSource extends _ReleaseSource {
+  final String releaseDir;
+
+  _DirectoryReleaseSource(String directory, String releaseTag)
+      : releaseDir = path.join(directory, releaseTag);
+
+  File _file(String fileName) {
+    final file = File(path.join(releaseDir, fileName));
+    if (!file.existsSync()) {
+      throw Exception('${file.path} not found in the bun mirror directory');
+    }
+    return file;
+  }
+
+  @override
+  Future<String> readText(String fileName) => _file(fileName).readAsString();
+
+  @override
+  Future<(Stream<List<int>>, int?)> open(String fileName) async {
+    final file = _file(fileName);
+    return (file.openRead(), await file.length());
+  }
+}
+
+/// Receives the digest produced by a chunked hash conversion
+class _DigestSink implements Sink<Digest> {
+  Digest? _value;
+
+  Digest get value => _value!;
+
+  @override
+  void add(Digest data) {
+    _value = data;
+  }
+
+  @override
+  void close() {}
+}
@@ -0,0 +1,301 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+import 'dart:math';
+
+import 'package:flutter/foundation.dart';
+import 'package:path/path.dart' as path;
+
+import '../config/constants.dart';
+import '../models/project.dart';
+import 'app_paths.dart';
+import 'preferences_service.dart';
+
+/// A command from the command line or the local API that can't be carried
+/// out, with the reason shown to the user
+class ControlException implements Exception {
+  final String message;
+
+  const ControlException(this.message);
+
+  @override
+  String toString() => message;
+}
+
+/// Lets the command line and other tools drive the running bob: a local HTTP
+/// server taking a bearer token. Its port and token are written to a file in
+/// the data directory for `bob list`, `bob run` and friends to find.
+///
+/// Routes:
+/// - `GET /projects` lists projects and the status of their tasks
+/// - `GET /projects/<project>/tasks/<task>` tells the status of a task
+/// - `GET /projects/<project>/tasks/<task>/output?lines=<n>` adds its last
+///   lines of output, without colors
+/// - `POST /projects/<project>/tasks/<task>/start` and `.../stop`
+/// - `POST /window/show` shows the window of a headless bob
+/// - `GET /projects/<project>/tasks/<task>/logs`, as a WebSocket, sends the
+///   output of a task so far and then as it comes
+/// - `GET /projects/<project>/tasks/<task>/view` is a page following it
+///
+/// Browsers can't set headers on WebSockets or pages they open, so the
+/// read-only `logs` and `view` routes also take the token from a `token`
+/// query parameter. Only the command line knows the port and token unless the
+/// local API is turned on in the settings, which fixes both, and can open it
+/// to the local network over plain HTTP, after a warning.
+class ControlServer {
+  final Future<Object?> Function(String command, Map<String, String> arguments) _handle;
+  HttpServer? _server;
+
+  /// Whether this bob was started with `--headless`, told to the command
+  /// line so that starting bob again shows its window
+  static bool headless = false;
+
+  ControlServer(this._handle);
+
+  /// Where the running bob says how to reach it
+  static Future<File> controlFile() async {
+    final directory = await AppPaths.supportDirectory();
+    return File(path.join(directory.path, 'control.json'));
+  }
+
+  /// A random token for bearer authentication
+  static String newToken() {
+    final random = Random.secure();
+    return List.generate(16, (_) => random.nextInt(256).toRadixString(16).padLeft(2, '0')).join();
+  }
+
+  /// Whether [given] is [expected], taking as long whatever differs so the
+  /// token can't be guessed from how fast a request is refused
+  static bool _sameToken(String? given, String expected) {
+    if (given == null || given.length != expected.length) {
+      return false;
+    }
+    var difference = 0;
+    for (var i = 0; i < expected.length; i++) {
+      difference |= given.codeUnitAt(i) ^ expected.codeUnitAt(i);
+    }
+    return difference == 0;
+  }
+
+  /// Path segments of the route carrying out a command, the other way from
+  /// [route]
+  static (String, List<String>) routeOf(String command, Map<String, String> arguments) {
+    final task = ['projects', arguments['project'] ?? '', 'tasks', arguments['task'] ?? ''];
+    return switch (command) {
+      'list' => ('GET', ['projects']),
+      'status' => ('GET', task),
+      'output' => ('GET', [...task, 'output']),
+      'run' => ('POST', [...task, 'start']),
+      'stop' => ('POST', [...task, 'stop']),
+      'show' => ('POST', ['window', 'show']),
+      _ => throw ControlException('Unknown command $command'),
+    };
+  }
+
+  /// The command and arguments a request asks for, null for unknown routes
+  @visibleForTesting
+  static (String, Map<String, String>)? route(String method, List<String> segments) {
+    return switch ((method, segments)) {
+      ('GET', ['projects']) => ('list', {}),
+      ('GET', ['projects', final project, 'tasks', final task]) => ('status', {'project': project, 'task': task}),
+      ('POST', ['projects', final project, 'tasks', final task, 'start']) => ('run', {'project': project, 'task': task}),
+      ('POST', ['projects', final project, 'tasks', final task, 'stop']) => ('stop', {'project': project, 'task': task}),
+      ('POST', ['window', 'show']) => ('show', {}),
+      ('GET', ['projects', final project, 'tasks', final task, 'output']) => ('output', {'project': project, 'task': task}),
+      ('GET', ['projects', final project, 'tasks', final task, 'logs']) => ('logs', {'project': project, 'task': task}),
+      ('GET', ['projects', final project, 'tasks', final task, 'view']) => ('view', {'project': project, 'task': task}),
+      _ => null,
+    };
+  }
+
+  /// Find a project by name or by path
+  static Project matchProject(List<Project> projects, String query) {
+    final absolute = path.normalize(path.absolute(query));
+    for (final project in projects) {
+      if (project.name == query || path.normalize(project.path) == absolute) {
+        return project;
+      }
+    }
+    throw ControlException('No project named $query');
+  }
+
+  /// Find a task of a project by script name or display name
+  static Task matchTask(Project project, String query) {
+    for (final task in project.tasks) {
+      if (task.name == query || task.alias == query) {
+        return task;
+      }
+    }
+    throw ControlException('${project.name} has no task named $query');
+  }
+
+  /// Listen on a random port, or on the port of the local API when it's on
+  Future<void> start() async {
+    try {
+      final preferences = PreferencesService();
+      final api = await preferences.getControlApi();
+
+      var token = newToken();
+      if (api) {
+        final saved = await preferences.getControlApiToken();
+        if (saved != null) {
+          token = saved;
+        } else {
+          await preferences.setControlApiToken(token);
+        }
+      }
+      final port = api ? await preferences.getControlApiPort() ?? AppConstants.controlApiPort : 0;
+      final address = api && await preferences.getControlApiLan()
+          ? InternetAddress.anyIPv4
+          : InternetAddress.loopbackIPv4;
+
+      final server = await HttpServer.bind(address, port);
+      // Only the user may read the token, so the file is locked down before
+      // it's written. The data directory is the user's own.
+      final file = await controlFile();
+      await file.writeAsString('');
+      if (!Platform.isWindows) {
+        await Process.run('chmod', ['600', file.path]);
+      }
+      await file.writeAsString(jsonEncode({'port': server.port, 'token': token, 'pid': pid, 'headless': headless}));
+      _server = server;
+      server.listen((request) => _serve(request, token));
+    } catch (e) {
+      // The command line falls back to running tasks on its own
+    }
+  }
+
+  Future<void> _serve(HttpRequest request, String token) async {
+    final route = ControlServer.route(request.method, request.uri.pathSegments);
+    final readOnly = route?.$1 == 'logs' || route?.$1 == 'view';
+    final authorized = _sameToken(request.headers.value(HttpHeaders.authorizationHeader), 'Bearer $token') ||
+        (readOnly && _sameToken(request.uri.queryParameters['token'], token));
+    if (route case ('logs', final arguments) when authorized && WebSocketTransformer.isUpgradeRequest(request)) {
+      await _streamLogs(request, arguments);
+      return;
+    }
+
+    final response = request.response;
+    response.headers.contentType = ContentType.json;
+    try {
+      if (!authorized) {
+        response.statusCode = HttpStatus.unauthorized;
+        response.write(jsonEncode({'error': 'Unauthorized'}));
+        return;
+      }
+
+      if (route == null) {
+        response.statusCode = HttpStatus.notFound;
+        response.write(jsonEncode({'error': 'No route for ${request.method} ${request.uri.path}'}));
+        return;
+      }
+
+      final (command, routeArguments) = route;
+      final arguments = {...request.uri.queryParameters, ...routeArguments}..remove('token');
+      if (command == 'view') {
+        response.headers.contentType = ContentType.html;
+        response.write(_viewerPage);
+        return;
+      }
+      if (command == 'logs') {
+        throw const ControlException('Logs stream over a WebSocket');
+      }
+      response.write(jsonEncode(await _handle(command, arguments)));
+    } on ControlException catch (e) {
+      response.statusCode = HttpStatus.badRequest;
+      response.write(jsonEncode({'error': e.message}));
+    } catch (e) {
+      response.statusCode = HttpStatus.internalServerError;
+      response.write(jsonEncode({'error': e.toString()}));
+    } finally {
+      await response.close();
+    }
+  }
+
+  /// Send the output of a task so far, then as it comes, until the client
+  /// goes away
+  Future<void> _streamLogs(HttpRequest request, Map<String, String> arguments) async {
+    final Object? watched;
+    try {
+      watched = await _handle('logs', arguments);
+    } catch (e) {
+      request.response.statusCode = e is ControlException ? HttpStatus.badRequest : HttpStatus.internalServerError;
+      request.response.write(e.toString());
+      await request.response.close();
+      return;
+    }
+
+    final (backlog, updates) = watched as (String, Stream<String>);
+    final WebSocket socket;
+    try {
+      socket = await WebSocketTransformer.upgrade(request);
+    } catch (e) {
+      // The client went away before the handshake
+      return;
+    }
+    socket.add(backlog);
+    // The client sees the end of the output too, e.g. when bob quits
+    final subscription = updates.listen(socket.add, onDone: socket.close);
+    socket.listen(
+      (_) {},
+      onDone: subscription.cancel,
+      onError: (_) => subscription.cancel(),
+      cancelOnError: true,
+    );
+  }
+
+  /// Listen again, after the settings of the local API changed
+  Future<void> restart() async {
+    await stop();
+    await start();
+  }
+
+  Future<void> stop() async {
+    final server = _server;
+    if (server == null) {
+      return;
+    }
+    _server = null;
+    await server.close(force: true);
+    try {
+      await (await controlFile()).delete();
+    } catch (e) {
+      // Already gone
+    }
+  }
+}
+
+// Follows the output of a task from a browser, through the WebSocket next to
+// the page. Colors are dropped, the point is reading along from a phone.
+const _viewerPage = r'''<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>bob</title>
+<style>
+  body { margin: 0; background: #1e1e1e; color: #d4d4d4; }
+  #status { position: fixed; top: 0; right: 0; padding: 4px 8px; font: 12px sans-serif; background: #333; }
+  pre { margin: 0; padding: 8px; font: 13px Consolas, Menlo, monospace; white-space: pre-wrap; word-break: break-all; }
+</style>
+</head>
+<body>
+<div id="status">Connecting…</div>
+<pre id="output"></pre>
+<script>
+  const output = document.getElementById('output');
+  const status = document.getElementById('status');
+  const socket = new WebSocket(location.href.replace(/^http/, 'ws').replace('/view?', '/logs?'));
+  socket.onopen = () => { status.textContent = 'Live'; };
+  socket.onclose = () => { status.textContent = 'Disconnected'; };
+  socket.onmessage = (event) => {
+    const following = window.innerHeight + window.scrollY >= document.body.scrollHeight - 20;
+    output.textContent += event.data.replace(/\x1b\[[0-9;?]*[A-Za-z]/g, '');
+    if (following) {
+      window.scrollTo(0, document.body.scrollHeight);
+    }
+  };
+</script>
+</body>
+</html>
+''';
@@ -0,0 +1,94 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+import '../models/project.dart';
+
+enum DevcontainerStatus {
+  /// Never built, or removed since
+  missing('Devcontainer not created'),
+  stopped('Devcontainer stopped'),
+  running('Devcontainer running');
+
+  final String displayName;
+
+  const DevcontainerStatus(this.displayName);
+}
+
+/// Finds and starts the devcontainers of projects. Starting one needs the
+/// devcontainer CLI, which builds them like editors do.
+class DevcontainerService {
+  // Label the devcontainer CLI and editors give the containers they start
+  static const _folderLabel = 'devcontainer.local_folder';
+
+  static Future<bool> exists(String projectPath) {
+    return File(path.join(projectPath, '.devcontainer', 'devcontainer.json')).exists();
+  }
+
+  /// State of the project's devcontainer, null when Docker can't tell
+  Future<DevcontainerStatus?> readStatus(String projectPath) async {
+    try {
+      final result = await Process.run('docker', [
+        'ps',
+        '--all',
+        '--filter',
+        'label=$_folderLabel=$projectPath',
+        '--format',
+        '{{.State}}',
+      ]);
+      if (result.exitCode != 0) {
+        return null;
+      }
+      final states = const LineSplitter().convert(result.stdout as String);
+      if (states.contains('running')) {
+        return DevcontainerStatus.running;
+      }
+      return states.isEmpty ? DevcontainerStatus.missing : DevcontainerStatus.stopped;
+    } on ProcessException {
+      return null;
+    }
+  }
+
+  /// Build and start the project's devcontainer unless it runs already,
+  /// streaming what the devcontainer CLI prints. Returns where to run tasks.
+  Future<DockerConfig> up(String projectPath, Function(String) onOutput) async {
+    final Process process;
+    try {
+      process = await Process.start(
+        'devcontainer',
+        ['up', '--workspace-folder', projectPath],
+        runInShell: Platform.isWindows,
+      );
+    } on ProcessException {
+      throw Exception('Install the devcontainer CLI to run tasks in the devcontainer: '
+          'npm install -g @devcontainers/cli');
+    }
+
+    // The CLI logs on stderr and answers with a JSON line on stdout
+    final logs = process.stderr.transform(utf8.decoder).listen(onOutput);
+    final answer = await process.stdout.transform(utf8.decoder).join();
+    final exitCode = await process.exitCode;
+    await logs.cancel();
+
+    final result = const LineSplitter()
+        .convert(answer)
+        .map((line) {
+          try {
+            return jsonDecode(line);
+          } on FormatException {
+            return null;
+          }
+        })
+        .whereType<Map<String, dynamic>>()
+        .lastOrNull;
+    if (exitCode != 0 || result == null || result['outcome'] != 'success') {
+      throw Exception(result?['message'] ?? 'devcontainer up exited with code $exitCode');
+    }
+    return DockerConfig(
+      container: result['containerId'] as String,
+      workdir: result['remoteWorkspaceFolder'] as String? ??
+          '/workspaces/${path.basename(projectPath)}',
+    );
+  }
+}
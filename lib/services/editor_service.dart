@@ -0,0 +1,121 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+import '../models/result.dart';
+import '../utils/process_utils.dart';
+import 'preferences_service.dart';
+
+class _KnownEditor {
+  final String name;
+  final String executable;
+  final List<String> arguments;
+
+  const _KnownEditor(this.name, this.executable, this.arguments);
+}
+
+/// Opens projects and file locations in the user's code editor
+class EditorService {
+  final PreferencesService _preferencesService = PreferencesService();
+
+  // Editors tried in order when no command is configured.
+  // {location} is replaced with path, path:line or path:line:column.
+  static const List<_KnownEditor> _knownEditors = [
+    _KnownEditor('VS Code', 'code', ['-g', '{location}']),
+    _KnownEditor('Cursor', 'cursor', ['-g', '{location}']),
+    _KnownEditor('Zed', 'zed', ['{location}']),
+    _KnownEditor('Sublime Text', 'subl', ['{location}']),
+  ];
+
+  /// Open a project directory in the editor
+  Future<Result<void>> openProject(String projectPath) {
+    return _open(projectPath);
+  }
+
+  /// Open a file location reported in task output. Relative paths are
+  /// resolved against the project directory.
+  Future<Result<void>> openLocation(
+    String projectPath,
+    String filePath, {
+    int? line,
+    int? column,
+  }) async {
+    final resolved = path.normalize(
+      path.isAbsolute(filePath) ? filePath : path.join(projectPath, filePath),
+    );
+    if (!await File(resolved).exists()) {
+      return Result.failure('File not found: $resolved');
+    }
+
+    var location = resolved;
+    if (line != null) {
+      location += ':$line';
+      if (column != null) {
+        location += ':$column';
+      }
+    }
+    return _open(location);
+  }
+
+  /// Name of the editor that will be used when none is configured, if any
+  Future<String?> detectEditor() async {
+    final editor = await _detectKnownEditor();
+    return editor?.name;
+  }
+
+  Future<Result<void>> _open(String location) async {
+    final String executable;
+    final List<String> arguments;
+
+    final command = (await _preferencesService.getEditorCommand())?.trim() ?? '';
+    if (command.isNotEmpty) {
+      final parts = ProcessUtils.splitCommand(command);
+      executable = parts.first;
+      final rest = parts.skip(1).toList();
+      arguments = rest.contains('{location}')
+          ? rest.map((arg) => arg == '{location}' ? location : arg).toList()
+          : [...rest, location];
+    } else {
+      final editor = await _detectKnownEditor();
+      if (editor == null) {
+        return Result.failure('No editor found. Set an editor command in Settings.');
+      }
+      executable = editor.executable;
+      arguments = editor.arguments.map((arg) => arg == '{location}' ? location : arg).toList();
+    }
+
+    try {
+      await Process.start(
+        executable,
+        arguments,
+        mode: ProcessStartMode.detached,
+        runInShell: Platform.isWindows,
+      );
+      return Result.success(null);
+    } catch (e) {
+      return Result.failure('Failed to start $executable: $e');
+    }
+  }
+
+  Future<_KnownEditor?> _detectKnownEditor() async {
+    for (final editor in _knownEditors) {
+      if (await _isOnPath(editor.executable)) {
+        return editor;
+      }
+    }
+    return null;
+  }
+
+  Future<bool> _isOnPath(String executable) async {
+    try {
+      final result = await Process.run(
+        Platform.isWindows ? 'where' : 'which',
+        [executable],
+        runInShell: Platform.isWindows,
+      );
+      return result.exitCode == 0;
+    } catch (e) {
+      return false;
+    }
+  }
+}
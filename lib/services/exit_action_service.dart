@@ -0,0 +1,160 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:http/http.dart' as http;
+
+import '../config/constants.dart';
+import '../models/project.dart';
+import '../models/task_settings.dart';
+import '../utils/process_utils.dart';
+import '../utils/task_utils.dart';
+
+/// Fires the webhooks and commands set to run when a task exits
+class ExitActionService {
+  // Environment variable holding the value of each placeholder, for commands
+  static const _variables = {
+    'project': 'BOB_PROJECT',
+    'path': 'BOB_PATH',
+    'task': 'BOB_TASK',
+    'status': 'BOB_STATUS',
+    'exitCode': 'BOB_EXIT_CODE',
+    'duration': 'BOB_DURATION',
+  };
+
+  static final _placeholder = RegExp(r'\{(\w+)\}');
+
+  /// Fill `{name}` placeholders of a template, leaving unknown ones as they are
+  static String expand(String template, Map<String, String> values) {
+    return template.replaceAllMapped(
+      _placeholder,
+      (match) => values[match.group(1)] ?? match.group(0)!,
+    );
+  }
+
+  /// [template] with its placeholders turned into references to the
+  /// variables holding their values, double-quoted so that spaces and
+  /// wildcards in them stay as they are. Within single quotes, where sh
+  /// expands nothing, the quotes are closed around the reference.
+  static String _commandFor(String template) {
+    final command = StringBuffer();
+    String? quote;
+    var index = 0;
+    while (index < template.length) {
+      final match = _placeholder.matchAsPrefix(template, index);
+      final variable = match == null ? null : _variables[match.group(1)];
+      if (variable != null) {
+        // cmd expands !NAME! only after parsing the command, unlike %NAME%
+        final reference = Platform.isWindows ? '!$variable!' : '\${$variable}';
+        command.write(switch (quote) {
+          '"' => reference,
+          "'" => "'\"$reference\"'",
+          _ => '"$reference"',
+        });
+        index = match!.end;
+        continue;
+      }
+
+      final char = template[index];
+      if (char == '\\' && quote != "'" && !Platform.isWindows && index + 1 < template.length) {
+        // An escaped quote neither opens nor closes quotes
+        command.write(template.substring(index, index + 2));
+        index += 2;
+        continue;
+      }
+      if (quote == null && (char == '"' || (char == "'" && !Platform.isWindows))) {
+        quote = char;
+      } else if (char == quote) {
+        quote = null;
+      }
+      command.write(char);
+      index++;
+    }
+    return command.toString();
+  }
+
+  /// Carry out [action] for a run of [task] that exited with [exitCode].
+  /// Returns what went wrong, or null when it went fine.
+  Future<String?> run(
+    ExitAction action,
+    Project project,
+    Task task,
+    int exitCode,
+    Duration? duration,
+  ) async {
+    final status = exitCode == 0 ? 'success' : 'failed';
+    final values = {
+      'project': project.name,
+      'path': project.path,
+      'task': task.name,
+      'status': status,
+      'exitCode': '$exitCode',
+      'duration': duration != null ? TaskUtils.formatDuration(duration) : '',
+    };
+    // Shown in errors. Values go into URLs encoded, and into commands as
+    // references to environment variables, so they can't break out.
+    final target = expand(action.target, values);
+
+    try {
+      switch (action.kind) {
+        case ExitActionKind.webhook:
+          // A text field makes the payload readable by Slack and Discord as is
+          final response = await http
+              .post(
+                Uri.parse(expand(
+                  action.target,
+                  values.map((name, value) => MapEntry(name, Uri.encodeComponent(value))),
+                )),
+                headers: {'Content-Type': 'application/json'},
+                body: jsonEncode({
+                  'text': '${task.name} of ${project.name} '
+                      '${exitCode == 0 ? 'succeeded' : 'failed with exit code $exitCode'}'
+                      '${duration != null ? ' after ${values['duration']}' : ''}',
+                  'project': project.name,
+                  'path': project.path,
+                  'task': task.name,
+                  'status': status,
+                  'exitCode': exitCode,
+                  if (duration != null) 'durationMs': duration.inMilliseconds,
+                }),
+              )
+              .timeout(AppConstants.webhookTimeout);
+          if (response.statusCode >= 300) {
+            return 'Webhook answered ${response.statusCode}';
+          }
+        case ExitActionKind.command:
+          final command = _commandFor(action.target);
+          final process = await Process.start(
+            Platform.isWindows ? 'cmd' : '/bin/sh',
+            Platform.isWindows ? [if (command != action.target) '/v:on', '/c', command] : ['-c', command],
+            workingDirectory: project.path,
+            environment: {
+              for (final MapEntry(key: name, value: variable) in _variables.entries) variable: values[name]!,
+              if (duration != null) 'BOB_DURATION_MS': '${duration.inMilliseconds}',
+            },
+          );
+          final stdoutDone = process.stdout.drain<void>();
+          final errorOutput = process.stderr.transform(utf8.decoder).join();
+          var timedOut = false;
+          final commandExitCode = await process.exitCode.timeout(
+            AppConstants.exitCommandTimeout,
+            onTimeout: () {
+              timedOut = true;
+              // Its children too, or they'd keep the output open
+              return ProcessUtils.killProcessTree(process.pid).then((_) => process.exitCode);
+            },
+          );
+          if (timedOut) {
+            return '$target took longer than ${AppConstants.exitCommandTimeout.inSeconds}s and was stopped';
+          }
+          await stdoutDone;
+          if (commandExitCode != 0) {
+            final error = (await errorOutput).trim();
+            return '$target exited with code $commandExitCode${error.isNotEmpty ? ': $error' : ''}';
+          }
+      }
+      return null;
+    } catch (e) {
+      return '${action.kind == ExitActionKind.webhook ? 'Webhook' : target} failed: $e';
+    }
+  }
+}
@@ -0,0 +1,150 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+class GitStatus {
+  final String gitDirectory;
+  final String branch;
+  final bool isDetached;
+  final bool isDirty;
+  final int ahead;
+  final int behind;
+
+  const GitStatus({
+    required this.gitDirectory,
+    required this.branch,
+    this.isDetached = false,
+    this.isDirty = false,
+    this.ahead = 0,
+    this.behind = 0,
+  });
+
+  GitStatus copyWith({
+    bool? isDirty,
+    int? ahead,
+    int? behind,
+  }) {
+    return GitStatus(
+      gitDirectory: gitDirectory,
+      branch: branch,
+      isDetached: isDetached,
+      isDirty: isDirty ?? this.isDirty,
+      ahead: ahead ?? this.ahead,
+      behind: behind ?? this.behind,
+    );
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      other is GitStatus &&
+      other.gitDirectory == gitDirectory &&
+      other.branch == branch &&
+      other.isDetached == isDetached &&
+      other.isDirty == isDirty &&
+      other.ahead == ahead &&
+      other.behind == behind;
+
+  @override
+  int get hashCode => Object.hash(gitDirectory, branch, isDetached, isDirty, ahead, behind);
+}
+
+/// Reads git information for projects. The branch is read straight from disk;
+/// dirty and ahead/behind state needs git on the PATH and is skipped without it.
+class GitService {
+  /// Find the git directory for a project, looking in parent directories
+  /// so projects inside a monorepo are detected too.
+  /// Handles worktrees and submodules where .git is a file pointing elsewhere.
+  Future<String?> findGitDirectory(String projectPath) async {
+    var current = path.normalize(path.absolute(projectPath));
+
+    while (true) {
+      final dotGit = path.join(current, '.git');
+
+      if (await Directory(dotGit).exists()) {
+        return dotGit;
+      }
+
+      final dotGitFile = File(dotGit);
+      if (await dotGitFile.exists()) {
+        final content = (await dotGitFile.readAsString()).trim();
+        if (content.startsWith('gitdir:')) {
+          final gitDir = content.substring('gitdir:'.length).trim();
+          return path.normalize(path.isAbsolute(gitDir) ? gitDir : path.join(current, gitDir));
+        }
+      }
+
+      final parent = path.dirname(current);
+      if (parent == current) {
+        return null;
+      }
+      current = parent;
+    }
+  }
+
+  /// Read the git status of a project, or null if it isn't in a git repository
+  Future<GitStatus?> readStatus(String projectPath) async {
+    final GitStatus status;
+    try {
+      final gitDirectory = await findGitDirectory(projectPath);
+      if (gitDirectory == null) {
+        return null;
+      }
+
+      final head = (await File(path.join(gitDirectory, 'HEAD')).readAsString()).trim();
+      if (head.startsWith('ref:')) {
+        final ref = head.substring('ref:'.length).trim();
+        final branch = ref.startsWith('refs/heads/') ? ref.substring('refs/heads/'.length) : ref;
+        status = GitStatus(gitDirectory: gitDirectory, branch: branch);
+      } else {
+        // Detached HEAD contains a commit hash
+        status = GitStatus(
+          gitDirectory: gitDirectory,
+          branch: head.length > 7 ? head.substring(0, 7) : head,
+          isDetached: true,
+        );
+      }
+    } catch (e) {
+      return null;
+    }
+
+    return _readWorkingTreeStatus(projectPath, status);
+  }
+
+  /// Add dirty and ahead/behind information using `git status`
+  Future<GitStatus> _readWorkingTreeStatus(String projectPath, GitStatus status) async {
+    try {
+      // --no-optional-locks keeps git from rewriting the index, which would
+      // otherwise trigger our own watcher and refresh again
+      final result = await Process.run(
+        'git',
+        ['--no-optional-locks', 'status', '--porcelain=v2', '--branch'],
+        workingDirectory: projectPath,
+        runInShell: Platform.isWindows,
+      );
+      if (result.exitCode != 0) {
+        return status;
+      }
+
+      var isDirty = false;
+      var ahead = 0;
+      var behind = 0;
+      for (final line in (result.stdout as String).split('\n')) {
+        if (line.startsWith('# branch.ab ')) {
+          // Format: # branch.ab +<ahead> -<behind>
+          final parts = line.substring('# branch.ab '.length).split(' ');
+          if (parts.length == 2) {
+            ahead = int.tryParse(parts[0].replaceFirst('+', '')) ?? 0;
+            behind = int.tryParse(parts[1].replaceFirst('-', '')) ?? 0;
+          }
+        } else if (line.isNotEmpty && !line.startsWith('#')) {
+          isDirty = true;
+        }
+      }
+
+      return status.copyWith(isDirty: isDirty, ahead: ahead, behind: behind);
+    } catch (e) {
+      // git isn't installed, keep the branch only
+      return status;
+    }
+  }
+}
@@ -0,0 +1,41 @@
+import 'package:flutter/foundation.dart';
+import 'package:hotkey_manager/hotkey_manager.dart';
+
+import 'preferences_service.dart';
+
+/// The shortcut summoning the quick launcher, registered with the OS so it
+/// works while another app has focus
+class LauncherHotkeyService {
+  static final LauncherHotkeyService _instance = LauncherHotkeyService._internal();
+  factory LauncherHotkeyService() => _instance;
+  LauncherHotkeyService._internal();
+
+  HotKey? _registered;
+
+  /// Called when the shortcut is pressed
+  VoidCallback? onPressed;
+
+  /// Register the shortcut saved in the preferences, in place of the one
+  /// registered before. Returns what went wrong, or null when it went fine.
+  Future<String?> apply() async {
+    if (_registered case final previous?) {
+      await hotKeyManager.unregister(previous);
+      _registered = null;
+    }
+
+    final json = await PreferencesService().getLauncherHotkey();
+    if (json == null) {
+      return null;
+    }
+
+    try {
+      final hotKey = HotKey.fromJson(json);
+      await hotKeyManager.register(hotKey, keyDownHandler: (_) => onPressed?.call());
+      _registered = hotKey;
+      return null;
+    } catch (e) {
+      // Taken by another app, or out of reach, e.g. under Wayland
+      return 'Could not register the launcher shortcut: $e';
+    }
+  }
+}
@@ -0,0 +1,261 @@
+import 'dart:convert';
+import 'dart:io';
+import 'dart:math';
+
+import 'package:crypto/crypto.dart';
+import 'package:path/path.dart' as path;
+
+import '../config/constants.dart';
+import '../models/project.dart';
+import 'app_paths.dart';
+
+class LogFileInfo {
+  final String path;
+  final String name;
+  final int size;
+  final DateTime modified;
+
+  /// When the run started, from the timestamp in the file name
+  final DateTime? started;
+
+  LogFileInfo({
+    required this.path,
+    required this.name,
+    required this.size,
+    required this.modified,
+    this.started,
+  });
+
+  /// How long the run took, measured up to the last write to the log
+  Duration? get duration =>
+      started != null && modified.isAfter(started!) ? modified.difference(started!) : null;
+}
+
+/// Writes task runs to log files on disk and reads them back in chunks
+class LogFileService {
+  Future<Directory> _getLogsRoot() async {
+    final appDir = await AppPaths.supportDirectory();
+    return Directory(path.join(appDir.path, 'logs'));
+  }
+
+  /// Directory holding the logs of a project, named after the project folder
+  /// with a short hash of its path to keep projects with the same name apart
+  Future<Directory> getProjectLogsDirectory(Project project) async {
+    final root = await _getLogsRoot();
+    final hash = sha1.convert(utf8.encode(project.path)).toString().substring(0, 12);
+    return Directory(path.join(root.path, '${path.basename(project.path)}-$hash'));
+  }
+
+  /// Open a new log file for a task run
+  Future<IOSink> openRunLog(Project project, Task task) async {
+    final directory = await getProjectLogsDirectory(project);
+    if (!await directory.exists()) {
+      await directory.create(recursive: true);
+    }
+
+    final file = File(path.join(directory.path, '${_safeName(task)}-${_timestamp(DateTime.now())}.log'));
+    return file.openWrite(mode: FileMode.append);
+  }
+
+  /// Open the file a popped out task follows, starting over from
+  /// [initialContent]. It sits with the logs without being listed as one.
+  Future<(String, IOSink)> openPopOut(Project project, Task task, String initialContent) async {
+    final directory = await getProjectLogsDirectory(project);
+    if (!await directory.exists()) {
+      await directory.create(recursive: true);
+    }
+
+    final file = File(path.join(directory.path, '${_safeName(task)}.live'));
+    final sink = file.openWrite();
+    sink.write(initialContent);
+    await sink.flush();
+    return (file.path, sink);
+  }
+
+  String _safeName(Task task) => task.name.replaceAll(RegExp(r'[^A-Za-z0-9._-]'), '_');
+
+  /// Whether a log file holds a run of [task], and not of a task whose name
+  /// merely starts the same
+  bool _isRunOf(LogFileInfo log, Task task) =>
+      RegExp('^${RegExp.escape(_safeName(task))}-\\d{8}-\\d{6}\\.log\$').hasMatch(log.name);
+
+  /// Delete the oldest runs of [task] beyond [AppConstants.maxLogRunsPerTask],
+  /// then the oldest runs of the project until its logs fit in
+  /// [AppConstants.maxProjectLogsBytes]
+  Future<void> pruneLogs(Project project, Task task) async {
+    final logs = await listLogs(project);
+    final deleted = {
+      for (final log in logs.where((log) => _isRunOf(log, task)).skip(AppConstants.maxLogRunsPerTask)) log.path,
+    };
+
+    var total = 0;
+    for (final log in logs) {
+      if (deleted.contains(log.path)) {
+        continue;
+      }
+      total += log.size;
+      if (total > AppConstants.maxProjectLogsBytes) {
+        deleted.add(log.path);
+      }
+    }
+
+    for (final logPath in deleted) {
+      try {
+        await File(logPath).delete();
+      } on FileSystemException {
+        // Still open by a running task on Windows, it goes next time
+      }
+    }
+  }
+
+  /// The end of the most recent run of each task of the project that has
+  /// one, starting at a line boundary
+  Future<Map<String, String>> readLastRunTails(Project project) async {
+    final logs = await listLogs(project);
+    final tails = <String, String>{};
+    for (final task in project.tasks) {
+      final last = logs.where((log) => _isRunOf(log, task)).firstOrNull;
+      if (last == null || last.size == 0) {
+        continue;
+      }
+
+      final offset = max(0, last.size - AppConstants.restoredLogTailBytes);
+      var tail = await readChunk(last.path, offset, last.size - offset);
+      if (offset > 0) {
+        tail = tail.substring(tail.indexOf('\n') + 1);
+      }
+      tails[task.name] = tail;
+    }
+    return tails;
+  }
+
+  /// List the log files of a project, most recent first
+  Future<List<LogFileInfo>> listLogs(Project project) async {
+    final directory = await getProjectLogsDirectory(project);
+    if (!await directory.exists()) {
+      return [];
+    }
+
+    final logs = <LogFileInfo>[];
+    await for (final entity in directory.list(followLinks: false)) {
+      if (entity is File && entity.path.endsWith('.log')) {
+        final stat = await entity.stat();
+        logs.add(LogFileInfo(
+          path: entity.path,
+          name: path.basename(entity.path),
+          size: stat.size,
+          modified: stat.modified,
+          started: _parseTimestamp(entity.path),
+        ));
+      }
+    }
+
+    logs.sort((a, b) => b.modified.compareTo(a.modified));
+    return logs;
+  }
+
+  /// Read [length] bytes starting at [offset] without loading the whole file.
+  /// Both ends move on to the next character boundary, so a character cut by
+  /// a chunk boundary is read whole by the chunk before.
+  Future<String> readChunk(String filePath, int offset, int length) async {
+    final file = await File(filePath).open();
+    try {
+      await file.setPosition(offset);
+      // A UTF-8 character is at most 4 bytes, so 3 more finish a cut one
+      final bytes = await file.read(length + 3);
+      var start = 0;
+      while (offset > 0 && start < min(3, bytes.length) && _isContinuationByte(bytes[start])) {
+        start++;
+      }
+      var end = min(length, bytes.length);
+      while (end < bytes.length && _isContinuationByte(bytes[end])) {
+        end++;
+      }
+      return utf8.decode(bytes.sublist(start, end), allowMalformed: true);
+    } finally {
+      await file.close();
+    }
+  }
+
+  static bool _isContinuationByte(int byte) => byte & 0xC0 == 0x80;
+
+  /// Current size of a log, which grows while its task runs
+  Future<int> sizeOf(String filePath) async {
+    return max(0, (await File(filePath).stat()).size);
+  }
+
+  DateTime? _parseTimestamp(String logPath) {
+    final match = RegExp(r'(\d{4})(\d{2})(\d{2})-(\d{2})(\d{2})(\d{2})\.log$').firstMatch(logPath);
+    if (match == null) {
+      return null;
+    }
+    final parts = [for (var i = 1; i <= 6; i++) int.parse(match.group(i)!)];
+    return DateTime(parts[0], parts[1], parts[2], parts[3], parts[4], parts[5]);
+  }
+
+  String _timestamp(DateTime time) {
+    String two(int n) => n.toString().padLeft(2, '0');
+    return '${time.year}${two(time.month)}${two(time.day)}-${two(time.hour)}${two(time.minute)}${two(time.second)}';
+  }
+}
+
+/// Appends output to a file of the user's choosing, moving it aside to
+/// `<file>.1`, `<file>.2` and so on once it grows past
+/// [AppConstants.teeFileMaxBytes]
+class RotatingLogWriter {
+  final String filePath;
+
+  RandomAccessFile? _file;
+  int _size = 0;
+
+  // Writes in order, a failed one doesn't stop the next
+  Future<void> _pending = Future.value();
+
+  RotatingLogWriter(this.filePath);
+
+  void write(String output) {
+    _pending = _pending.then((_) => _write(output)).catchError((_) {});
+  }
+
+  Future<void> _write(String output) async {
+    final bytes = utf8.encode(output);
+    var file = _file ??= await _open();
+    if (_size > 0 && _size + bytes.length > AppConstants.teeFileMaxBytes) {
+      await file.close();
+      await _rotate();
+      file = _file = await _open();
+    }
+    await file.writeFrom(bytes);
+    _size += bytes.length;
+  }
+
+  Future<RandomAccessFile> _open() async {
+    final file = File(filePath);
+    await file.parent.create(recursive: true);
+    final handle = await file.open(mode: FileMode.append);
+    _size = await handle.length();
+    return handle;
+  }
+
+  Future<void> _rotate() async {
+    final oldest = File('$filePath.${AppConstants.teeFileBackups}');
+    if (await oldest.exists()) {
+      await oldest.delete();
+    }
+    for (var i = AppConstants.teeFileBackups - 1; i >= 1; i--) {
+      final backup = File('$filePath.$i');
+      if (await backup.exists()) {
+        await backup.rename('$filePath.${i + 1}');
+      }
+    }
+    await File(filePath).rename('$filePath.1');
+  }
+
+  Future<void> close() {
+    _pending = _pending.then((_) async {
+      await _file?.close();
+      _file = null;
+    }).catchError((_) {});
+    return _pending;
+  }
+}
@@ -0,0 +1,117 @@
+import 'dart:io';
+
+import 'package:flutter/scheduler.dart';
+import 'package:path/path.dart' as path;
+
+import 'app_paths.dart';
+
+/// Durations recorded for one kind of work, summarized in the report
+class _Samples {
+  final List<int> _micros = [];
+
+  void add(Duration duration) => _micros.add(duration.inMicroseconds);
+
+  int get count => _micros.length;
+
+  String summarize() {
+    if (_micros.isEmpty) {
+      return 'no samples';
+    }
+
+    final sorted = List<int>.from(_micros)..sort();
+    String ms(int micros) => '${(micros / 1000).toStringAsFixed(2)}ms';
+    int percentile(double p) => sorted[((sorted.length - 1) * p).round()];
+    final total = sorted.reduce((a, b) => a + b);
+    return '${sorted.length} samples, '
+        'avg ${ms(total ~/ sorted.length)}, '
+        'p50 ${ms(percentile(0.5))}, '
+        'p95 ${ms(percentile(0.95))}, '
+        'max ${ms(sorted.last)}';
+  }
+}
+
+/// Measures frame, state update and task output costs when bob is started
+/// with --perf-report, and writes a report when it quits.
+class PerfMonitor {
+  // Singleton pattern
+  static final PerfMonitor _instance = PerfMonitor._internal();
+  factory PerfMonitor() => _instance;
+  PerfMonitor._internal();
+
+  bool _enabled = false;
+  final Stopwatch _uptime = Stopwatch();
+
+  final _Samples _frameBuild = _Samples();
+  final _Samples _frameRaster = _Samples();
+  final _Samples _notify = _Samples();
+  final _Samples _outputAppend = _Samples();
+  int _outputCharacters = 0;
+  int _jankyFrames = 0;
+
+  bool get enabled => _enabled;
+
+  void start() {
+    if (_enabled) {
+      return;
+    }
+    _enabled = true;
+    _uptime.start();
+    SchedulerBinding.instance.addTimingsCallback(_onFrameTimings);
+  }
+
+  void _onFrameTimings(List<FrameTiming> timings) {
+    for (final timing in timings) {
+      _frameBuild.add(timing.buildDuration);
+      _frameRaster.add(timing.rasterDuration);
+      if (timing.totalSpan > const Duration(microseconds: 16667)) {
+        _jankyFrames++;
+      }
+    }
+  }
+
+  /// Time spent notifying listeners, including every Selector re-evaluating
+  void recordNotify(Duration duration) {
+    if (_enabled) {
+      _notify.add(duration);
+    }
+  }
+
+  /// Time spent appending a chunk of task output to its buffer
+  void recordOutput(int characters, Duration duration) {
+    if (_enabled) {
+      _outputCharacters += characters;
+      _outputAppend.add(duration);
+    }
+  }
+
+  String report() {
+    final seconds = _uptime.elapsedMilliseconds / 1000;
+    final throughput = seconds > 0 ? (_outputCharacters / seconds).round() : 0;
+    return [
+      'bob performance report',
+      'Recorded for ${seconds.toStringAsFixed(1)}s',
+      '',
+      'Frame build:     ${_frameBuild.summarize()}',
+      'Frame raster:    ${_frameRaster.summarize()}',
+      'Frames over 16ms: $_jankyFrames of ${_frameBuild.count}',
+      'Notify:          ${_notify.summarize()}',
+      'Output append:   ${_outputAppend.summarize()}',
+      'Output received: $_outputCharacters characters, $throughput/s on average',
+      '',
+    ].join('\n');
+  }
+
+  /// Write the report next to the logs and return its path
+  Future<String?> dump() async {
+    if (!_enabled) {
+      return null;
+    }
+
+    final appDir = await AppPaths.supportDirectory();
+    final timestamp = DateTime.now().toIso8601String().replaceAll(':', '-').split('.').first;
+    final file = File(path.join(appDir.path, 'perf-$timestamp.txt'));
+    await file.writeAsString(report());
+    stdout.writeln('Performance report written to ${file.path}');
+    return file.path;
+  }
+}
@@ -0,0 +1,130 @@
+import 'dart:convert';
+import 'dart:io';
+
+import 'package:shared_preferences/shared_preferences.dart';
+
+/// Key-value storage behind [PreferencesService]
+abstract class PreferenceStore {
+  Object? get(String key);
+  bool? getBool(String key);
+  int? getInt(String key);
+  double? getDouble(String key);
+  String? getString(String key);
+  List<String>? getStringList(String key);
+
+  Future<bool> setBool(String key, bool value);
+  Future<bool> setInt(String key, int value);
+  Future<bool> setDouble(String key, double value);
+  Future<bool> setString(String key, String value);
+  Future<bool> setStringList(String key, List<String> value);
+  Future<bool> remove(String key);
+}
+
+/// Preferences where the OS keeps them for apps
+class SharedPreferenceStore implements PreferenceStore {
+  final SharedPreferences _prefs;
+
+  SharedPreferenceStore(this._prefs);
+
+  static Future<SharedPreferenceStore> open() async {
+    return SharedPreferenceStore(await SharedPreferences.getInstance());
+  }
+
+  @override
+  Object? get(String key) => _prefs.get(key);
+  @override
+  bool? getBool(String key) => _prefs.getBool(key);
+  @override
+  int? getInt(String key) => _prefs.getInt(key);
+  @override
+  double? getDouble(String key) => _prefs.getDouble(key);
+  @override
+  String? getString(String key) => _prefs.getString(key);
+  @override
+  List<String>? getStringList(String key) => _prefs.getStringList(key);
+
+  @override
+  Future<bool> setBool(String key, bool value) => _prefs.setBool(key, value);
+  @override
+  Future<bool> setInt(String key, int value) => _prefs.setInt(key, value);
+  @override
+  Future<bool> setDouble(String key, double value) => _prefs.setDouble(key, value);
+  @override
+  Future<bool> setString(String key, String value) => _prefs.setString(key, value);
+  @override
+  Future<bool> setStringList(String key, List<String> value) => _prefs.setStringList(key, value);
+  @override
+  Future<bool> remove(String key) => _prefs.remove(key);
+}
+
+/// Preferences in a JSON file, for portable mode. The whole file is written
+/// again on each change, which is fine at the size preferences have.
+class FilePreferenceStore implements PreferenceStore {
+  final File _file;
+  final Map<String, Object> _values;
+
+  // Writes in order, so an older one never lands after a newer one
+  Future<void> _lastWrite = Future.value();
+
+  FilePreferenceStore._(this._file, this._values);
+
+  static Future<FilePreferenceStore> open(String filePath) async {
+    final file = File(filePath);
+    var values = <String, Object>{};
+    if (await file.exists()) {
+      try {
+        values = (jsonDecode(await file.readAsString()) as Map<String, dynamic>).cast<String, Object>();
+      } catch (e) {
+        // A corrupt file starts over, like missing preferences would
+      }
+    }
+    return FilePreferenceStore._(file, values);
+  }
+
+  Future<bool> _save() {
+    final content = jsonEncode(_values);
+    final write = _lastWrite.then((_) async {
+      // Through a temporary file, so a crash never leaves half a file
+      await _file.parent.create(recursive: true);
+      final temporary = File('${_file.path}.tmp');
+      await temporary.writeAsString(content, flush: true);
+      await temporary.rename(_file.path);
+    });
+    _lastWrite = write.catchError((_) {});
+    return write.then((_) => true, onError: (_) => false);
+  }
+
+  Future<bool> _set(String key, Object value) {
+    _values[key] = value;
+    return _save();
+  }
+
+  @override
+  Object? get(String key) => _values[key];
+  @override
+  bool? getBool(String key) => _values[key] as bool?;
+  @override
+  int? getInt(String key) => _values[key] as int?;
+  @override
+  double? getDouble(String key) => (_values[key] as num?)?.toDouble();
+  @override
+  String? getString(String key) => _values[key] as String?;
+  @override
+  List<String>? getStringList(String key) => (_values[key] as List<dynamic>?)?.cast<String>().toList();
+
+  @override
+  Future<bool> setBool(String key, bool value) => _set(key, value);
+  @override
+  Future<bool> setInt(String key, int value) => _set(key, value);
+  @override
+  Future<bool> setDouble(String key, double value) => _set(key, value);
+  @override
+  Future<bool> setString(String key, String value) => _set(key, value);
+  @override
+  Future<bool> setStringList(String key, List<String> value) => _set(key, List<String>.from(value));
+  @override
+  Future<bool> remove(String key) {
+    _values.remove(key);
+    return _save();
+  }
+}
@@ -1,11 +1,14 @@
 import 'dart:io';
 import 'dart:convert';
+import 'dart:ui' show Rect;
 import 'package:flutter/foundation.dart';
 import 'package:path_provider/path_provider.dart';
-import 'package:shared_preferences/shared_preferences.dart';
 import 'package:path/path.dart' as path;
 import 'package:macos_secure_bookmarks/macos_secure_bookmarks.dart';
 import '../models/project.dart';
+import '../models/task_settings.dart';
+import 'app_paths.dart';
+import 'preference_store.dart';
 
 // Top-level functions for isolate execution
 List<Project> _decodeProjects(String projectsJson) {
@@ -29,17 +32,85 @@ class PreferencesService {
   static const String _apiKeysKey = 'api_keys';
   static const String _bookmarksKey = 'security_bookmarks';
   static const String _parentDirBookmarkKey = 'parent_directory_bookmark';
+  static const String _persistLogsKey = 'persist_logs';
+  static const String _readOnlyKey = 'read_only';
+  static const String _editorCommandKey = 'editor_command';
+  static const String _taskSettingsKey = 'task_settings';
+  static const String _terminalCommandKey = 'terminal_command';
+  static const String _bunModeKey = 'bun_mode';
+  static const String _bunCustomPathKey = 'bun_custom_path';
+  static const String _downloadProxyKey = 'download_proxy';
+  static const String _bunMirrorKey = 'bun_mirror';
+  static const String _runAllConcurrencyKey = 'run_all_concurrency';
+  static const String _workspaceEnvironmentsKey = 'workspace_environments';
+  static const String _collapsedProjectsKey = 'collapsed_projects';
+  static const String _projectIconsKey = 'project_icons';
+  static const String _favoriteTasksKey = 'favorite_tasks';
+  static const String _recentTasksKey = 'recent_tasks';
+  static const String _openTabsKey = 'open_tabs';
+  static const String _selectedTaskKey = 'selected_task';
+  static const String _leftPaneWidthKey = 'left_pane_width';
+  static const String _stopGracePeriodKey = 'stop_grace_period';
+  static const String _confirmRemoveProjectKey = 'confirm_remove_project';
+  static const String _confirmKillTaskKey = 'confirm_kill_task';
+  static const String _customTasksKey = 'custom_tasks';
+  static const String _maskSecretsKey = 'mask_secrets';
+  static const String _secretPatternsKey = 'secret_patterns';
+  static const String _installHashesKey = 'install_hashes';
+  static const String _wrapLogLinesKey = 'wrap_log_lines';
+  static const String _logFontSizeKey = 'log_font_size';
+  static const String _logFontFamilyKey = 'log_font_family';
+  static const String _profilesKey = 'profiles';
+  static const String _controlApiKey = 'control_api';
+  static const String _controlApiPortKey = 'control_api_port';
+  static const String _controlApiTokenKey = 'control_api_token';
+  static const String _controlApiLanKey = 'control_api_lan';
+  static const String _mcpServerKey = 'mcp_server';
+  static const String _windowBoundsKey = 'window_bounds';
+  static const String _launcherHotkeyKey = 'launcher_hotkey';
+  static const String _currentProfileKey = 'current_profile';
+
+  // Version of exported configuration files
+  static const int _configVersion = 1;
+
+  // Settings carried by exported configuration. API keys stay on this
+  // computer; bookmarks, install hashes and the bun path only make sense on it.
+  static const List<String> _exportedSettingKeys = [
+    _persistLogsKey,
+    _wrapLogLinesKey,
+    _logFontSizeKey,
+    _logFontFamilyKey,
+    _editorCommandKey,
+    _terminalCommandKey,
+    _bunModeKey,
+    _downloadProxyKey,
+    _bunMirrorKey,
+    _runAllConcurrencyKey,
+    _stopGracePeriodKey,
+    _confirmRemoveProjectKey,
+    _confirmKillTaskKey,
+    _maskSecretsKey,
+    _secretPatternsKey,
+  ];
 
   // Singleton pattern
   static final PreferencesService _instance = PreferencesService._internal();
   factory PreferencesService() => _instance;
   PreferencesService._internal();
 
-  SharedPreferences? _prefs;
+  PreferenceStore? _prefs;
+
+  // Profile whose projects and settings are read and written, null for the
+  // default one. API keys, install hashes and the profile list are shared.
+  String? _profile;
   final SecureBookmarks? _secureBookmarks = Platform.isMacOS ? SecureBookmarks() : null;
 
   Future<void> initialize() async {
-    _prefs = await SharedPreferences.getInstance();
+    final preferencesFile = AppPaths.preferencesFile;
+    _prefs = preferencesFile != null
+        ? await FilePreferenceStore.open(preferencesFile)
+        : await SharedPreferenceStore.open();
+    _profile = _prefs!.getString(_currentProfileKey);
 
     // On macOS, restore the parent directory bookmark
     if (Platform.isMacOS && _secureBookmarks != null) {
@@ -47,12 +118,50 @@ class PreferencesService {
     }
   }
 
+  /// Key of a preference in the current profile
+  String _scoped(String key) => _profile == null ? key : 'profile:$_profile:$key';
+
+  /// The current profile, null for the default one
+  String? get profile => _profile;
+
+  /// Named profiles, besides the default one
+  Future<List<String>> getProfiles() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getStringList(_profilesKey) ?? [];
+  }
+
+  /// Switch to a profile, null for the default one, creating it when new.
+  /// The next start opens it too.
+  Future<void> useProfile(String? profile) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    _profile = profile == null || profile.isEmpty ? null : profile;
+    if (_profile case final profile?) {
+      final profiles = await getProfiles();
+      if (!profiles.contains(profile)) {
+        await _prefs!.setStringList(_profilesKey, [...profiles, profile]);
+      }
+      await _prefs!.setString(_currentProfileKey, profile);
+    } else {
+      await _prefs!.remove(_currentProfileKey);
+    }
+
+    if (Platform.isMacOS && _secureBookmarks != null) {
+      await _restoreParentDirectoryBookmark();
+    }
+  }
+
   Future<String> getParentDirectory() async {
     if (_prefs == null) {
       await initialize();
     }
 
-    final savedDirectory = _prefs!.getString(_parentDirectoryKey);
+    final savedDirectory = _prefs!.getString(_scoped(_parentDirectoryKey));
 
     if (savedDirectory != null && savedDirectory.isNotEmpty) {
       return savedDirectory;
@@ -66,7 +175,7 @@ class PreferencesService {
       await initialize();
     }
 
-    await _prefs!.setString(_parentDirectoryKey, directory);
+    await _prefs!.setString(_scoped(_parentDirectoryKey), directory);
 
     // On macOS, create a bookmark for the parent directory
     if (Platform.isMacOS && _secureBookmarks != null && directory.isNotEmpty) {
@@ -74,7 +183,7 @@ class PreferencesService {
         final bookmark = await _secureBookmarks.bookmark(Directory(directory)).timeout(
           const Duration(seconds: 2),
         );
-        await _prefs!.setString(_parentDirBookmarkKey, bookmark);
+        await _prefs!.setString(_scoped(_parentDirBookmarkKey), bookmark);
       } catch (e) {
         // Bookmark creation failed, but continue
       }
@@ -91,7 +200,7 @@ class PreferencesService {
       await _restoreBookmarks();
     }
 
-    final projectsJson = _prefs!.getString(_projectsKey);
+    final projectsJson = _prefs!.getString(_scoped(_projectsKey));
 
     if (projectsJson == null || projectsJson.isEmpty) {
       return [];
@@ -116,13 +225,13 @@ class PreferencesService {
     // Encode JSON in a separate isolate to avoid blocking the UI
     final projectsData = projects.map((p) => p.toJson()).toList();
     final projectsJson = await compute(_encodeProjects, projectsData);
-    await _prefs!.setString(_projectsKey, projectsJson);
+    await _prefs!.setString(_scoped(_projectsKey), projectsJson);
   }
 
   Future<void> _saveBookmarks(List<Project> projects) async {
     final bookmarksMap = <String, String>{};
 
-    for (final project in projects) {
+    for (final project in projects.where((p) => !p.isRemote)) {
       try {
         final bookmark = await _secureBookmarks!.bookmark(Directory(project.path)).timeout(
           const Duration(seconds: 2),
@@ -134,13 +243,13 @@ class PreferencesService {
     }
 
     if (bookmarksMap.isNotEmpty) {
-      await _prefs!.setString(_bookmarksKey, jsonEncode(bookmarksMap));
+      await _prefs!.setString(_scoped(_bookmarksKey), jsonEncode(bookmarksMap));
     }
   }
 
   Future<void> _restoreParentDirectoryBookmark() async {
     try {
-      final bookmark = _prefs!.getString(_parentDirBookmarkKey);
+      final bookmark = _prefs!.getString(_scoped(_parentDirBookmarkKey));
       if (bookmark == null || bookmark.isEmpty) {
         return;
       }
@@ -154,7 +263,7 @@ class PreferencesService {
 
   Future<void> _restoreBookmarks() async {
     try {
-      final bookmarksJson = _prefs!.getString(_bookmarksKey);
+      final bookmarksJson = _prefs!.getString(_scoped(_bookmarksKey));
       if (bookmarksJson == null || bookmarksJson.isEmpty) {
         return;
       }
@@ -232,6 +341,884 @@ class PreferencesService {
     await _prefs!.setString(_apiKeysKey, jsonEncode(apiKeys));
   }
 
+  /// Whether task output should be saved to log files on disk
+  Future<bool> getPersistLogs() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_scoped(_persistLogsKey)) ?? false;
+  }
+
+  Future<void> setPersistLogs(bool persistLogs) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_scoped(_persistLogsKey), persistLogs);
+  }
+
+  /// Whether long log lines wrap, rather than scrolling horizontally
+  Future<bool> getWrapLogLines() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_scoped(_wrapLogLinesKey)) ?? true;
+  }
+
+  Future<void> setWrapLogLines(bool wrap) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_scoped(_wrapLogLinesKey), wrap);
+  }
+
+  /// Size of log text, null for the default
+  Future<double?> getLogFontSize() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getDouble(_scoped(_logFontSizeKey));
+  }
+
+  Future<void> setLogFontSize(double? size) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (size == null) {
+      await _prefs!.remove(_scoped(_logFontSizeKey));
+    } else {
+      await _prefs!.setDouble(_scoped(_logFontSizeKey), size);
+    }
+  }
+
+  /// Monospace font of log text, null for the default
+  Future<String?> getLogFontFamily() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getString(_scoped(_logFontFamilyKey));
+  }
+
+  Future<void> setLogFontFamily(String? family) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (family == null || family.isEmpty) {
+      await _prefs!.remove(_scoped(_logFontFamilyKey));
+    } else {
+      await _prefs!.setString(_scoped(_logFontFamilyKey), family);
+    }
+  }
+
+  /// How many tasks "Run all" starts at once, null when never chosen
+  Future<int?> getRunAllConcurrency() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getInt(_scoped(_runAllConcurrencyKey));
+  }
+
+  Future<void> setRunAllConcurrency(int concurrency) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setInt(_scoped(_runAllConcurrencyKey), concurrency);
+  }
+
+  /// Paths of projects whose tasks are hidden in the sidebar
+  Future<Set<String>> getCollapsedProjects() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return (_prefs!.getStringList(_scoped(_collapsedProjectsKey)) ?? []).toSet();
+  }
+
+  Future<void> setCollapsedProjects(Set<String> projectPaths) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setStringList(_scoped(_collapsedProjectsKey), projectPaths.toList());
+  }
+
+  /// Keys of the tasks pinned at the top of the sidebar, in the order they
+  /// were starred
+  Future<List<String>> getFavoriteTasks() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getStringList(_scoped(_favoriteTasksKey)) ?? [];
+  }
+
+  Future<void> setFavoriteTasks(List<String> taskKeys) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setStringList(_scoped(_favoriteTasksKey), taskKeys);
+  }
+
+  /// Keys of the tasks started last, the latest first
+  Future<List<String>> getRecentTasks() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getStringList(_scoped(_recentTasksKey)) ?? [];
+  }
+
+  Future<void> setRecentTasks(List<String> taskKeys) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setStringList(_scoped(_recentTasksKey), taskKeys);
+  }
+
+  /// Emoji shown before the names of projects, keyed by project path
+  Future<Map<String, String>> getProjectIcons() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final iconsJson = _prefs!.getString(_scoped(_projectIconsKey));
+    if (iconsJson == null || iconsJson.isEmpty) {
+      return {};
+    }
+
+    try {
+      final decoded = jsonDecode(iconsJson) as Map<String, dynamic>;
+      return decoded.map((projectPath, icon) => MapEntry(projectPath, icon.toString()));
+    } catch (e) {
+      return {};
+    }
+  }
+
+  Future<void> setProjectIcons(Map<String, String> icons) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (icons.isEmpty) {
+      await _prefs!.remove(_scoped(_projectIconsKey));
+    } else {
+      await _prefs!.setString(_scoped(_projectIconsKey), jsonEncode(icons));
+    }
+  }
+
+  /// Tasks open as tabs when bob last closed, as project path and task name
+  Future<List<(String, String)>> getOpenTabs() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final value = _prefs!.getString(_scoped(_openTabsKey));
+    if (value == null) {
+      return [];
+    }
+    try {
+      return [
+        for (final tab in (jsonDecode(value) as List<dynamic>).cast<List<dynamic>>())
+          (tab[0] as String, tab[1] as String),
+      ];
+    } catch (e) {
+      return [];
+    }
+  }
+
+  Future<void> setOpenTabs(List<(String, String)> tabs) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setString(
+      _scoped(_openTabsKey),
+      jsonEncode([
+        for (final (projectPath, taskName) in tabs) [projectPath, taskName],
+      ]),
+    );
+  }
+
+  /// Task selected when bob last closed, as project path and task name
+  Future<(String, String)?> getSelectedTask() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final value = _prefs!.getStringList(_scoped(_selectedTaskKey));
+    return value != null && value.length == 2 ? (value[0], value[1]) : null;
+  }
+
+  Future<void> setSelectedTask((String, String)? task) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (task == null) {
+      await _prefs!.remove(_scoped(_selectedTaskKey));
+    } else {
+      await _prefs!.setStringList(_scoped(_selectedTaskKey), [task.$1, task.$2]);
+    }
+  }
+
+  /// Width of the sidebar, null until resized
+  Future<double?> getLeftPaneWidth() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getDouble(_leftPaneWidthKey);
+  }
+
+  Future<void> setLeftPaneWidth(double width) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setDouble(_leftPaneWidthKey, width);
+  }
+
+  /// Seconds a stopped task has to exit before being killed, null when never set
+  Future<int?> getStopGracePeriod() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getInt(_scoped(_stopGracePeriodKey));
+  }
+
+  Future<void> setStopGracePeriod(int? seconds) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (seconds == null) {
+      await _prefs!.remove(_scoped(_stopGracePeriodKey));
+    } else {
+      await _prefs!.setInt(_scoped(_stopGracePeriodKey), seconds);
+    }
+  }
+
+  /// Whether removing a project asks for confirmation first
+  Future<bool> getConfirmRemoveProject() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_scoped(_confirmRemoveProjectKey)) ?? true;
+  }
+
+  Future<void> setConfirmRemoveProject(bool confirm) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_scoped(_confirmRemoveProjectKey), confirm);
+  }
+
+  /// Whether killing a stopping task asks for confirmation first
+  Future<bool> getConfirmKillTask() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_scoped(_confirmKillTaskKey)) ?? true;
+  }
+
+  Future<void> setConfirmKillTask(bool confirm) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_scoped(_confirmKillTaskKey), confirm);
+  }
+
+  /// Whether secrets are hidden in logs
+  Future<bool> getMaskSecrets() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_scoped(_maskSecretsKey)) ?? true;
+  }
+
+  Future<void> setMaskSecrets(bool mask) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_scoped(_maskSecretsKey), mask);
+  }
+
+  /// Regular expressions of secrets to hide in logs, besides the values of
+  /// environment variables named like secrets
+  Future<List<String>> getSecretPatterns() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getStringList(_scoped(_secretPatternsKey)) ?? [];
+  }
+
+  Future<void> setSecretPatterns(List<String> patterns) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setStringList(_scoped(_secretPatternsKey), patterns);
+  }
+
+  /// Whether other tools can control bob through its local API
+  Future<bool> getControlApi() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_controlApiKey) ?? false;
+  }
+
+  Future<void> setControlApi(bool enabled) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_controlApiKey, enabled);
+  }
+
+  /// Port of the local API, null for the default one
+  Future<int?> getControlApiPort() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getInt(_controlApiPortKey);
+  }
+
+  Future<void> setControlApiPort(int? port) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (port == null) {
+      await _prefs!.remove(_controlApiPortKey);
+    } else {
+      await _prefs!.setInt(_controlApiPortKey, port);
+    }
+  }
+
+  /// Whether the local API also answers other devices of the local network
+  Future<bool> getControlApiLan() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_controlApiLanKey) ?? false;
+  }
+
+  Future<void> setControlApiLan(bool enabled) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_controlApiLanKey, enabled);
+  }
+
+  /// Whether AI assistants may drive bob through `bob mcp`
+  Future<bool> getMcpServer() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_mcpServerKey) ?? false;
+  }
+
+  Future<void> setMcpServer(bool enabled) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_mcpServerKey, enabled);
+  }
+
+  /// Shortcut summoning the quick launcher from any app, as hotkey_manager
+  /// writes it, null when off. Kept across profiles like any OS shortcut.
+  Future<Map<String, dynamic>?> getLauncherHotkey() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final value = _prefs!.getString(_launcherHotkeyKey);
+    if (value == null) {
+      return null;
+    }
+    try {
+      return jsonDecode(value) as Map<String, dynamic>;
+    } catch (e) {
+      return null;
+    }
+  }
+
+  Future<void> setLauncherHotkey(Map<String, dynamic>? hotkey) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (hotkey == null) {
+      await _prefs!.remove(_launcherHotkeyKey);
+    } else {
+      await _prefs!.setString(_launcherHotkeyKey, jsonEncode(hotkey));
+    }
+  }
+
+  /// Where the window was when bob last closed, and whether it was
+  /// maximized. Kept across profiles since it belongs to the screens.
+  Future<(Rect, bool)?> getWindowBounds() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final value = _prefs!.getString(_windowBoundsKey);
+    if (value == null) {
+      return null;
+    }
+    try {
+      final json = jsonDecode(value) as Map<String, dynamic>;
+      final bounds = Rect.fromLTWH(
+        (json['x'] as num).toDouble(),
+        (json['y'] as num).toDouble(),
+        (json['width'] as num).toDouble(),
+        (json['height'] as num).toDouble(),
+      );
+      return (bounds, json['maximized'] as bool? ?? false);
+    } catch (e) {
+      return null;
+    }
+  }
+
+  Future<void> setWindowBounds(Rect bounds, bool maximized) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setString(
+      _windowBoundsKey,
+      jsonEncode({
+        'x': bounds.left,
+        'y': bounds.top,
+        'width': bounds.width,
+        'height': bounds.height,
+        'maximized': maximized,
+      }),
+    );
+  }
+
+  /// Token tools send to the local API, kept so they don't need a new one
+  /// after each restart
+  Future<String?> getControlApiToken() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getString(_controlApiTokenKey);
+  }
+
+  Future<void> setControlApiToken(String? token) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (token == null || token.isEmpty) {
+      await _prefs!.remove(_controlApiTokenKey);
+    } else {
+      await _prefs!.setString(_controlApiTokenKey, token);
+    }
+  }
+
+  Future<bool> getReadOnly() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getBool(_scoped(_readOnlyKey)) ?? false;
+  }
+
+  Future<void> setReadOnly(bool readOnly) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setBool(_scoped(_readOnlyKey), readOnly);
+  }
+
+  Future<String?> getEditorCommand() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getString(_scoped(_editorCommandKey));
+  }
+
+  Future<void> setEditorCommand(String? command) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (command == null || command.isEmpty) {
+      await _prefs!.remove(_scoped(_editorCommandKey));
+    } else {
+      await _prefs!.setString(_scoped(_editorCommandKey), command);
+    }
+  }
+
+  Future<String?> getTerminalCommand() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getString(_scoped(_terminalCommandKey));
+  }
+
+  Future<void> setTerminalCommand(String? command) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (command == null || command.isEmpty) {
+      await _prefs!.remove(_scoped(_terminalCommandKey));
+    } else {
+      await _prefs!.setString(_scoped(_terminalCommandKey), command);
+    }
+  }
+
+  /// How bun is provided, as a [BunMode] name
+  Future<String?> getBunMode() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getString(_scoped(_bunModeKey));
+  }
+
+  Future<void> setBunMode(String mode) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    await _prefs!.setString(_scoped(_bunModeKey), mode);
+  }
+
+  Future<String?> getBunCustomPath() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getString(_scoped(_bunCustomPathKey));
+  }
+
+  Future<void> setBunCustomPath(String? bunPath) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (bunPath == null || bunPath.isEmpty) {
+      await _prefs!.remove(_scoped(_bunCustomPathKey));
+    } else {
+      await _prefs!.setString(_scoped(_bunCustomPathKey), bunPath);
+    }
+  }
+
+  /// Proxy used for downloads, overriding HTTPS_PROXY from the environment
+  Future<String?> getDownloadProxy() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getString(_scoped(_downloadProxyKey));
+  }
+
+  Future<void> setDownloadProxy(String? proxy) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (proxy == null || proxy.isEmpty) {
+      await _prefs!.remove(_scoped(_downloadProxyKey));
+    } else {
+      await _prefs!.setString(_scoped(_downloadProxyKey), proxy);
+    }
+  }
+
+  /// Base URL or local directory mirroring bun's GitHub releases
+  Future<String?> getBunMirror() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return _prefs!.getString(_scoped(_bunMirrorKey));
+  }
+
+  Future<void> setBunMirror(String? mirror) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (mirror == null || mirror.isEmpty) {
+      await _prefs!.remove(_scoped(_bunMirrorKey));
+    } else {
+      await _prefs!.setString(_scoped(_bunMirrorKey), mirror);
+    }
+  }
+
+  /// Get environment variables shared by the projects in each workspace, keyed by directory
+  Future<Map<String, Map<String, String>>> getWorkspaceEnvironments() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final environmentsJson = _prefs!.getString(_scoped(_workspaceEnvironmentsKey));
+    if (environmentsJson == null || environmentsJson.isEmpty) {
+      return {};
+    }
+
+    try {
+      final decoded = jsonDecode(environmentsJson) as Map<String, dynamic>;
+      return decoded.map((directory, variables) => MapEntry(
+            directory,
+            (variables as Map<String, dynamic>).map((key, value) => MapEntry(key, value.toString())),
+          ));
+    } catch (e) {
+      return {};
+    }
+  }
+
+  Future<void> setWorkspaceEnvironments(Map<String, Map<String, String>> environments) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (environments.isEmpty) {
+      await _prefs!.remove(_scoped(_workspaceEnvironmentsKey));
+    } else {
+      await _prefs!.setString(_scoped(_workspaceEnvironmentsKey), jsonEncode(environments));
+    }
+  }
+
+  /// Get the commands added as tasks, keyed by project path then task name
+  Future<Map<String, Map<String, String>>> getCustomTasks() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final tasksJson = _prefs!.getString(_scoped(_customTasksKey));
+    if (tasksJson == null || tasksJson.isEmpty) {
+      return {};
+    }
+
+    try {
+      final decoded = jsonDecode(tasksJson) as Map<String, dynamic>;
+      return decoded.map((projectPath, tasks) => MapEntry(
+            projectPath,
+            (tasks as Map<String, dynamic>).map((name, command) => MapEntry(name, command.toString())),
+          ));
+    } catch (e) {
+      return {};
+    }
+  }
+
+  Future<void> setCustomTasks(Map<String, Map<String, String>> tasks) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final nonEmpty = Map.fromEntries(tasks.entries.where((e) => e.value.isNotEmpty));
+    if (nonEmpty.isEmpty) {
+      await _prefs!.remove(_scoped(_customTasksKey));
+    } else {
+      await _prefs!.setString(_scoped(_customTasksKey), jsonEncode(nonEmpty));
+    }
+  }
+
+  /// Get the hash of the install inputs at each project's last successful
+  /// install, keyed by project path
+  Future<Map<String, String>> getInstallHashes() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final hashesJson = _prefs!.getString(_installHashesKey);
+    if (hashesJson == null || hashesJson.isEmpty) {
+      return {};
+    }
+
+    try {
+      final decoded = jsonDecode(hashesJson) as Map<String, dynamic>;
+      return decoded.map((projectPath, hash) => MapEntry(projectPath, hash.toString()));
+    } catch (e) {
+      return {};
+    }
+  }
+
+  Future<void> setInstallHash(String projectPath, String? hash) async {
+    final hashes = await getInstallHashes();
+    if (hash == null) {
+      hashes.remove(projectPath);
+    } else {
+      hashes[projectPath] = hash;
+    }
+
+    if (hashes.isEmpty) {
+      await _prefs!.remove(_installHashesKey);
+    } else {
+      await _prefs!.setString(_installHashesKey, jsonEncode(hashes));
+    }
+  }
+
+  /// Get the run configuration of all tasks, keyed by task key
+  Future<Map<String, TaskSettings>> getAllTaskSettings() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    final settingsJson = _prefs!.getString(_scoped(_taskSettingsKey));
+    if (settingsJson == null || settingsJson.isEmpty) {
+      return {};
+    }
+
+    try {
+      final decoded = jsonDecode(settingsJson) as Map<String, dynamic>;
+      return decoded.map((key, value) =>
+          MapEntry(key, TaskSettings.fromJson(value as Map<String, dynamic>)));
+    } catch (e) {
+      return {};
+    }
+  }
+
+  /// Get the run configuration of a task
+  Future<TaskSettings> getTaskSettings(String taskKey) async {
+    final allSettings = await getAllTaskSettings();
+    return allSettings[taskKey] ?? const TaskSettings();
+  }
+
+  /// Set the run configuration of a task, removing it when back to defaults
+  Future<void> setTaskSettings(String taskKey, TaskSettings settings) async {
+    final allSettings = await getAllTaskSettings();
+    if (settings.isDefault) {
+      allSettings.remove(taskKey);
+    } else {
+      allSettings[taskKey] = settings;
+    }
+
+    await _prefs!.setString(
+      _scoped(_taskSettingsKey),
+      jsonEncode(allSettings.map((key, value) => MapEntry(key, value.toJson()))),
+    );
+  }
+
+  /// Serialize projects, settings and per-project configuration kept here,
+  /// to carry them to another computer
+  Future<Map<String, dynamic>> exportConfig() async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    return {
+      'version': _configVersion,
+      'projects': (await getProjects()).map((p) => p.toJson()).toList(),
+      'collapsedProjects': (await getCollapsedProjects()).toList(),
+      'settings': {
+        for (final key in _exportedSettingKeys)
+          if (_prefs!.get(_scoped(key)) case final value?) key: value,
+      },
+      'taskSettings': (await getAllTaskSettings()).map((key, value) => MapEntry(key, value.toJson())),
+      'workspaceEnvironments': await getWorkspaceEnvironments(),
+      'customTasks': await getCustomTasks(),
+    };
+  }
+
+  /// Merge configuration from [exportConfig] into this computer's. Imported
+  /// settings win, per-project entries are added to those already here.
+  /// Returns the projects of the configuration, which callers add.
+  Future<List<Project>> importConfig(Map<String, dynamic> config) async {
+    if (_prefs == null) {
+      await initialize();
+    }
+
+    if (config['version'] != _configVersion) {
+      throw const FormatException('Not a bob configuration file');
+    }
+
+    final settings = config['settings'] as Map<String, dynamic>? ?? {};
+    for (final MapEntry(:key, :value) in settings.entries) {
+      if (!_exportedSettingKeys.contains(key)) {
+        continue;
+      }
+      switch (value) {
+        case final bool flag:
+          await _prefs!.setBool(_scoped(key), flag);
+        case final int number:
+          await _prefs!.setInt(_scoped(key), number);
+        case final double number:
+          await _prefs!.setDouble(_scoped(key), number);
+        case final String text:
+          await _prefs!.setString(_scoped(key), text);
+        case final List<dynamic> items:
+          await _prefs!.setStringList(_scoped(key), items.map((item) => item.toString()).toList());
+      }
+    }
+
+    final taskSettings = await getAllTaskSettings();
+    for (final MapEntry(:key, :value) in (config['taskSettings'] as Map<String, dynamic>? ?? {}).entries) {
+      taskSettings[key] = TaskSettings.fromJson(value as Map<String, dynamic>);
+    }
+    await _prefs!.setString(
+      _scoped(_taskSettingsKey),
+      jsonEncode(taskSettings.map((key, value) => MapEntry(key, value.toJson()))),
+    );
+
+    Map<String, Map<String, String>> merge(
+      Map<String, Map<String, String>> current,
+      Map<String, dynamic>? imported,
+    ) {
+      return {
+        ...current,
+        for (final MapEntry(:key, :value) in (imported ?? {}).entries)
+          key: {
+            ...?current[key],
+            ...(value as Map<String, dynamic>).map((name, item) => MapEntry(name, item.toString())),
+          },
+      };
+    }
+
+    await setWorkspaceEnvironments(merge(
+      await getWorkspaceEnvironments(),
+      config['workspaceEnvironments'] as Map<String, dynamic>?,
+    ));
+    await setCustomTasks(merge(
+      await getCustomTasks(),
+      config['customTasks'] as Map<String, dynamic>?,
+    ));
+
+    await setCollapsedProjects({
+      ...await getCollapsedProjects(),
+      ...(config['collapsedProjects'] as List<dynamic>? ?? []).map((projectPath) => projectPath.toString()),
+    });
+
+    return (config['projects'] as List<dynamic>? ?? [])
+        .map((item) => Project.fromJson(item as Map<String, dynamic>))
+        .toList();
+  }
+
   Future<String> _getDefaultDocumentsDirectory() async {
     try {
       if (Platform.isWindows) {
@@ -1,16 +1,26 @@
 import 'dart:convert';
 import 'dart:io';
 
+import 'package:crypto/crypto.dart';
 import 'package:file_picker/file_picker.dart';
 import 'package:flutter/foundation.dart';
 import 'package:path/path.dart' as path;
 
+import '../models/install_script_dependency.dart';
+import '../models/package_dependency.dart';
 import '../models/project.dart';
 import '../models/project_template.dart';
 import '../models/result.dart';
 import '../utils/json_utils.dart';
+import '../utils/makefile_utils.dart';
+import '../utils/procfile_utils.dart';
+import '../utils/pyproject_utils.dart';
+import '../utils/taskfile_utils.dart';
+import '../utils/wsl_utils.dart';
 import '../utils/process_utils.dart';
 import './binary_manager.dart';
+import './devcontainer_service.dart';
+import './preferences_service.dart';
 
 class ProjectService {
   final BinaryManager _binaryManager = BinaryManager();
@@ -96,6 +106,11 @@ class ProjectService {
       return Result.failure('No directory selected');
     }
 
+    return importProjectFromPath(directoryPath);
+  }
+
+  /// Import the project at [directoryPath], creating a package.json if missing
+  Future<Result<Project>> importProjectFromPath(String directoryPath) async {
     try {
       final directory = Directory(directoryPath);
 
@@ -127,7 +142,7 @@ class ProjectService {
       // Initialize launch directory based on project type
       await _initializeLaunchDirectory(directoryPath, json);
 
-      final project = Project.fromPackageJson(directoryPath, json);
+      final project = await _loadProject(directoryPath, json);
 
       return Result.success(project);
     } on FormatException catch (e) {
@@ -139,6 +154,28 @@ class ProjectService {
     }
   }
 
+  /// Import the project at [remotePath] on the SSH [host], whose tasks come
+  /// from its package.json there
+  Future<Result<Project>> importRemoteProject(String host, String remotePath) async {
+    final stub = Project(name: path.posix.basename(remotePath), path: '$host:$remotePath', tasks: [], host: host);
+    return reloadProject(stub);
+  }
+
+  /// Read the package.json of a remote project over SSH
+  Future<String> _readRemotePackageJson(Project project) async {
+    final result = await Process.run('ssh', [
+      '-o',
+      'BatchMode=yes',
+      project.host!,
+      'cat ${ProcessUtils.shellQuote(path.posix.join(project.remotePath, 'package.json'))}',
+    ]);
+    if (result.exitCode != 0) {
+      final error = result.stderr.toString().trim();
+      throw FileSystemException(error.isNotEmpty ? error : 'ssh exited with code ${result.exitCode}', project.path);
+    }
+    return result.stdout as String;
+  }
+
   Future<Result<Project>> createProject({
     required String projectName,
     required String parentDirectory,
@@ -158,7 +195,7 @@ class ProjectService {
           onOutput?.call('Directory already exists with package.json, loading existing project…\n');
           final content = await packageJsonFile.readAsString();
           final json = await compute(decodeJson, content);
-          final project = Project.fromPackageJson(projectPath, json);
+          final project = await _loadProject(projectPath, json);
           return Result.success(project);
         }
 
@@ -251,7 +288,17 @@ class ProjectService {
   Future<void> _writePackageJson(String projectPath, Map<String, dynamic> packageJson) async {
     final packageJsonPath = path.join(projectPath, 'package.json');
     final jsonString = await compute(encodeJson, packageJson);
-    await File(packageJsonPath).writeAsString(jsonString);
+
+    // Write next to it and rename over it, so watchers and tools running
+    // at the same time never read a half-written file
+    final temporary = File('$packageJsonPath.bob-$pid.tmp');
+    await temporary.writeAsString(jsonString, flush: true);
+    try {
+      await temporary.rename(packageJsonPath);
+    } catch (e) {
+      await temporary.delete().catchError((_) => temporary);
+      rethrow;
+    }
   }
 
   // Public methods for package.json manipulation
@@ -263,6 +310,38 @@ class ProjectService {
     await _writePackageJson(projectPath, packageJson);
   }
 
+  /// The scripts of a project's package.json, in file order
+  Future<Result<Map<String, String>>> readScripts(String projectPath) async {
+    try {
+      final content = await File(path.join(projectPath, 'package.json')).readAsString();
+      final Map<String, dynamic> json;
+      try {
+        json = await compute(decodeJson, content);
+      } on FormatException catch (e) {
+        return Result.failure('Invalid JSON format${_describeErrorLocation(content, e.offset)}: ${e.message}');
+      }
+      final scripts = json['scripts'] as Map<String, dynamic>? ?? {};
+      return Result.success(scripts.map((key, value) => MapEntry(key, value.toString())));
+    } on FileSystemException catch (e) {
+      return Result.failure('File system error: ${e.message}');
+    }
+  }
+
+  /// Replace the scripts of a project's package.json, leaving the rest of
+  /// the file alone. The package.json watcher picks up the change.
+  Future<Result<void>> writeScripts(String projectPath, Map<String, String> scripts) async {
+    try {
+      final packageJson = await _readPackageJson(projectPath);
+      packageJson['scripts'] = scripts;
+      await _writePackageJson(projectPath, packageJson);
+      return Result.success(null);
+    } on FormatException catch (e) {
+      return Result.failure('Invalid JSON format: ${e.message}');
+    } on FileSystemException catch (e) {
+      return Result.failure('File system error: ${e.message}');
+    }
+  }
+
   Future<Result<Project>> _createEleventyProject(
     String projectPath,
     String projectName,
@@ -406,6 +485,8 @@ class ProjectService {
     }
   }
 
+  /// Recursively scan [directoryPath] for package.json files.
+  /// Skips node_modules and hidden directories (.git, .cache, ...).
   Future<List<Project>> scanDirectory(String directoryPath) async {
     final projects = <Project>[];
     final directory = Directory(directoryPath);
@@ -414,39 +495,59 @@ class ProjectService {
       return projects;
     }
 
-    try {
-      await for (final entity in directory.list(recursive: true, followLinks: false)) {
-        if (entity is File && path.basename(entity.path) == 'package.json') {
-          try {
-            final content = await entity.readAsString();
-            final json = await compute(decodeJson, content);
-            final projectPath = path.dirname(entity.path);
-            projects.add(Project.fromPackageJson(projectPath, json));
-          } catch (e) {
-            // Skip invalid package.json files
+    final pending = <Directory>[directory];
+    while (pending.isNotEmpty) {
+      final current = pending.removeLast();
+
+      try {
+        await for (final entity in current.list(followLinks: false)) {
+          final name = path.basename(entity.path);
+          if (entity is Directory) {
+            if (name != 'node_modules' && !name.startsWith('.')) {
+              pending.add(entity);
+            }
+          } else if (entity is File && name == 'package.json') {
+            try {
+              final content = await entity.readAsString();
+              final json = await compute(decodeJson, content);
+              projects.add(await _loadProject(current.path, json));
+            } catch (e) {
+              // Skip invalid package.json files
+            }
           }
         }
+      } catch (e) {
+        // Skip unreadable directories and keep scanning the rest
       }
-    } catch (e) {
-      // Return partial results if directory scan fails
     }
 
+    projects.sort((a, b) => a.path.compareTo(b.path));
     return projects;
   }
 
   Future<Result<Project>> reloadProject(Project project) async {
     try {
-      final packageJsonPath = path.join(project.path, 'package.json');
-      final packageJsonFile = File(packageJsonPath);
+      final String content;
+      if (project.isRemote) {
+        content = await _readRemotePackageJson(project);
+      } else {
+        final packageJsonPath = path.join(project.path, 'package.json');
+        final packageJsonFile = File(packageJsonPath);
 
-      if (!await packageJsonFile.exists()) {
-        return Result.failure('package.json not found at ${project.path}');
-      }
+        if (!await packageJsonFile.exists()) {
+          return Result.failure('package.json not found at ${project.path}');
+        }
 
-      final content = await packageJsonFile.readAsString();
-      final json = await compute(decodeJson, content);
+        content = await packageJsonFile.readAsString();
+      }
+      final Map<String, dynamic> json;
+      try {
+        json = await compute(decodeJson, content);
+      } on FormatException catch (e) {
+        return Result.failure('Invalid JSON format${_describeErrorLocation(content, e.offset)}: ${e.message}');
+      }
 
-      final reloadedProject = Project.fromPackageJson(project.path, json);
+      final reloadedProject = await _loadProject(project.path, json, host: project.host);
 
       return Result.success(reloadedProject);
     } on FormatException catch (e) {
@@ -458,7 +559,284 @@ class ProjectService {
     }
   }
 
+  /// Build a project from its package.json, adding the targets of its
+  /// Makefile, the tasks of its Taskfile, the scripts of its pyproject.toml,
+  /// the process types of its Procfile and the commands added in bob.
+  /// Remote projects only get their scripts and commands.
+  Future<Project> _loadProject(String projectPath, Map<String, dynamic> json, {String? host}) async {
+    final project = Project.fromPackageJson(
+      projectPath,
+      json,
+      host: host,
+      hasDevcontainer: host == null && await DevcontainerService.exists(projectPath),
+      isWslPath: WslUtils.isWslPath(projectPath),
+    );
+    if (host == null) {
+      project.tasks.addAll(await MakefileUtils.readTasks(projectPath));
+      project.tasks.addAll(await TaskfileUtils.readTasks(projectPath));
+      project.tasks.addAll(await PyprojectUtils.readTasks(projectPath));
+      project.tasks.addAll(
+        await ProcfileUtils.readTasks(projectPath, project.tasks.map((t) => t.name)),
+      );
+    }
+
+    // A command named like a task added since then stays hidden until renamed
+    final customTasks = (await PreferencesService().getCustomTasks())[projectPath] ?? {};
+    final taken = project.tasks.map((t) => t.name).toSet();
+    project.tasks.addAll(customTasks.entries
+        .where((entry) => !taken.contains(entry.key))
+        .map((entry) => Task(name: entry.key, command: entry.value, type: TaskType.custom)));
+    return project;
+  }
+
+  /// Turn a character offset into " at line L, column C", or an empty string
+  String _describeErrorLocation(String content, int? offset) {
+    if (offset == null || offset < 0 || offset > content.length) {
+      return '';
+    }
+
+    final before = content.substring(0, offset);
+    final line = '\n'.allMatches(before).length + 1;
+    final column = offset - (before.lastIndexOf('\n') + 1) + 1;
+    return ' at line $line, column $column';
+  }
+
+
+  /// Expand a script into a group of [children] in bob.groups, or turn it
+  /// back into a plain script when [children] is null
+  Future<Result<Project>> setScriptGroup(
+    Project project,
+    String scriptName,
+    List<String>? children,
+  ) async {
+    try {
+      final packageJson = await _readPackageJson(project.path);
+
+      final bob = packageJson['bob'] as Map<String, dynamic>? ?? {};
+      final groups = bob['groups'] as Map<String, dynamic>? ?? {};
+      if (children == null) {
+        groups.remove(scriptName);
+      } else {
+        groups[scriptName] = children;
+      }
+
+      if (groups.isEmpty) {
+        bob.remove('groups');
+      } else {
+        bob['groups'] = groups;
+      }
+      if (bob.isEmpty) {
+        packageJson.remove('bob');
+      } else {
+        packageJson['bob'] = bob;
+      }
+
+      await _writePackageJson(project.path, packageJson);
+      return await reloadProject(project);
+    } on FileSystemException catch (e) {
+      return Result.failure('File system error: ${e.message}');
+    } catch (e) {
+      return Result.failure('Failed to update task group: ${e.toString()}');
+    }
+  }
 
+  /// Show a task as [alias] in bob.aliases, or by its name again when
+  /// [alias] is null
+  /// Run the tasks of a project in its devcontainer, or on this computer
+  /// again
+  Future<Result<Project>> setUseDevcontainer(Project project, bool useDevcontainer) async {
+    try {
+      final packageJson = await _readPackageJson(project.path);
+
+      final bob = packageJson['bob'] as Map<String, dynamic>? ?? {};
+      if (useDevcontainer) {
+        bob['docker'] = const DockerConfig(devcontainer: true).toJson();
+      } else {
+        bob.remove('docker');
+      }
+      if (bob.isEmpty) {
+        packageJson.remove('bob');
+      } else {
+        packageJson['bob'] = bob;
+      }
+
+      await _writePackageJson(project.path, packageJson);
+      return await reloadProject(project);
+    } on FileSystemException catch (e) {
+      return Result.failure('File system error: ${e.message}');
+    } catch (e) {
+      return Result.failure('Failed to change where tasks run: ${e.toString()}');
+    }
+  }
+
+  Future<Result<Project>> setTaskAlias(
+    Project project,
+    String taskName,
+    String? alias,
+  ) async {
+    try {
+      final packageJson = await _readPackageJson(project.path);
+
+      final bob = packageJson['bob'] as Map<String, dynamic>? ?? {};
+      final aliases = bob['aliases'] as Map<String, dynamic>? ?? {};
+      if (alias == null) {
+        aliases.remove(taskName);
+      } else {
+        aliases[taskName] = alias;
+      }
+
+      if (aliases.isEmpty) {
+        bob.remove('aliases');
+      } else {
+        bob['aliases'] = aliases;
+      }
+      if (bob.isEmpty) {
+        packageJson.remove('bob');
+      } else {
+        packageJson['bob'] = bob;
+      }
+
+      await _writePackageJson(project.path, packageJson);
+      return await reloadProject(project);
+    } on FileSystemException catch (e) {
+      return Result.failure('File system error: ${e.message}');
+    } catch (e) {
+      return Result.failure('Failed to rename task: ${e.toString()}');
+    }
+  }
+
+  /// Dependencies and devDependencies of a project with their installed
+  /// versions, read from node_modules (walking up like node does for
+  /// workspaces) or else from bun.lock
+  Future<List<PackageDependency>> readDependencies(String projectPath) async {
+    final packageJson = await _readPackageJson(projectPath);
+
+    Map<String, String> lockedVersions = {};
+    final lockFile = File(path.join(projectPath, 'bun.lock'));
+    if (await lockFile.exists()) {
+      // Entries look like "name": ["name@version", ...]
+      final entry = RegExp(r'"([^"]+)":\s*\[\s*"((?:@[^@"/]+/)?[^@"]+)@([^"]+)"');
+      lockedVersions = {
+        for (final match in entry.allMatches(await lockFile.readAsString()))
+          if (match.group(1) == match.group(2)) match.group(1)!: match.group(3)!,
+      };
+    }
+
+    Future<String?> installedVersion(String name) async {
+      var directory = projectPath;
+      while (true) {
+        final file = File(path.join(directory, 'node_modules', name, 'package.json'));
+        if (await file.exists()) {
+          try {
+            final json = jsonDecode(await file.readAsString());
+            if (json is Map<String, dynamic> && json['version'] is String) {
+              return json['version'] as String;
+            }
+          } catch (e) {
+            // Unreadable package.json, keep looking
+          }
+        }
+        final parent = path.dirname(directory);
+        if (parent == directory) {
+          return lockedVersions[name];
+        }
+        directory = parent;
+      }
+    }
+
+    final dependencies = <PackageDependency>[];
+    for (final (key, isDev) in [('dependencies', false), ('devDependencies', true)]) {
+      final declared = packageJson[key] as Map<String, dynamic>? ?? {};
+      for (final entry in declared.entries) {
+        dependencies.add(PackageDependency(
+          name: entry.key,
+          requested: entry.value.toString(),
+          installed: await installedVersion(entry.key),
+          isDev: isDev,
+        ));
+      }
+    }
+    dependencies.sort((a, b) => a.name.compareTo(b.name));
+    return dependencies;
+  }
+
+  /// Hash of what bun install reads: package.json and the lockfile. Null
+  /// when node_modules is missing, so nothing counts as installed.
+  Future<String?> installInputsHash(String projectPath) async {
+    if (!await Directory(path.join(projectPath, 'node_modules')).exists()) {
+      return null;
+    }
+
+    final input = BytesBuilder(copy: false);
+    for (final name in ['package.json', 'bun.lock', 'bun.lockb']) {
+      final file = File(path.join(projectPath, name));
+      if (!await file.exists()) {
+        continue;
+      }
+      input.add(utf8.encode('$name\n'));
+      input.add(await file.readAsBytes());
+    }
+    return sha256.convert(input.takeBytes()).toString();
+  }
+
+  /// Installed dependencies declaring preinstall, install or postinstall
+  /// scripts, found by reading package.json files in node_modules
+  Future<List<InstallScriptDependency>> findInstallScriptDependencies(String projectPath) async {
+    const lifecycleScripts = ['preinstall', 'install', 'postinstall'];
+
+    final packageJson = await _readPackageJson(projectPath);
+    final trusted = (packageJson['trustedDependencies'] as List<dynamic>? ?? [])
+        .whereType<String>()
+        .toSet();
+
+    final nodeModules = Directory(path.join(projectPath, 'node_modules'));
+    if (!await nodeModules.exists()) {
+      return [];
+    }
+
+    // Top-level packages and the ones in @scope directories
+    final packageDirs = <Directory>[];
+    await for (final entity in nodeModules.list(followLinks: false)) {
+      if (entity is! Directory) continue;
+      final name = path.basename(entity.path);
+      if (name.startsWith('@')) {
+        await for (final scoped in entity.list(followLinks: false)) {
+          if (scoped is Directory) packageDirs.add(scoped);
+        }
+      } else if (!name.startsWith('.')) {
+        packageDirs.add(entity);
+      }
+    }
+
+    final dependencies = <InstallScriptDependency>[];
+    for (final dir in packageDirs) {
+      try {
+        final file = File(path.join(dir.path, 'package.json'));
+        if (!await file.exists()) continue;
+
+        final json = jsonDecode(await file.readAsString());
+        if (json is! Map<String, dynamic>) continue;
+        final scripts = json['scripts'] as Map<String, dynamic>? ?? {};
+        final installScripts = {
+          for (final name in lifecycleScripts)
+            if (scripts[name] is String) name: scripts[name] as String,
+        };
+        if (installScripts.isEmpty) continue;
+
+        final name = json['name'] as String? ?? path.basename(dir.path);
+        dependencies.add(InstallScriptDependency(
+          name: name,
+          scripts: installScripts,
+          trusted: trusted.contains(name),
+        ));
+      } catch (e) {
+        // Skip unreadable or invalid package.json files
+      }
+    }
+
+    dependencies.sort((a, b) => a.name.compareTo(b.name));
+    return dependencies;
+  }
 
   Future<Result<Project>> addSite(
     Project project,
@@ -0,0 +1,226 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+
+/// CPU and memory used by a task's process and everything it spawned
+class ProcessUsage {
+  /// Share of one core, so a busy multithreaded tree can exceed 100
+  final double cpuPercent;
+  final int rssBytes;
+
+  const ProcessUsage({required this.cpuPercent, required this.rssBytes});
+
+  String get formattedCpu => '${cpuPercent.round()}%';
+
+  String get formattedRss {
+    const mb = 1024 * 1024;
+    if (rssBytes >= 1024 * mb) {
+      return '${(rssBytes / (1024 * mb)).toStringAsFixed(1)} GB';
+    }
+    return '${(rssBytes / mb).round()} MB';
+  }
+}
+
+/// One process as seen in a snapshot of the process table
+class _ProcessEntry {
+  final int pid;
+  final int parentPid;
+
+  /// Linux: CPU clock ticks used so far. Elsewhere: current CPU percent.
+  final double cpu;
+  final int rssBytes;
+
+  _ProcessEntry(this.pid, this.parentPid, this.cpu, this.rssBytes);
+}
+
+/// Samples resource usage of process trees. Linux reads /proc, macOS asks
+/// ps and Windows asks the performance counters, through a PowerShell kept
+/// running while tasks are, as starting one takes longer than a sample.
+class ResourceMonitor {
+  // Printed by PowerShell after the answer to each query
+  static const _endOfAnswer = '--bob-end--';
+  static const _powershellTimeout = Duration(seconds: 10);
+
+  // Linux reports CPU time in clock ticks, almost always 100 per second
+  static const _linuxClockTicks = 100;
+  static const _linuxPageSize = 4096;
+
+  // Previous CPU ticks per pid and when they were read, for Linux deltas
+  Map<int, double> _previousTicks = {};
+  DateTime? _previousSampleAt;
+
+  Process? _powershell;
+  StreamIterator<String>? _powershellLines;
+
+  /// Usage of each root process and its descendants, keyed like [rootPids]
+  Future<Map<String, ProcessUsage>> sample(Map<String, int> rootPids) async {
+    if (rootPids.isEmpty) {
+      _stopPowershell();
+      return {};
+    }
+
+    final List<_ProcessEntry> processes;
+    try {
+      processes = Platform.isLinux
+          ? await _readLinuxProcesses()
+          : Platform.isWindows
+              ? await _readWindowsProcesses()
+              : await _readPsProcesses();
+    } catch (e) {
+      return {};
+    }
+
+    final cpuPercents = Platform.isLinux
+        ? _linuxCpuPercents(processes)
+        : {for (final process in processes) process.pid: process.cpu};
+
+    final children = <int, List<int>>{};
+    final byPid = <int, _ProcessEntry>{};
+    for (final process in processes) {
+      byPid[process.pid] = process;
+      children.putIfAbsent(process.parentPid, () => []).add(process.pid);
+    }
+
+    final usage = <String, ProcessUsage>{};
+    for (final MapEntry(key: key, value: rootPid) in rootPids.entries) {
+      if (!byPid.containsKey(rootPid)) {
+        continue;
+      }
+
+      var cpu = 0.0;
+      var rss = 0;
+      final pending = [rootPid];
+      final seen = <int>{};
+      while (pending.isNotEmpty) {
+        final pid = pending.removeLast();
+        if (!seen.add(pid)) {
+          continue;
+        }
+        final process = byPid[pid];
+        if (process == null) {
+          continue;
+        }
+        cpu += cpuPercents[pid] ?? 0;
+        rss += process.rssBytes;
+        pending.addAll(children[pid] ?? const []);
+      }
+      usage[key] = ProcessUsage(cpuPercent: cpu, rssBytes: rss);
+    }
+    return usage;
+  }
+
+  Map<int, double> _linuxCpuPercents(List<_ProcessEntry> processes) {
+    final now = DateTime.now();
+    final elapsed = _previousSampleAt == null
+        ? null
+        : now.difference(_previousSampleAt!).inMicroseconds / Duration.microsecondsPerSecond;
+
+    final percents = <int, double>{};
+    for (final process in processes) {
+      final previous = _previousTicks[process.pid];
+      if (elapsed != null && elapsed > 0 && previous != null) {
+        percents[process.pid] = (process.cpu - previous) / _linuxClockTicks / elapsed * 100;
+      }
+    }
+
+    _previousTicks = {for (final process in processes) process.pid: process.cpu};
+    _previousSampleAt = now;
+    return percents;
+  }
+
+  Future<List<_ProcessEntry>> _readLinuxProcesses() async {
+    final processes = <_ProcessEntry>[];
+    await for (final entity in Directory('/proc').list(followLinks: false)) {
+      final pid = int.tryParse(entity.path.split('/').last);
+      if (pid == null) {
+        continue;
+      }
+      try {
+        final stat = await File('/proc/$pid/stat').readAsString();
+        // The command name is in parentheses and may contain spaces
+        final fields = stat.substring(stat.lastIndexOf(')') + 2).split(' ');
+        processes.add(_ProcessEntry(
+          pid,
+          int.parse(fields[1]),
+          double.parse(fields[11]) + double.parse(fields[12]),
+          int.parse(fields[21]) * _linuxPageSize,
+        ));
+      } catch (e) {
+        // Exited while the table was being read
+      }
+    }
+    return processes;
+  }
+
+  Future<List<_ProcessEntry>> _readPsProcesses() async {
+    final result = await Process.run('ps', ['-A', '-o', 'pid=,ppid=,pcpu=,rss=']);
+    return (result.stdout as String)
+        .split('\n')
+        .map((line) => line.trim().split(RegExp(r'\s+')))
+        .where((fields) => fields.length == 4)
+        .map((fields) => _ProcessEntry(
+              int.parse(fields[0]),
+              int.parse(fields[1]),
+              double.parse(fields[2]),
+              int.parse(fields[3]) * 1024,
+            ))
+        .toList();
+  }
+
+  Future<List<_ProcessEntry>> _readWindowsProcesses() async {
+    final lines = await _queryPowershell(
+      'Get-CimInstance Win32_PerfFormattedData_PerfProc_Process | '
+      'ForEach-Object { "\$(\$_.IDProcess) \$(\$_.CreatingProcessID) \$(\$_.PercentProcessorTime) \$(\$_.WorkingSet)" }',
+    );
+    return lines
+        .map((line) => line.trim().split(' '))
+        .where((fields) => fields.length == 4 && fields[0] != '0')
+        .map((fields) => _ProcessEntry(
+              int.parse(fields[0]),
+              int.parse(fields[1]),
+              double.parse(fields[2]),
+              int.parse(fields[3]),
+            ))
+        .toList();
+  }
+
+  /// Run [command] in the PowerShell kept running, started first if needed,
+  /// and return the lines it printed
+  Future<List<String>> _queryPowershell(String command) async {
+    if (_powershell == null) {
+      final powershell = await Process.start('powershell', ['-NoProfile', '-NonInteractive', '-Command', '-']);
+      powershell.stderr.drain<void>();
+      _powershell = powershell;
+      _powershellLines = StreamIterator(powershell.stdout.transform(utf8.decoder).transform(const LineSplitter()));
+    }
+
+    try {
+      _powershell!.stdin.writeln('$command; "$_endOfAnswer"');
+      final lines = <String>[];
+      final output = _powershellLines!;
+      while (await output.moveNext().timeout(_powershellTimeout)) {
+        if (output.current.trim() == _endOfAnswer) {
+          return lines;
+        }
+        lines.add(output.current);
+      }
+      throw const ProcessException('powershell', [], 'Exited');
+    } catch (e) {
+      // Start over with a new one next time
+      _stopPowershell();
+      rethrow;
+    }
+  }
+
+  void _stopPowershell() {
+    _powershellLines?.cancel();
+    _powershellLines = null;
+    _powershell?.kill();
+    _powershell = null;
+  }
+
+  /// Stop the PowerShell kept for sampling, if any
+  void dispose() {
+    _stopPowershell();
+  }
+}
@@ -0,0 +1,38 @@
+import 'package:flutter_secure_storage/flutter_secure_storage.dart';
+
+/// Values of secret environment variables, kept in the keychain of the OS
+/// (Keychain on macOS, Credential Manager on Windows, the Secret Service on
+/// Linux) rather than in package.json, which only lists their names
+class SecretStore {
+  static final SecretStore _instance = SecretStore._internal();
+  factory SecretStore() => _instance;
+  SecretStore._internal();
+
+  final FlutterSecureStorage _storage = const FlutterSecureStorage();
+
+  String _key(String projectPath, String name) => 'bob:env:$projectPath:$name';
+
+  Future<String?> read(String projectPath, String name) {
+    return _storage.read(key: _key(projectPath, name));
+  }
+
+  /// Values of the secrets of a project, leaving out those not stored on
+  /// this computer
+  Future<Map<String, String>> readAll(String projectPath, List<String> names) async {
+    final values = <String, String>{};
+    for (final name in names) {
+      if (await read(projectPath, name) case final value?) {
+        values[name] = value;
+      }
+    }
+    return values;
+  }
+
+  Future<void> write(String projectPath, String name, String value) {
+    return _storage.write(key: _key(projectPath, name), value: value);
+  }
+
+  Future<void> delete(String projectPath, String name) {
+    return _storage.delete(key: _key(projectPath, name));
+  }
+}
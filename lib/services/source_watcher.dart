@@ -0,0 +1,113 @@
+import 'dart:async';
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+import '../models/project.dart';
+import '../utils/watch_filter.dart';
+
+/// Watches the source files of a project and reports changes to those its
+/// [WatchFilter] accepts, with paths relative to the project folder
+class SourceWatcher {
+  final String projectPath;
+  final void Function(String relativePath) onChange;
+  Project _project;
+  WatchFilter _filter;
+
+  // Linux: one watcher per directory. Elsewhere: one recursive watcher.
+  final Map<String, StreamSubscription<FileSystemEvent>> _watchers = {};
+
+  SourceWatcher(Project project, this.onChange)
+      : projectPath = project.path,
+        _project = project,
+        _filter = WatchFilter.forProject(project);
+
+  void start() {
+    if (Platform.isLinux) {
+      // inotify watches every directory separately and their number is
+      // limited, so only descend into directories that aren't excluded
+      _watchTree(projectPath);
+    } else {
+      _watch(projectPath, recursive: true);
+    }
+  }
+
+  /// Pick up changed watch globs after the project was reloaded
+  void updateProject(Project project) {
+    _project = project;
+    _filter = WatchFilter.forProject(project);
+  }
+
+  void cancel() {
+    for (final subscription in _watchers.values) {
+      subscription.cancel();
+    }
+    _watchers.clear();
+  }
+
+  void _watch(String directory, {bool recursive = false}) {
+    try {
+      _watchers[directory] = Directory(directory)
+          .watch(events: FileSystemEvent.all, recursive: recursive)
+          .listen(_onEvent, onError: (error) {
+        // Silently ignore watcher errors
+      });
+    } catch (e) {
+      // Directory vanished or can't be watched
+    }
+  }
+
+  void _watchTree(String directory) {
+    if (_watchers.containsKey(directory)) {
+      return;
+    }
+
+    _watch(directory);
+    try {
+      for (final entity in Directory(directory).listSync(followLinks: false)) {
+        if (entity is Directory && !_filter.excludes(_relative(entity.path))) {
+          _watchTree(entity.path);
+        }
+      }
+    } catch (e) {
+      // Directory vanished while being listed
+    }
+  }
+
+  void _unwatchTree(String directory) {
+    for (final watched in _watchers.keys.toList()) {
+      if (watched == directory || path.isWithin(directory, watched)) {
+        _watchers.remove(watched)?.cancel();
+      }
+    }
+  }
+
+  void _onEvent(FileSystemEvent event) {
+    final eventPath = event is FileSystemMoveEvent && event.destination != null
+        ? event.destination!
+        : event.path;
+    final relativePath = _relative(eventPath);
+
+    if (Platform.isLinux && event.isDirectory) {
+      if (event.type == FileSystemEvent.delete || event.type == FileSystemEvent.move) {
+        _unwatchTree(event.path);
+      }
+      final appeared = event.type == FileSystemEvent.create ||
+          (event is FileSystemMoveEvent && event.destination != null);
+      if (appeared && !_filter.excludes(relativePath)) {
+        _watchTree(eventPath);
+      }
+    }
+
+    if (relativePath == '.gitignore') {
+      _filter = WatchFilter.forProject(_project);
+    }
+
+    if (_filter.matches(relativePath)) {
+      onChange(relativePath);
+    }
+  }
+
+  String _relative(String absolutePath) =>
+      path.split(path.relative(absolutePath, from: projectPath)).join('/');
+}
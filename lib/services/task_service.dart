@@ -2,19 +2,43 @@ import 'dart:async';
 import 'dart:convert';
 import 'dart:io';
 
+import 'package:path/path.dart' as path;
+
 import '../config/constants.dart';
 import '../models/project.dart';
+import '../models/task_settings.dart';
+import '../utils/env_utils.dart';
 import '../utils/process_utils.dart';
+import '../utils/procfile_utils.dart';
 import '../utils/task_utils.dart';
+import '../utils/wsl_utils.dart';
 import './binary_manager.dart';
+import './devcontainer_service.dart';
+import './preferences_service.dart';
+import './secret_store.dart';
 
 class TaskService {
   final Map<String, Process> _runningProcesses = {};
   final Map<String, StreamController<String>> _outputControllers = {};
   final Set<String> _explicitlyStopped = {};
+
+  // Stops the process a task runs in a container or in WSL, which outlives
+  // the command it was started with. Forced when given true.
+  final Map<String, Future<void> Function(bool)> _innerStops = {};
   final BinaryManager _binaryManager = BinaryManager();
 
-  Future<void> startTask(Project project, Task task, Function(String) onOutput, Function(int) onExit) async {
+  /// Whether tasks can be pinned to CPU cores on this platform.
+  /// macOS has no public API for thread affinity.
+  static bool get supportsCpuPinning => Platform.isLinux || Platform.isWindows;
+
+  Future<void> startTask(
+    Project project,
+    Task task,
+    Function(String) onOutput,
+    Function(int) onExit, {
+    TaskSettings settings = const TaskSettings(),
+    Function(String)? onErrorOutput,
+  }) async {
     final taskKey = TaskUtils.getTaskKey(project, task);
 
     // Clear explicitly stopped flag when starting
@@ -25,135 +49,611 @@ class TaskService {
       await stopTask(project, task);
     }
 
+    if (project.isRemote) {
+      await _startRemoteTask(project, task, onOutput, onExit, onErrorOutput);
+      return;
+    }
+    if (project.docker != null) {
+      await _startDockerTask(project, task, onOutput, onExit, settings, onErrorOutput);
+      return;
+    }
+    if (Platform.isWindows && project.runInWsl) {
+      await _startWslTask(project, task, onOutput, onExit, settings, onErrorOutput);
+      return;
+    }
+
     try {
-      // Get managed binaries
-      final bunPath = await _binaryManager.getBunPath();
+      // Get managed binaries, honoring a bun version pinned by the project
+      final bunVersion = await _binaryManager.getProjectBunVersion(project.path);
+      if (bunVersion != null && bunVersion != AppConstants.bunVersion) {
+        onOutput('Using bun $bunVersion pinned by the project\n');
+      }
+      final bunPath = await _binaryManager.getBunPath(version: bunVersion);
 
-      // Build environment with bun in PATH
-      final environment = ProcessUtils.buildEnvironmentWithBinaries([bunPath]);
+      // Build environment with bun in PATH. From lowest to highest precedence:
+      // the system, workspace variables, env files and the project's overrides.
+      // Like dotenv, env files don't replace variables set in the system.
+      final workspaces = await PreferencesService().getWorkspaceEnvironments();
+      final envFileVariables = project.loadEnvFiles
+          ? await EnvUtils.readEnvFiles(project.path, [...EnvUtils.defaultEnvFiles, ...project.envFiles])
+          : <String, String>{};
+      envFileVariables.removeWhere((key, _) => Platform.environment.containsKey(key));
+      final environment = ProcessUtils.buildEnvironmentWithBinaries(
+        [bunPath],
+        variables: {
+          ...EnvUtils.inheritedFor(project.path, workspaces),
+          ...envFileVariables,
+          ...project.environment,
+        },
+      );
+
+      // Secrets come from the keychain only now, and win over everything
+      final secrets = await SecretStore().readAll(project.path, project.secretEnvironment);
+      for (final name in project.secretEnvironment.where((name) => !secrets.containsKey(name))) {
+        onOutput('[bob] Secret $name is not set on this computer, set it in the project configuration\n');
+      }
+      environment.addAll(secrets);
+
+      final workingDirectory = (task.type == TaskType.script || task.type == TaskType.custom) &&
+              settings.workingDirectory != null
+          ? path.normalize(path.join(project.path, settings.workingDirectory!))
+          : project.path;
+      if (!await Directory(workingDirectory).exists()) {
+        throw Exception('Working directory $workingDirectory does not exist');
+      }
+
+      if (settings.preRun != null) {
+        final exitCode = await _runHook('Pre-run', settings.preRun!, workingDirectory, environment, onOutput);
+        if (exitCode != 0) {
+          onExit(exitCode);
+          return;
+        }
+      }
 
       // Determine arguments based on task type
-      final List<String> args;
-      if (task.type == TaskType.install) {
+      var executable = bunPath;
+      List<String> args;
+      if (task.type == TaskType.make) {
+        executable = 'make';
+        args = [task.command.substring('make '.length)];
+      } else if (task.type == TaskType.taskfile) {
+        executable = 'task';
+        args = [task.command.substring('task '.length)];
+      } else if (task.type == TaskType.custom) {
+        // Commands added in bob run through the shell, with the project's binaries on PATH
+        (executable, args) = _throughShell(task.command);
+        final binDirectory = path.join(project.path, 'node_modules', '.bin');
+        environment['PATH'] = '$binDirectory${ProcessUtils.pathSeparator}${environment['PATH']}';
+      } else if (task.type == TaskType.python) {
+        // uv and poetry come from the system, found through the shell
+        (executable, args) = _throughShell(task.command);
+      } else if (task.type == TaskType.procfile) {
+        // Like foreman: through a shell, with the project's binaries on PATH
+        // and a port per process type unless PORT is already set
+        (executable, args) = _throughShell(task.command);
+        final binDirectory = path.join(project.path, 'node_modules', '.bin');
+        environment['PATH'] = '$binDirectory${ProcessUtils.pathSeparator}${environment['PATH']}';
+        final index = project.tasks
+            .where((t) => t.type == TaskType.procfile)
+            .toList()
+            .indexWhere((t) => t.name == task.name);
+        environment.putIfAbsent('PORT', () => '${ProcfileUtils.basePort + index * 100}');
+      } else if (task.type == TaskType.install) {
         // For install tasks, run 'bun install' directly
-        args = ['install'];
+        args = [
+          'install',
+          if (project.frozenLockfile) '--frozen-lockfile',
+          if (project.ignoreScripts) '--ignore-scripts',
+        ];
+      } else if (workingDirectory != project.path) {
+        // 'bun run' would use the package.json of the working directory, so
+        // run the script's command there with the project's binaries on PATH
+        args = ['exec', task.command];
+        final binDirectory = path.join(project.path, 'node_modules', '.bin');
+        environment['PATH'] = '$binDirectory${ProcessUtils.pathSeparator}${environment['PATH']}';
       } else {
         // For script tasks, run 'bun run <task-name>'
         // This executes the script from package.json
         args = ['run', task.name];
       }
 
+      final (command, arguments) = _wrapWithScheduling(executable, args, settings);
       final process = await Process.start(
-        bunPath,
-        args,
-        workingDirectory: project.path,
+        command,
+        arguments,
+        workingDirectory: workingDirectory,
         runInShell: Platform.isWindows, // Use shell on Windows to ensure child processes are killed
         environment: environment,
       );
 
       _runningProcesses[taskKey] = process;
-      final outputController = StreamController<String>();
-      _outputControllers[taskKey] = outputController;
-
-      // Listen to stdout
-      process.stdout.transform(utf8.decoder).listen(
-        (data) {
-          onOutput(data);
-          if (!outputController.isClosed) {
-            outputController.add(data);
-          }
-        },
-        onError: (error) {
-          // Ignore errors
-        },
-        cancelOnError: false,
-      );
 
-      // Listen to stderr
-      process.stderr.transform(utf8.decoder).listen(
-        (data) {
-          onOutput(data);
-          if (!outputController.isClosed) {
-            outputController.add(data);
-          }
-        },
-        onError: (error) {
-          // Ignore errors
-        },
-        cancelOnError: false,
+      _listen(
+        taskKey,
+        process,
+        onOutput,
+        onExit,
+        onErrorOutput,
+        afterExit: settings.postRun == null
+            ? null
+            : () => _runHook('Post-run', settings.postRun!, workingDirectory, environment, onOutput),
       );
+    } catch (e) {
+      onOutput('Error starting task: $e\n');
+      onExit(-1);
+    }
+  }
 
-      // Listen to exit
-      process.exitCode.then((exitCode) {
+  /// Run a task of a remote project on its host through ssh. The terminal
+  /// ssh allocates there ends the task when bob stops ssh.
+  Future<void> _startRemoteTask(
+    Project project,
+    Task task,
+    Function(String) onOutput,
+    Function(int) onExit,
+    Function(String)? onErrorOutput,
+  ) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    try {
+      final command = _shellCommand(task);
+
+      // The environment of this computer stays here, the project's goes along
+      final environment = {
+        ...project.environment,
+        ...await SecretStore().readAll(project.path, project.secretEnvironment),
+      };
+
+      // Through stdin into a file only the user can read, which the task
+      // reads and removes, so secrets stay out of any command line
+      final environmentFile = environment.isEmpty ? null : await _sendEnvironment(project.host!, environment);
+      final loadEnvironment = environmentFile != null
+          ? '. ${ProcessUtils.shellQuote(environmentFile)}; rm -f ${ProcessUtils.shellQuote(environmentFile)}; '
+          : '';
+
+      final process = await Process.start('ssh', [
+        '-tt',
+        '-o',
+        'BatchMode=yes',
+        project.host!,
+        '${loadEnvironment}cd ${ProcessUtils.shellQuote(project.remotePath)} && '
+            'sh -c ${ProcessUtils.shellQuote(command)}',
+      ]);
+      _runningProcesses[taskKey] = process;
+      _listen(taskKey, process, onOutput, onExit, onErrorOutput);
+    } catch (e) {
+      onOutput('Error starting task: $e\n');
+      onExit(-1);
+    }
+  }
+
+  /// Write [environment] as export lines to a new file on [host], through
+  /// the stdin of ssh. Returns the path of the file.
+  Future<String> _sendEnvironment(String host, Map<String, String> environment) async {
+    final process = await Process.start('ssh', [
+      '-o',
+      'BatchMode=yes',
+      host,
+      'umask 077 && file=\$(mktemp) && cat > "\$file" && echo "\$file"',
+    ]);
+    process.stdin.write([
+      for (final MapEntry(:key, :value) in environment.entries) 'export $key=${ProcessUtils.shellQuote(value)}\n',
+    ].join());
+    await process.stdin.close();
+
+    final (output, _, exitCode) = await (
+      process.stdout.transform(utf8.decoder).join(),
+      process.stderr.drain<void>(),
+      process.exitCode,
+    ).wait;
+    final file = output.trim();
+    if (exitCode != 0 || file.isEmpty) {
+      throw Exception('Could not pass the environment to $host');
+    }
+    return file;
+  }
+
+  /// Run a task of a project in Docker: in a new container of its image with
+  /// the project folder mounted, or in its running container or devcontainer
+  Future<void> _startDockerTask(
+    Project project,
+    Task task,
+    Function(String) onOutput,
+    Function(int) onExit,
+    TaskSettings settings,
+    Function(String)? onErrorOutput,
+  ) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    var docker = project.docker!;
+    try {
+      if (docker.devcontainer) {
+        onOutput('[bob] Starting devcontainer\n');
+        docker = await DevcontainerService().up(project.path, onOutput);
+        onOutput('[bob] Devcontainer started\n');
+      }
+
+      final environment = await _isolatedEnvironment(project);
+
+      final subdirectory = (task.type == TaskType.script || task.type == TaskType.custom)
+          ? settings.workingDirectory
+          : null;
+      final workdir = subdirectory != null ? path.posix.join(docker.workdir, subdirectory) : docker.workdir;
+      // 'bun run' would use the package.json of the working directory
+      final command = subdirectory != null && task.type == TaskType.script
+          ? 'bun exec ${ProcessUtils.shellQuote(task.command)}'
+          : _shellCommand(task);
+
+      final name = 'bob-${DateTime.now().microsecondsSinceEpoch}';
+      final List<String> arguments;
+      if (docker.container case final container?) {
+        // docker exec leaves the process running when stopped, so remember
+        // it to stop it there
+        final pidFile = '/tmp/$name.pid';
+        arguments = [
+          'exec',
+          '-i',
+          '-w',
+          workdir,
+          for (final key in environment.keys) ...['-e', key],
+          container,
+          'sh',
+          '-c',
+          'echo \$\$ > $pidFile; exec sh -c ${ProcessUtils.shellQuote(command)}',
+        ];
+        _innerStops[taskKey] = (force) => Process.run('docker', [
+              'exec',
+              container,
+              'sh',
+              '-c',
+              'kill -${force ? 'KILL' : 'TERM'} \$(cat $pidFile); rm -f $pidFile',
+            ]);
+      } else {
+        arguments = [
+          'run',
+          '--rm',
+          '-i',
+          '--init',
+          '--name',
+          name,
+          '-v',
+          '${project.path}:${docker.workdir}',
+          '-w',
+          workdir,
+          for (final key in environment.keys) ...['-e', key],
+          docker.image!,
+          'sh',
+          '-c',
+          command,
+        ];
+        _innerStops[taskKey] = (force) async {
+          final seconds = (await _stopGracePeriod()).inSeconds;
+          await Process.run('docker', force ? ['kill', name] : ['stop', '-t', '$seconds', name]);
+        };
+      }
+
+      // Named only, docker reads the values from its own environment so
+      // secrets stay out of its command line
+      final process = await Process.start('docker', arguments, environment: environment);
+      _runningProcesses[taskKey] = process;
+      _listen(taskKey, process, onOutput, (exitCode) {
+        _innerStops.remove(taskKey);
+        onExit(exitCode);
+      }, onErrorOutput);
+    } catch (e) {
+      _innerStops.remove(taskKey);
+      onOutput('Error starting task: $e\n');
+      onExit(-1);
+    }
+  }
+
+  /// Run a task in WSL with the Linux bun, for projects whose files are
+  /// there or need Linux tools
+  Future<void> _startWslTask(
+    Project project,
+    Task task,
+    Function(String) onOutput,
+    Function(int) onExit,
+    TaskSettings settings,
+    Function(String)? onErrorOutput,
+  ) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    try {
+      final environment = await _isolatedEnvironment(project);
+      final distro = project.wslDistro ?? WslUtils.distroOf(project.path);
+      final distroArguments = distro != null ? ['-d', distro] : <String>[];
+
+      final subdirectory = (task.type == TaskType.script || task.type == TaskType.custom)
+          ? settings.workingDirectory
+          : null;
+      final projectDirectory = WslUtils.toLinuxPath(project.path);
+      final workingDirectory =
+          subdirectory != null ? path.posix.normalize(path.posix.join(projectDirectory, subdirectory)) : projectDirectory;
+      // 'bun run' would use the package.json of the working directory
+      final command = subdirectory != null && task.type == TaskType.script
+          ? 'bun exec ${ProcessUtils.shellQuote(task.command)}'
+          : _shellCommand(task);
+
+      // Stopping wsl.exe leaves the task running in WSL, so remember it to
+      // stop it there. A login shell puts the Linux bun on PATH.
+      final pidFile = '/tmp/bob-${DateTime.now().microsecondsSinceEpoch}.pid';
+      // WSLENV shares the named variables of wsl.exe's environment with
+      // Linux, so secrets stay out of its command line
+      final sharedVariables = [
+        if (Platform.environment['WSLENV'] case final inherited? when inherited.isNotEmpty) inherited,
+        ...environment.keys,
+      ];
+      final process = await Process.start(
+        'wsl.exe',
+        [
+          ...distroArguments,
+          '--cd',
+          workingDirectory,
+          '--',
+          'bash',
+          '-lc',
+          'echo \$\$ > $pidFile; exec sh -c ${ProcessUtils.shellQuote(command)}',
+        ],
+        environment: {...environment, 'WSLENV': sharedVariables.join(':')},
+      );
+      _innerStops[taskKey] = (force) => Process.run('wsl.exe', [
+            ...distroArguments,
+            '--',
+            'sh',
+            '-c',
+            'kill -${force ? 'KILL' : 'TERM'} \$(cat $pidFile); rm -f $pidFile',
+          ]);
+      _runningProcesses[taskKey] = process;
+      _listen(taskKey, process, onOutput, (exitCode) {
+        _innerStops.remove(taskKey);
         onExit(exitCode);
-        _runningProcesses.remove(taskKey);
+      }, onErrorOutput);
+    } catch (e) {
+      _innerStops.remove(taskKey);
+      onOutput('Error starting task: $e\n');
+      onExit(-1);
+    }
+  }
+
+  /// The environment of a task run away from this computer's: the project's
+  /// env files, variables and secrets, without the system's variables
+  Future<Map<String, String>> _isolatedEnvironment(Project project) async {
+    return {
+      if (project.loadEnvFiles)
+        ...await EnvUtils.readEnvFiles(project.path, [...EnvUtils.defaultEnvFiles, ...project.envFiles]),
+      ...project.environment,
+      ...await SecretStore().readAll(project.path, project.secretEnvironment),
+    };
+  }
+
+  /// What runs a task through a shell, e.g. on a remote host or in a container
+  String _shellCommand(Task task) {
+    return task.type == TaskType.script ? 'bun run ${ProcessUtils.shellQuote(task.name)}' : task.command;
+  }
+
+  /// Pass on the output and exit of a started task
+  void _listen(
+    String taskKey,
+    Process process,
+    Function(String) onOutput,
+    Function(int) onExit,
+    Function(String)? onErrorOutput, {
+    Future<void> Function()? afterExit,
+  }) {
+    final outputController = StreamController<String>();
+    _outputControllers[taskKey] = outputController;
+
+    // Listen to stdout
+    process.stdout.transform(utf8.decoder).listen(
+      (data) {
+        onOutput(data);
+        if (!outputController.isClosed) {
+          outputController.add(data);
+        }
+      },
+      onError: (error) {
+        // Ignore errors
+      },
+      cancelOnError: false,
+    );
+
+    // Listen to stderr
+    process.stderr.transform(utf8.decoder).listen(
+      (data) {
+        (onErrorOutput ?? onOutput)(data);
         if (!outputController.isClosed) {
-          outputController.close();
+          outputController.add(data);
         }
+      },
+      onError: (error) {
+        // Ignore errors
+      },
+      cancelOnError: false,
+    );
+
+    // Listen to exit
+    process.exitCode.then((exitCode) async {
+      await afterExit?.call();
+      onExit(exitCode);
+      _runningProcesses.remove(taskKey);
+      if (!outputController.isClosed) {
+        outputController.close();
+      }
+      // The task may have been started again meanwhile
+      if (identical(_outputControllers[taskKey], outputController)) {
         _outputControllers.remove(taskKey);
-      });
+      }
+    });
+  }
+
+  /// Run a pre-run or post-run command through the shell, announcing it so
+  /// its output folds into a section of the task's log
+  Future<int> _runHook(
+    String label,
+    String command,
+    String workingDirectory,
+    Map<String, String> environment,
+    Function(String) onOutput,
+  ) async {
+    onOutput('[bob] $label command: $command\n');
+    try {
+      final (executable, args) = _throughShell(command);
+      final process = await Process.start(
+        executable,
+        args,
+        workingDirectory: workingDirectory,
+        environment: environment,
+      );
+      final stdoutDone = process.stdout.transform(utf8.decoder).forEach(onOutput);
+      final stderrDone = process.stderr.transform(utf8.decoder).forEach(onOutput);
+      final exitCode = await process.exitCode;
+      await Future.wait([stdoutDone, stderrDone]);
+      onOutput(exitCode == 0
+          ? '[bob] $label command finished\n'
+          : '[bob] $label command failed with exit code $exitCode\n');
+      return exitCode;
     } catch (e) {
-      onOutput('Error starting task: $e\n');
-      onExit(-1);
+      onOutput('[bob] $label command failed: $e\n');
+      return -1;
+    }
+  }
+
+  (String, List<String>) _throughShell(String command) {
+    return Platform.isWindows ? ('cmd', ['/c', command]) : ('/bin/sh', ['-c', command]);
+  }
+
+  /// Wrap the command with taskset/nice, or start on Windows, so child
+  /// processes inherit the affinity and priority from the start
+  (String, List<String>) _wrapWithScheduling(
+    String executable,
+    List<String> args,
+    TaskSettings settings,
+  ) {
+    if (Platform.isWindows) {
+      if (settings.cpuCores.isEmpty && !settings.highPriority) {
+        return (executable, args);
+      }
+      // Runs in the shell, where start is built in. /b keeps the console so
+      // stopping still reaches the task, /wait passes its exit code on.
+      final mask = settings.cpuCores.fold<int>(0, (mask, core) => mask | (1 << core));
+      return (
+        'start',
+        [
+          '',
+          '/b',
+          '/wait',
+          if (settings.cpuCores.isNotEmpty) ...['/affinity', mask.toRadixString(16)],
+          if (settings.highPriority) '/high',
+          executable,
+          ...args,
+        ],
+      );
     }
+
+    var command = [executable, ...args];
+    if (settings.highPriority) {
+      // Negative niceness needs privileges; nice still runs the command without them
+      command = ['nice', '-n', '-10', ...command];
+    }
+    if (settings.cpuCores.isNotEmpty && Platform.isLinux) {
+      command = ['taskset', '-c', settings.cpuCores.join(','), ...command];
+    }
+    return (command.first, command.sublist(1));
   }
 
-  Future<void> stopTask(Project project, Task task) async {
+  /// Stop a task, giving it the grace period to exit. Returns whether it
+  /// exited; one that didn't is left for [killTask] to finish off.
+  Future<bool> stopTask(Project project, Task task) async {
     final taskKey = TaskUtils.getTaskKey(project, task);
     final process = _runningProcesses[taskKey];
+    if (process == null) {
+      return true;
+    }
 
-    if (process != null) {
-      // Mark as explicitly stopped before killing
-      _explicitlyStopped.add(taskKey);
+    // Mark as explicitly stopped before killing
+    _explicitlyStopped.add(taskKey);
 
-      await _killProcess(process);
-      _runningProcesses.remove(taskKey);
-      _outputControllers[taskKey]?.close();
-      _outputControllers.remove(taskKey);
+    await _stopInner(taskKey, force: false);
+    if (!await _killProcess(process)) {
+      return false;
+    }
+    _runningProcesses.remove(taskKey);
+    _outputControllers[taskKey]?.close();
+    _outputControllers.remove(taskKey);
+    return true;
+  }
+
+  /// Kill a task and its child processes right away, cutting short the
+  /// grace period of a stop in progress
+  Future<void> killTask(Project project, Task task) async {
+    final taskKey = TaskUtils.getTaskKey(project, task);
+    final process = _runningProcesses[taskKey];
+    if (process == null) {
+      return;
+    }
+
+    _explicitlyStopped.add(taskKey);
+    await _stopInner(taskKey, force: true);
+    try {
+      if (Platform.isWindows) {
+        await Process.run('taskkill', ['/F', '/T', '/PID', process.pid.toString()]);
+      } else {
+        process.kill(ProcessSignal.sigkill);
+      }
+    } catch (e) {
+      // The process may have exited in the meantime
+    }
+  }
+
+  Future<void> _stopInner(String taskKey, {required bool force}) async {
+    try {
+      await _innerStops.remove(taskKey)?.call(force);
+    } catch (e) {
+      // Docker or WSL went away, and the task with it
     }
   }
 
-  Future<void> _killProcess(Process process) async {
+  /// How long a stopped task has to exit on its own, from the settings
+  Future<Duration> _stopGracePeriod() async {
+    final seconds = await PreferencesService().getStopGracePeriod();
+    return seconds != null ? Duration(seconds: seconds) : AppConstants.processKillGracePeriod;
+  }
+
+  /// Ask a process to exit, forcing it once the grace period is over.
+  /// Returns whether it exited, as even a forced kill may not take.
+  Future<bool> _killProcess(Process process) async {
+    Future<bool> exitedWithin(Duration timeout) =>
+        process.exitCode.then((_) => true).timeout(timeout, onTimeout: () => false);
+
     try {
       final pid = process.pid;
+      final gracePeriod = await _stopGracePeriod();
 
       // On Windows, kill the entire process tree to ensure child processes are terminated
       if (Platform.isWindows) {
         try {
+          // Let servers shut down on CTRL_BREAK first, then force the whole tree
+          if (await _sendCtrlBreak(pid) && await exitedWithin(gracePeriod)) {
+            return true;
+          }
+
           // Use taskkill to kill the process tree
           // /F = force, /T = terminate tree, /PID = process ID
           await Process.run('taskkill', ['/F', '/T', '/PID', pid.toString()]);
 
           // Wait for process to actually exit
-          await process.exitCode.timeout(
-            AppConstants.processKillGracePeriod,
-            onTimeout: () => -1,
-          );
-          return;
+          return await exitedWithin(AppConstants.processKillGracePeriod);
         } catch (e) {
           // If taskkill fails, fall back to normal kill
         }
       }
 
       // Unix/fallback: Try graceful termination first (SIGTERM)
-      final killed = process.kill(ProcessSignal.sigterm);
-
-      if (killed) {
-        // Wait for graceful shutdown
-        await process.exitCode.timeout(
-          AppConstants.processKillGracePeriod,
-          onTimeout: () {
-            // Force kill if timeout (SIGKILL)
-            process.kill(ProcessSignal.sigkill);
-            return process.exitCode;
-          },
-        );
-        return;
+      if (process.kill(ProcessSignal.sigterm) && await exitedWithin(gracePeriod)) {
+        return true;
       }
 
-      // If SIGTERM didn't work, force kill immediately
+      // Force kill if it didn't work or timed out (SIGKILL)
       process.kill(ProcessSignal.sigkill);
-      await process.exitCode;
+      return await exitedWithin(AppConstants.processKillGracePeriod);
     } catch (e) {
       // If all else fails, try default kill
       try {
@@ -161,9 +661,61 @@ class TaskService {
       } catch (_) {
         // Ignore errors - process might already be dead
       }
+      return false;
+    }
+  }
+
+  /// Deliver CTRL_BREAK_EVENT to the console of a process. Windows only lets a
+  /// process signal its own console, so a helper attaches to the task's. The
+  /// event reaches the helper as well, which a handler returning true makes
+  /// it live through; a null handler only ignores CTRL+C.
+  Future<bool> _sendCtrlBreak(int pid) async {
+    const script = r'''
+$k = Add-Type -Name Console -Namespace Bob -PassThru -MemberDefinition '
+public delegate bool Handler(uint ctrlType);
+static Handler survive = ctrlType => true;
+[DllImport("kernel32.dll")] public static extern bool FreeConsole();
+[DllImport("kernel32.dll")] public static extern bool AttachConsole(uint pid);
+[DllImport("kernel32.dll")] static extern bool SetConsoleCtrlHandler(Handler handler, bool add);
+[DllImport("kernel32.dll")] public static extern bool GenerateConsoleCtrlEvent(uint ctrlEvent, uint group);
+public static bool Survive() { return SetConsoleCtrlHandler(survive, true); }'
+$k::FreeConsole() | Out-Null
+if (-not $k::AttachConsole(PID)) { exit 1 }
+if (-not $k::Survive()) { exit 1 }
+if (-not $k::GenerateConsoleCtrlEvent(1, 0)) { exit 1 }
+''';
+
+    try {
+      final result = await Process.run(
+        'powershell',
+        ['-NoProfile', '-Command', script.replaceFirst('PID', pid.toString())],
+      );
+      return result.exitCode == 0;
+    } catch (e) {
+      return false;
+    }
+  }
+
+  /// Check every tracked process against the OS and drop the ones that are
+  /// gone without their exit being noticed. Returns the removed task keys.
+  Future<List<String>> reconcile() async {
+    final staleKeys = <String>[];
+    for (final entry in _runningProcesses.entries.toList()) {
+      if (!await ProcessUtils.isProcessAlive(entry.value.pid)) {
+        // Its output controller is left to close when the exit comes through,
+        // as output may still be on its way
+        staleKeys.add(entry.key);
+        _runningProcesses.remove(entry.key);
+      }
     }
+    return staleKeys;
   }
 
+  /// Process ids of running tasks, by task key
+  Map<String, int> get runningPids => {
+        for (final entry in _runningProcesses.entries) entry.key: entry.value.pid,
+      };
+
   bool isTaskRunning(Project project, Task task) {
     final taskKey = TaskUtils.getTaskKey(project, task);
     return _runningProcesses.containsKey(taskKey);
@@ -184,8 +736,9 @@ class TaskService {
     return _outputControllers[taskKey]?.stream;
   }
 
-  Future<void> dispose() async {
-    // Kill all running processes
+  /// Stop every running task with the same grace period as stopping one
+  Future<void> stopAll() async {
+    _explicitlyStopped.addAll(_runningProcesses.keys);
     final killFutures = <Future<void>>[];
     for (final process in _runningProcesses.values) {
       killFutures.add(_killProcess(process));
@@ -208,6 +761,16 @@ class TaskService {
     );
 
     _runningProcesses.clear();
+  }
+
+  /// Forget running tasks without stopping them, so they outlive bob
+  void releaseAll() {
+    _runningProcesses.clear();
+  }
+
+  Future<void> dispose() async {
+    // Kill all running processes
+    await stopAll();
 
     // Close all output controllers
     for (final controller in _outputControllers.values) {
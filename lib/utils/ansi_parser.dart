@@ -2,13 +2,37 @@ import 'package:flutter/gestures.dart';
 import 'package:flutter/material.dart';
 import 'package:url_launcher/url_launcher.dart';
 
+/// Called with the path, line and optional column of a clicked file location
+typedef LocationTapCallback = void Function(String filePath, int? line, int? column);
+
 class AnsiParser {
   static final _urlRegex = RegExp(
     r'https?://[a-zA-Z0-9][-a-zA-Z0-9.]*(?::[0-9]+)?(?:/[^\s<>"{}|\\^`\[\]]*)?',
     caseSensitive: false,
   );
 
-  static List<TextSpan> parse(String text, {required Color defaultColor}) {
+  // Matches file:line[:column] such as src/index.ts:12:5 or C:\app\main.js:3.
+  // Requires an extension starting with a letter and refuses to start right
+  // after a word, path or scheme character, so hosts like 127.0.0.1:3000 or
+  // http://example.com:8080 aren't picked up.
+  static final _locationRegex = RegExp(
+    r'(?<![\w./\\:-])((?:[A-Za-z]:)?[\w./\\@-]*[\w@-]+\.[A-Za-z][A-Za-z0-9]*):(\d+)(?::(\d+))?',
+  );
+
+  static final _ansiEscapeRegex = RegExp(r'\x1B\[[0-9;?]*[A-Za-z]');
+
+  /// Remove all ANSI escape sequences from text
+  static String stripAnsi(String text) {
+    return text.replaceAll(_ansiEscapeRegex, '');
+  }
+
+  /// Parse ANSI colored text into spans. URLs are always clickable;
+  /// file:line locations are clickable when [onLocationTap] is given.
+  static List<TextSpan> parse(
+    String text, {
+    required Color defaultColor,
+    LocationTapCallback? onLocationTap,
+  }) {
     final spans = <TextSpan>[];
     final ansiRegex = RegExp(r'\x1B\[([0-9;]*)m');
 
@@ -25,8 +49,8 @@ class AnsiParser {
       if (match.start > currentIndex) {
         final textSegment = text.substring(currentIndex, match.start);
         if (textSegment.isNotEmpty) {
-          // Parse URLs in this segment
-          spans.addAll(_parseTextWithUrls(
+          // Parse links in this segment
+          spans.addAll(_parseTextWithLinks(
             textSegment,
             currentColor: currentColor,
             currentBgColor: currentBgColor,
@@ -35,6 +59,7 @@ class AnsiParser {
             italic: italic,
             underline: underline,
             defaultColor: defaultColor,
+            onLocationTap: onLocationTap,
           ));
         }
       }
@@ -127,7 +152,7 @@ class AnsiParser {
     if (currentIndex < text.length) {
       final textSegment = text.substring(currentIndex);
       if (textSegment.isNotEmpty) {
-        spans.addAll(_parseTextWithUrls(
+        spans.addAll(_parseTextWithLinks(
           textSegment,
           currentColor: currentColor,
           currentBgColor: currentBgColor,
@@ -136,6 +161,7 @@ class AnsiParser {
           italic: italic,
           underline: underline,
           defaultColor: defaultColor,
+          onLocationTap: onLocationTap,
         ));
       }
     }
@@ -143,7 +169,7 @@ class AnsiParser {
     return spans;
   }
 
-  static List<TextSpan> _parseTextWithUrls(
+  static List<TextSpan> _parseTextWithLinks(
     String text, {
     required Color? currentColor,
     required Color? currentBgColor,
@@ -152,12 +178,13 @@ class AnsiParser {
     required bool italic,
     required bool underline,
     required Color defaultColor,
+    required LocationTapCallback? onLocationTap,
   }) {
     final spans = <TextSpan>[];
-    final urlMatches = _urlRegex.allMatches(text).toList();
+    final links = _findLinks(text, onLocationTap);
 
-    if (urlMatches.isEmpty) {
-      // No URLs, return as single span
+    if (links.isEmpty) {
+      // No links, return as single span
       spans.add(TextSpan(
         text: text,
         style: TextStyle(
@@ -172,11 +199,11 @@ class AnsiParser {
     }
 
     int currentIndex = 0;
-    for (final urlMatch in urlMatches) {
-      // Add text before URL
-      if (urlMatch.start > currentIndex) {
+    for (final link in links) {
+      // Add text before link
+      if (link.start > currentIndex) {
         spans.add(TextSpan(
-          text: text.substring(currentIndex, urlMatch.start),
+          text: text.substring(currentIndex, link.start),
           style: TextStyle(
             color: currentColor,
             backgroundColor: currentBgColor,
@@ -187,10 +214,9 @@ class AnsiParser {
         ));
       }
 
-      // Add URL as clickable link
-      final url = urlMatch.group(0)!;
+      // Add link as clickable span
       spans.add(TextSpan(
-        text: url,
+        text: text.substring(link.start, link.end),
         style: TextStyle(
           color: const Color(0xFF3B8EEA), // Blue color for links
           backgroundColor: currentBgColor,
@@ -198,19 +224,13 @@ class AnsiParser {
           fontStyle: italic ? FontStyle.italic : FontStyle.normal,
           decoration: TextDecoration.underline,
         ),
-        recognizer: TapGestureRecognizer()
-          ..onTap = () async {
-            final uri = Uri.parse(url);
-            if (await canLaunchUrl(uri)) {
-              await launchUrl(uri, mode: LaunchMode.externalApplication);
-            }
-          },
+        recognizer: TapGestureRecognizer()..onTap = link.onTap,
       ));
 
-      currentIndex = urlMatch.end;
+      currentIndex = link.end;
     }
 
-    // Add remaining text after last URL
+    // Add remaining text after last link
     if (currentIndex < text.length) {
       spans.add(TextSpan(
         text: text.substring(currentIndex),
@@ -226,4 +246,45 @@ class AnsiParser {
 
     return spans;
   }
+
+  /// Find URLs and, when [onLocationTap] is set, file:line locations in text.
+  /// URLs win over locations that overlap them.
+  static List<_Link> _findLinks(String text, LocationTapCallback? onLocationTap) {
+    final links = <_Link>[];
+
+    for (final match in _urlRegex.allMatches(text)) {
+      final url = match.group(0)!;
+      links.add(_Link(match.start, match.end, () async {
+        final uri = Uri.parse(url);
+        if (await canLaunchUrl(uri)) {
+          await launchUrl(uri, mode: LaunchMode.externalApplication);
+        }
+      }));
+    }
+
+    if (onLocationTap != null) {
+      for (final match in _locationRegex.allMatches(text)) {
+        final overlapsUrl = links.any((link) => match.start < link.end && link.start < match.end);
+        if (overlapsUrl) {
+          continue;
+        }
+
+        final filePath = match.group(1)!;
+        final line = int.tryParse(match.group(2)!);
+        final column = match.group(3) != null ? int.tryParse(match.group(3)!) : null;
+        links.add(_Link(match.start, match.end, () => onLocationTap(filePath, line, column)));
+      }
+    }
+
+    links.sort((a, b) => a.start.compareTo(b.start));
+    return links;
+  }
+}
+
+class _Link {
+  final int start;
+  final int end;
+  final VoidCallback onTap;
+
+  _Link(this.start, this.end, this.onTap);
 }
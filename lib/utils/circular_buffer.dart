@@ -1,24 +1,28 @@
 import '../config/constants.dart';
 
 /// A circular buffer for storing a limited number of text lines.
-/// When the buffer is full, the oldest lines are discarded.
+/// When the buffer is full, the oldest lines are discarded. Remembers
+/// which lines came from stderr.
 class CircularBuffer {
   final int maxLines;
   final List<String> _lines = [];
+  final List<bool> _fromStderr = [];
   int _totalLinesWritten = 0;
 
   CircularBuffer({this.maxLines = AppConstants.maxOutputLines});
 
   /// Appends text to the buffer, splitting by newlines.
-  void append(String text) {
+  void append(String text, {bool stderr = false}) {
     if (text.isEmpty) return;
 
     final lines = text.split('\n');
     for (final line in lines) {
       if (_lines.length >= maxLines) {
         _lines.removeAt(0);
+        _fromStderr.removeAt(0);
       }
       _lines.add(line);
+      _fromStderr.add(stderr);
       _totalLinesWritten++;
     }
   }
@@ -29,6 +33,12 @@ class CircularBuffer {
     return _lines.join('\n');
   }
 
+  /// Returns the indices of the lines that came from stderr.
+  Set<int> get stderrLines => {
+        for (var i = 0; i < _fromStderr.length; i++)
+          if (_fromStderr[i]) i,
+      };
+
   /// Returns true if the buffer has reached capacity and is dropping lines.
   bool get isTruncated => _totalLinesWritten > maxLines;
 
@@ -38,9 +48,13 @@ class CircularBuffer {
   /// Returns the total number of lines that have been written (including dropped ones).
   int get totalLinesWritten => _totalLinesWritten;
 
+  /// Returns the number of lines dropped to stay within [maxLines].
+  int get droppedLines => _totalLinesWritten - _lines.length;
+
   /// Clears the buffer.
   void clear() {
     _lines.clear();
+    _fromStderr.clear();
     _totalLinesWritten = 0;
   }
 }
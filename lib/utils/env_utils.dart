@@ -0,0 +1,82 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+class EnvUtils {
+  /// Env files read from the project folder before any configured ones
+  static const defaultEnvFiles = ['.env', '.env.local'];
+
+  /// Parse KEY=value lines, skipping blank lines and # comments
+  static Map<String, String> parse(String text) {
+    final variables = <String, String>{};
+    for (final line in text.split('\n')) {
+      final trimmed = line.trim();
+      if (trimmed.isEmpty || trimmed.startsWith('#')) {
+        continue;
+      }
+      final separator = trimmed.indexOf('=');
+      if (separator <= 0) {
+        continue;
+      }
+      variables[trimmed.substring(0, separator).trim()] = trimmed.substring(separator + 1);
+    }
+    return variables;
+  }
+
+  /// Parse a .env file the way dotenv does: optional `export`, single or
+  /// double quotes (the latter with \n escapes) and trailing # comments
+  static Map<String, String> parseDotenv(String text) {
+    final variables = <String, String>{};
+    final line = RegExp(r'^\s*(?:export\s+)?([\w.-]+)\s*=\s*(.*?)\s*$');
+    for (final match in text.split(RegExp(r'\r?\n')).map(line.firstMatch).nonNulls) {
+      var value = match.group(2)!;
+      if (value.length >= 2 && value.startsWith('"') && value.endsWith('"')) {
+        value = value.substring(1, value.length - 1).replaceAll(r'\n', '\n').replaceAll(r'\"', '"');
+      } else if (value.length >= 2 && value.startsWith("'") && value.endsWith("'")) {
+        value = value.substring(1, value.length - 1);
+      } else {
+        final comment = value.indexOf(' #');
+        if (comment != -1) {
+          value = value.substring(0, comment).trimRight();
+        }
+      }
+      variables[match.group(1)!] = value;
+    }
+    return variables;
+  }
+
+  /// Read the env files of a project that exist, later files overriding earlier ones
+  static Future<Map<String, String>> readEnvFiles(String projectPath, List<String> fileNames) async {
+    final variables = <String, String>{};
+    for (final fileName in fileNames) {
+      final file = File(path.join(projectPath, fileName));
+      if (await file.exists()) {
+        variables.addAll(parseDotenv(await file.readAsString()));
+      }
+    }
+    return variables;
+  }
+
+  /// Format variables as KEY=value lines, the inverse of [parse]
+  static String format(Map<String, String> variables) {
+    return variables.entries.map((e) => '${e.key}=${e.value}').join('\n');
+  }
+
+  /// Variables a project inherits from the workspaces containing it.
+  ///
+  /// Workspaces are keyed by directory. When they are nested, the one
+  /// closest to the project wins.
+  static Map<String, String> inheritedFor(
+    String projectPath,
+    Map<String, Map<String, String>> workspaces,
+  ) {
+    final roots = workspaces.keys
+        .where((root) => path.equals(root, projectPath) || path.isWithin(root, projectPath))
+        .toList()
+      ..sort((a, b) => a.length.compareTo(b.length));
+
+    return {
+      for (final root in roots) ...workspaces[root]!,
+    };
+  }
+}
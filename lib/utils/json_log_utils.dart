@@ -0,0 +1,139 @@
+import 'dart:convert';
+
+import 'log_level_utils.dart';
+
+/// A line of structured output, one JSON object as pino, bunyan and many
+/// other loggers write them
+class JsonLogEntry {
+  final LogLevel level;
+
+  /// Level as the logger named it, e.g. info or 30
+  final String? levelName;
+
+  final String? time;
+  final String? message;
+
+  /// Everything but the level, time and message
+  final Map<String, Object?> fields;
+
+  const JsonLogEntry({
+    required this.level,
+    this.levelName,
+    this.time,
+    this.message,
+    this.fields = const {},
+  });
+}
+
+class JsonLogUtils {
+  static const _levelKeys = ['level', 'severity', 'lvl', 'log.level'];
+  static const _timeKeys = ['time', 'timestamp', 'ts', '@timestamp'];
+  static const _messageKeys = ['msg', 'message', '@message'];
+
+  // Levels as pino and bunyan number them
+  static const _levelNumbers = {10: 'trace', 20: 'debug', 30: 'info', 40: 'warn', 50: 'error', 60: 'fatal'};
+
+  // A search for lines whose field has a value, e.g. level=error or reqId=42
+  static final _fieldFilter = RegExp(r'^([\w.@-]+)=(.*)$');
+
+  /// Cheap check on raw output before parsing lines
+  static bool mayContainJson(String output) => output.contains('{"');
+
+  /// Parse a line stripped of ANSI codes, null when it isn't a JSON object
+  static JsonLogEntry? parse(String line) {
+    final trimmed = line.trim();
+    if (!trimmed.startsWith('{') || !trimmed.endsWith('}')) {
+      return null;
+    }
+
+    final Map<String, Object?> json;
+    try {
+      final decoded = jsonDecode(trimmed);
+      if (decoded is! Map<String, Object?>) {
+        return null;
+      }
+      json = decoded;
+    } on FormatException {
+      return null;
+    }
+
+    Object? take(List<String> keys) {
+      for (final key in keys) {
+        if (json.containsKey(key)) {
+          return json.remove(key);
+        }
+      }
+      return null;
+    }
+
+    final level = take(_levelKeys);
+    final levelName = level is int ? _levelNumbers[level] ?? '$level' : level?.toString();
+    return JsonLogEntry(
+      level: _classifyLevel(level, levelName),
+      levelName: levelName,
+      time: _formatTime(take(_timeKeys)),
+      message: take(_messageKeys)?.toString(),
+      fields: json,
+    );
+  }
+
+  static LogLevel _classifyLevel(Object? level, String? levelName) {
+    if (level is int && level >= 50) {
+      return LogLevel.error;
+    }
+    if (level is int && level >= 40) {
+      return LogLevel.warning;
+    }
+    return switch (levelName?.toLowerCase()) {
+      'error' || 'fatal' || 'critical' || 'crit' || 'alert' || 'emergency' || 'panic' => LogLevel.error,
+      'warn' || 'warning' => LogLevel.warning,
+      _ => LogLevel.info,
+    };
+  }
+
+  /// Time of day of a timestamp, in milliseconds since the epoch as pino
+  /// writes them or as an ISO 8601 string
+  static String? _formatTime(Object? time) {
+    final parsed = switch (time) {
+      int milliseconds => DateTime.fromMillisecondsSinceEpoch(milliseconds),
+      String text => DateTime.tryParse(text)?.toLocal(),
+      _ => null,
+    };
+    if (parsed == null) {
+      return time?.toString();
+    }
+    String two(int n) => n.toString().padLeft(2, '0');
+    return '${two(parsed.hour)}:${two(parsed.minute)}:${two(parsed.second)}';
+  }
+
+  /// A field value as shown, strings without their quotes
+  static String formatValue(Object? value) => value is String ? value : jsonEncode(value);
+
+  /// The field and value a search asks for, null for plain text searches
+  static (String, String)? fieldFilter(String query) {
+    final match = _fieldFilter.firstMatch(query.trim());
+    return match == null ? null : (match.group(1)!, match.group(2)!);
+  }
+
+  /// Whether a line is a JSON entry whose field has the value, ignoring case.
+  /// The level matches by name too, so level=error finds pino's 50.
+  static bool matchesField(String line, String field, String value) {
+    final entry = parse(line);
+    if (entry == null) {
+      return false;
+    }
+
+    final Object? actual;
+    if (_levelKeys.contains(field)) {
+      if (entry.level.name == value || (value == 'warn' && entry.level == LogLevel.warning)) {
+        return true;
+      }
+      actual = entry.levelName;
+    } else if (_messageKeys.contains(field)) {
+      actual = entry.message;
+    } else {
+      actual = entry.fields[field];
+    }
+    return actual != null && formatValue(actual).toLowerCase() == value.toLowerCase();
+  }
+}
@@ -0,0 +1,42 @@
+import 'json_log_utils.dart';
+
+/// How serious a line of output looks
+enum LogLevel {
+  info,
+  warning,
+  error,
+}
+
+class LogLevelUtils {
+  // Lines starting with an error marker, possibly after a [tag], or naming
+  // an error type, and failed exits as bob reports them
+  static final _error = RegExp(
+    r'^\s*(?:\[[\w:./-]+\]\s*)?(?:error|err!|fatal|failed)\b|^\s*[✖✗×❌]|'
+    r'\b\w*(?:error|exception)\b:|exited with code [1-9]|killed by sig',
+    caseSensitive: false,
+  );
+  static final _errorUppercase = RegExp(r'\b(?:ERROR|FATAL|FAIL)\b');
+
+  static final _warning = RegExp(
+    r'^\s*(?:\[[\w:./-]+\]\s*)?warn(?:ing)?\b|^\s*⚠|\bwarn(?:ing)?\b:|\bdeprecated\b',
+    caseSensitive: false,
+  );
+  static final _warningUppercase = RegExp(r'\bWARN(?:ING)?\b');
+
+  /// Classify a line stripped of ANSI codes by the usual markers of tools,
+  /// or by its level when it's a JSON entry
+  static LogLevel classify(String line) {
+    if (line.startsWith('{')) {
+      if (JsonLogUtils.parse(line) case final entry?) {
+        return entry.level;
+      }
+    }
+    if (_error.hasMatch(line) || _errorUppercase.hasMatch(line)) {
+      return LogLevel.error;
+    }
+    if (_warning.hasMatch(line) || _warningUppercase.hasMatch(line)) {
+      return LogLevel.warning;
+    }
+    return LogLevel.info;
+  }
+}
@@ -0,0 +1,90 @@
+/// Lines of output that can be folded under their first line
+class LogSection {
+  /// Index of the line the section folds under
+  final int header;
+
+  /// Index of the last line of the section
+  final int end;
+
+  /// Folded until the user unfolds it
+  final bool collapsed;
+
+  const LogSection({required this.header, required this.end, this.collapsed = false});
+
+  int get hiddenLines => end - header;
+}
+
+class LogSectionUtils {
+  // Dependencies installed before a run, the devcontainer started for it and
+  // pre-run and post-run commands, as bob announces them
+  static final _stepStart = RegExp(
+    r'^\[bob\] (?:Installing dependencies|Waiting for install to finish|Starting devcontainer|(?:Pre|Post)-run command:)',
+  );
+  static final _stepFailed = RegExp(r'^\[bob\] (?:Install|Pre-run command|Post-run command) failed');
+
+  // A new cycle of a task: restarted by bob, or rebuilt by a watcher
+  static final _cycleStart = RegExp(
+    r'^\[bob\] (?:.* changed, restarting|Run started at)|'
+    r'^.*(?:File change detected\. Starting incremental compilation|\[watch\] build started)',
+  );
+
+  // Frames of JavaScript, Python and Java stack traces
+  static final _stackFrame = RegExp(r'^\s+(?:at |File ".*", line \d+)');
+
+  // Shorter traces aren't worth folding
+  static const _minStackFrames = 3;
+
+  /// Cheap check on raw output before looking for sections line by line
+  static bool mayContainSections(String output) =>
+      output.contains('[bob] ') ||
+      output.contains('    at ') ||
+      output.contains('\tat ') ||
+      output.contains('File "') ||
+      output.contains('File change detected') ||
+      output.contains('[watch]');
+
+  /// Find the sections of output lines stripped of ANSI codes, sorted by
+  /// header. Sections nest but never overlap otherwise.
+  static List<LogSection> find(List<String> lines) {
+    final sections = <LogSection>[];
+
+    int nextIndex(int from, bool Function(String line) test) {
+      for (var i = from; i < lines.length; i++) {
+        if (test(lines[i])) {
+          return i;
+        }
+      }
+      return lines.length;
+    }
+
+    for (var i = 0; i < lines.length; i++) {
+      final line = lines[i];
+
+      if (_stepStart.hasMatch(line)) {
+        // Up to what bob says next, folded when that isn't a failure
+        final next = nextIndex(i + 1, (line) => line.startsWith('[bob] '));
+        if (next - 1 > i) {
+          sections.add(LogSection(
+            header: i,
+            end: next - 1,
+            collapsed: next < lines.length && !_stepFailed.hasMatch(lines[next]),
+          ));
+        }
+      } else if (_cycleStart.hasMatch(line)) {
+        final next = nextIndex(i + 1, _cycleStart.hasMatch);
+        if (next - 1 > i) {
+          sections.add(LogSection(header: i, end: next - 1));
+        }
+      } else if (i > 0 && _stackFrame.hasMatch(line) && !_stackFrame.hasMatch(lines[i - 1])) {
+        // Folded under the line saying what went wrong
+        final next = nextIndex(i, (line) => !_stackFrame.hasMatch(line));
+        if (next - i >= _minStackFrames) {
+          sections.add(LogSection(header: i - 1, end: next - 1));
+        }
+      }
+    }
+
+    sections.sort((a, b) => a.header.compareTo(b.header));
+    return sections;
+  }
+}
@@ -0,0 +1,59 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+import '../models/project.dart';
+
+class MakefileUtils {
+  /// File names make looks for, in the order it tries them
+  static const fileNames = ['GNUmakefile', 'makefile', 'Makefile'];
+
+  // A rule line: targets at the start of the line, then a colon that isn't
+  // part of an assignment (:= or ::=)
+  static final _rule = RegExp(r'^([^\s:#=][^:#=]*?)\s*::?(?![=:])');
+
+  /// Top-level targets of a Makefile in the order they appear, skipping
+  /// special targets like .PHONY, pattern rules and variable targets
+  static List<String> parseTargets(String content) {
+    final targets = <String>{};
+    for (final line in content.split(RegExp(r'\r?\n'))) {
+      final match = _rule.firstMatch(line);
+      if (match == null) {
+        continue;
+      }
+      for (final target in match.group(1)!.split(RegExp(r'\s+'))) {
+        if (target.isEmpty ||
+            target.startsWith('.') ||
+            target.contains('%') ||
+            target.contains(r'$')) {
+          continue;
+        }
+        targets.add(target);
+      }
+    }
+    return targets.toList();
+  }
+
+  /// Tasks running the targets of the project's Makefile, if it has one.
+  /// They're named "make <target>" so they can't clash with scripts.
+  static Future<List<Task>> readTasks(String projectPath) async {
+    for (final fileName in fileNames) {
+      final file = File(path.join(projectPath, fileName));
+      if (!await file.exists()) {
+        continue;
+      }
+      try {
+        return parseTargets(await file.readAsString())
+            .map((target) => Task(
+                  name: 'make $target',
+                  command: 'make $target',
+                  type: TaskType.make,
+                ))
+            .toList();
+      } catch (e) {
+        return [];
+      }
+    }
+    return [];
+  }
+}
@@ -0,0 +1,110 @@
+import '../models/problem.dart';
+import 'ansi_parser.dart';
+import 'log_level_utils.dart';
+
+/// Extracts compiler errors and warnings from task output, like the problem
+/// matchers of editors do
+class ProblemMatcher {
+  // tsc: src/a.ts(12,5): error TS2322: ...
+  static final _tsc = RegExp(r'^(\S.*?)\((\d+),(\d+)\): (error|warning) (TS\d+: .*)$');
+
+  // tsc --pretty: src/a.ts:12:5 - error TS2322: ...
+  static final _tscPretty = RegExp(r'^(\S.*?):(\d+):(\d+) - (error|warning) (TS\d+: .*)$');
+
+  // esbuild: ✘ [ERROR] message, with the location a couple of lines below
+  static final _esbuildHeader = RegExp(r'^\s*[✘▲X]\s+\[(ERROR|WARNING)\]\s+(.*)$');
+  static final _esbuildLocation = RegExp(r'^\s+(\S.*?):(\d+):(\d+):\s*$');
+
+  // rustc: error[E0308]: message, with the location on the next line
+  static final _rustcHeader = RegExp(r'^(error|warning)(?:\[\w+\])?: (.*)$');
+  static final _rustcLocation = RegExp(r'^\s*--> (.+?):(\d+):(\d+)$');
+
+  // vite: [vite] Internal server error: /src/App.tsx: Unexpected token (12:5)
+  static final _vite = RegExp(r'^\[vite\] .*?[Ee]rror: (\S+?): (.*) \((\d+):(\d+)\)$');
+
+  // vite and rollup: the failing file on a line of its own below the error
+  static final _viteFile = RegExp(r'^\s*file: (\S.*?):(\d+):(\d+)$');
+
+  // How many lines a header waits for its location
+  static const _maxLocationDistance = 3;
+
+  /// The problems in [output], in order and without duplicates
+  static List<Problem> parse(String output) {
+    final problems = <Problem>{};
+
+    // Message and severity of a multi-line report waiting for its location
+    (String, bool)? pending;
+    var pendingAge = 0;
+    String? lastError;
+
+    for (final rawLine in output.split('\n')) {
+      final line = AnsiParser.stripAnsi(rawLine).trimRight();
+
+      if (_tsc.firstMatch(line) ?? _tscPretty.firstMatch(line) case final match?) {
+        problems.add(Problem(
+          file: match.group(1)!,
+          line: int.parse(match.group(2)!),
+          column: int.parse(match.group(3)!),
+          message: match.group(5)!,
+          isWarning: match.group(4) == 'warning',
+        ));
+        continue;
+      }
+
+      if (_vite.firstMatch(line) case final match?) {
+        problems.add(Problem(
+          file: match.group(1)!,
+          line: int.parse(match.group(3)!),
+          column: int.parse(match.group(4)!),
+          message: match.group(2)!,
+        ));
+        continue;
+      }
+
+      if (_esbuildHeader.firstMatch(line) case final match?) {
+        pending = (match.group(2)!, match.group(1) == 'WARNING');
+        pendingAge = 0;
+        continue;
+      }
+
+      if (_rustcHeader.firstMatch(line) case final match?) {
+        pending = (match.group(2)!, match.group(1) == 'warning');
+        pendingAge = 0;
+        continue;
+      }
+
+      if (pending case (final message, final isWarning)) {
+        if (_esbuildLocation.firstMatch(line) ?? _rustcLocation.firstMatch(line) case final match?) {
+          problems.add(Problem(
+            file: match.group(1)!,
+            line: int.parse(match.group(2)!),
+            column: int.parse(match.group(3)!),
+            message: message,
+            isWarning: isWarning,
+          ));
+          pending = null;
+          continue;
+        }
+        if (++pendingAge > _maxLocationDistance) {
+          pending = null;
+        }
+      }
+
+      if (_viteFile.firstMatch(line) case final match?) {
+        problems.add(Problem(
+          file: match.group(1)!,
+          line: int.parse(match.group(2)!),
+          column: int.parse(match.group(3)!),
+          message: lastError ?? 'Build failed',
+        ));
+        continue;
+      }
+
+      if (line.isNotEmpty && LogLevelUtils.classify(line) == LogLevel.error) {
+        lastError = line.trim();
+      }
+    }
+
+    return problems.toList();
+  }
+}
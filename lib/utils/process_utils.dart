@@ -26,6 +26,229 @@ class ProcessUtils {
     await Process.start(executable, args, runInShell: false);
   }
 
+  /// Split a command line into its executable and arguments. Double or
+  /// single quotes keep spaces within a word, as in
+  /// `"C:\Program Files\Editor\editor.exe" --wait`. Backslashes are kept
+  /// as they are, being path separators on Windows.
+  static List<String> splitCommand(String command) {
+    final words = <String>[];
+    final word = StringBuffer();
+    var inWord = false;
+    String? quote;
+    for (final char in command.split('')) {
+      if (quote != null) {
+        if (char == quote) {
+          quote = null;
+        } else {
+          word.write(char);
+        }
+      } else if (char == '"' || char == "'") {
+        quote = char;
+        inWord = true;
+      } else if (char.trim().isEmpty) {
+        if (inWord) {
+          words.add(word.toString());
+          word.clear();
+          inWord = false;
+        }
+      } else {
+        word.write(char);
+        inWord = true;
+      }
+    }
+    if (inWord) {
+      words.add(word.toString());
+    }
+    return words;
+  }
+
+  /// Name of the system file manager, for menu labels
+  static String get fileManagerName {
+    if (Platform.isWindows) return 'Explorer';
+    if (Platform.isMacOS) return 'Finder';
+    return 'file manager';
+  }
+
+  /// Open a terminal with its working directory set to [directoryPath].
+  ///
+  /// When [command] is set it is used instead of the platform default;
+  /// `{path}` in it is replaced with the directory, and the terminal is
+  /// started from that directory either way.
+  /// - Windows: Windows Terminal, falling back to cmd
+  /// - macOS: Terminal.app
+  /// - Linux: the first of x-terminal-emulator, gnome-terminal, konsole, xterm
+  static Future<void> openTerminal(String directoryPath, {String? command}) async {
+    if (command != null && command.trim().isNotEmpty) {
+      final parts = splitCommand(command);
+      await Process.start(
+        parts.first,
+        parts.skip(1).map((arg) => arg.replaceAll('{path}', directoryPath)).toList(),
+        workingDirectory: directoryPath,
+        mode: ProcessStartMode.detached,
+        runInShell: Platform.isWindows,
+      );
+      return;
+    }
+
+    if (Platform.isWindows) {
+      try {
+        await Process.start('wt', ['-d', directoryPath], mode: ProcessStartMode.detached);
+      } catch (e) {
+        // Windows Terminal not installed
+        await Process.start(
+          'cmd',
+          ['/c', 'start', 'cmd', '/K', 'cd', '/d', directoryPath],
+          workingDirectory: directoryPath,
+          mode: ProcessStartMode.detached,
+        );
+      }
+      return;
+    }
+
+    if (Platform.isMacOS) {
+      await Process.start('open', ['-a', 'Terminal', directoryPath]);
+      return;
+    }
+
+    // Linux: try common terminal emulators in order
+    const candidates = [
+      ('x-terminal-emulator', <String>[]),
+      ('gnome-terminal', <String>['--working-directory']),
+      ('konsole', <String>['--workdir']),
+      ('xterm', <String>[]),
+    ];
+    for (final (executable, directoryArgs) in candidates) {
+      try {
+        await Process.start(
+          executable,
+          directoryArgs.isEmpty ? [] : [...directoryArgs, directoryPath],
+          workingDirectory: directoryPath,
+          mode: ProcessStartMode.detached,
+        );
+        return;
+      } on ProcessException {
+        // Not installed, try the next one
+      }
+    }
+    throw const ProcessException('x-terminal-emulator', [], 'No terminal emulator found');
+  }
+
+  /// Open a terminal window following [filePath] as it grows, from its
+  /// first line, like tail -F
+  static Future<void> followInTerminal(String filePath) async {
+    if (Platform.isWindows) {
+      final follow = [
+        'powershell',
+        '-NoExit',
+        '-Command',
+        "Get-Content -Wait -LiteralPath '${filePath.replaceAll("'", "''")}'",
+      ];
+      try {
+        await Process.start('wt', follow, mode: ProcessStartMode.detached);
+      } catch (e) {
+        // Windows Terminal not installed
+        await Process.start('cmd', ['/c', 'start', ...follow], mode: ProcessStartMode.detached);
+      }
+      return;
+    }
+
+    final tail = "tail -n +1 -F '${filePath.replaceAll("'", r"'\''")}'";
+    if (Platform.isMacOS) {
+      final script = tail.replaceAll(r'\', r'\\').replaceAll('"', r'\"');
+      await Process.start('osascript', [
+        '-e',
+        'tell application "Terminal" to do script "$script"',
+        '-e',
+        'tell application "Terminal" to activate',
+      ]);
+      return;
+    }
+
+    // Linux: the same terminal emulators as openTerminal, with their flag
+    // for the command to run
+    const candidates = [
+      ('x-terminal-emulator', '-e'),
+      ('gnome-terminal', '--'),
+      ('konsole', '-e'),
+      ('xterm', '-e'),
+    ];
+    for (final (executable, commandFlag) in candidates) {
+      try {
+        await Process.start(
+          executable,
+          [commandFlag, 'sh', '-c', tail],
+          mode: ProcessStartMode.detached,
+        );
+        return;
+      } on ProcessException {
+        // Not installed, try the next one
+      }
+    }
+    throw const ProcessException('x-terminal-emulator', [], 'No terminal emulator found');
+  }
+
+  /// Check whether a process is still alive.
+  /// Returns true when liveness can't be determined, so callers never
+  /// drop a process they can't prove is gone.
+  static Future<bool> isProcessAlive(int pid) async {
+    try {
+      if (Platform.isWindows) {
+        final result = await Process.run(
+          'tasklist',
+          ['/FI', 'PID eq $pid', '/NH', '/FO', 'CSV'],
+        );
+        if (result.exitCode != 0) {
+          return true;
+        }
+        return (result.stdout as String).contains('"$pid"');
+      }
+
+      // Signal 0 checks existence without affecting the process
+      final result = await Process.run('kill', ['-0', pid.toString()]);
+      return result.exitCode == 0;
+    } catch (e) {
+      return true;
+    }
+  }
+
+  /// Kill a process with everything it started, so that commands run
+  /// through a shell don't leave their children behind. Windows kills the
+  /// tree with taskkill; elsewhere its descendants are looked up with ps.
+  static Future<void> killProcessTree(int pid) async {
+    try {
+      if (Platform.isWindows) {
+        await Process.run('taskkill', ['/F', '/T', '/PID', pid.toString()]);
+        return;
+      }
+
+      final children = <int, List<int>>{};
+      final result = await Process.run('ps', ['-A', '-o', 'pid=', '-o', 'ppid=']);
+      for (final line in (result.stdout as String).split('\n')) {
+        final fields = line.trim().split(RegExp(r'\s+'));
+        final child = int.tryParse(fields.first);
+        final parent = fields.length > 1 ? int.tryParse(fields[1]) : null;
+        if (child != null && parent != null) {
+          children.putIfAbsent(parent, () => []).add(child);
+        }
+      }
+
+      // Collected before killing any, as orphans move to another parent
+      final tree = [pid];
+      for (var i = 0; i < tree.length; i++) {
+        tree.addAll(children[tree[i]] ?? const []);
+      }
+      for (final member in tree) {
+        Process.killPid(member, ProcessSignal.sigkill);
+      }
+    } catch (e) {
+      // Gone already, or ps is missing
+      Process.killPid(pid, ProcessSignal.sigkill);
+    }
+  }
+
+  /// Quote a value for a POSIX shell, e.g. the one of a remote host
+  static String shellQuote(String value) => "'${value.replaceAll("'", "'\\''")}'";
+
   /// Get the path separator for the current platform
   static String get pathSeparator => Platform.isWindows ? ';' : ':';
 
@@ -41,8 +264,14 @@ class ProcessUtils {
   ///   '/path/to/bun/bun'
   /// ]);
   /// ```
-  static Map<String, String> buildEnvironmentWithBinaries(List<String> binaryPaths) {
-    final environment = Map<String, String>.from(Platform.environment);
+  ///
+  /// [variables] are applied on top of the current environment before
+  /// PATH is extended, so they can't hide the managed binaries.
+  static Map<String, String> buildEnvironmentWithBinaries(
+    List<String> binaryPaths, {
+    Map<String, String> variables = const {},
+  }) {
+    final environment = Map<String, String>.from(Platform.environment)..addAll(variables);
     final currentPath = environment['PATH'] ?? '';
 
     // Extract directories from binary paths and join with path separator
@@ -0,0 +1,49 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+import '../models/project.dart';
+
+class ProcfileUtils {
+  static const fileName = 'Procfile';
+
+  /// Port foreman gives the first process type, the next ones get 100 more each
+  static const basePort = 5000;
+
+  static final _entry = RegExp(r'^([A-Za-z0-9_-]+):\s*(.+?)\s*$');
+
+  /// Process types of a Procfile and their commands, in file order
+  static Map<String, String> parse(String content) {
+    final entries = <String, String>{};
+    for (final line in content.split(RegExp(r'\r?\n'))) {
+      final match = _entry.firstMatch(line);
+      if (match != null) {
+        entries[match.group(1)!] = match.group(2)!;
+      }
+    }
+    return entries;
+  }
+
+  /// Tasks running the process types of the project's Procfile, if it has
+  /// one. A type named like an existing task gets a "(Procfile)" suffix.
+  static Future<List<Task>> readTasks(String projectPath, Iterable<String> existingNames) async {
+    final file = File(path.join(projectPath, fileName));
+    if (!await file.exists()) {
+      return [];
+    }
+
+    try {
+      final taken = existingNames.toSet();
+      return parse(await file.readAsString())
+          .entries
+          .map((entry) => Task(
+                name: taken.contains(entry.key) ? '${entry.key} ($fileName)' : entry.key,
+                command: entry.value,
+                type: TaskType.procfile,
+              ))
+          .toList();
+    } catch (e) {
+      return [];
+    }
+  }
+}
@@ -0,0 +1,65 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+import '../models/project.dart';
+
+class PyprojectUtils {
+  static const fileName = 'pyproject.toml';
+
+  static final _section = RegExp(r'^\s*\[([^\[\]]+)\]\s*(#.*)?$');
+  static final _key = RegExp(r'''^\s*(?:"([^"]+)"|'([^']+)'|([A-Za-z0-9_.-]+))\s*=''');
+
+  /// Keys of each table in a TOML file. Just enough TOML for script tables:
+  /// values, arrays of tables and multiline strings are not interpreted.
+  static Map<String, List<String>> parseTableKeys(String content) {
+    final tables = <String, List<String>>{};
+    String? current;
+    for (final line in content.split(RegExp(r'\r?\n'))) {
+      final section = _section.firstMatch(line);
+      if (section != null) {
+        current = section.group(1)!.trim();
+        tables.putIfAbsent(current, () => []);
+        continue;
+      }
+      final key = _key.firstMatch(line);
+      if (current != null && key != null) {
+        tables[current]!.add(key.group(1) ?? key.group(2) ?? key.group(3)!);
+      }
+    }
+    return tables;
+  }
+
+  /// Tasks for the scripts of the project's pyproject.toml, run with poetry
+  /// for poetry projects and uv otherwise, plus pytest when it's configured
+  static Future<List<Task>> readTasks(String projectPath) async {
+    final file = File(path.join(projectPath, fileName));
+    if (!await file.exists()) {
+      return [];
+    }
+
+    try {
+      final content = await file.readAsString();
+      final tables = parseTableKeys(content);
+      final usesPoetry = tables.keys.any((table) => table == 'tool.poetry' || table.startsWith('tool.poetry.')) ||
+          await File(path.join(projectPath, 'poetry.lock')).exists();
+      final runner = usesPoetry ? 'poetry run' : 'uv run';
+
+      final scripts = {
+        ...?tables['project.scripts'],
+        ...?tables['tool.poetry.scripts'],
+      };
+      final hasPytest = tables.keys.any((table) => table.startsWith('tool.pytest')) ||
+          RegExp(r'''["']pytest\b''').hasMatch(content);
+
+      return [
+        for (final script in scripts)
+          Task(name: '$runner $script', command: '$runner $script', type: TaskType.python),
+        if (hasPytest && !scripts.contains('pytest'))
+          Task(name: '$runner pytest', command: '$runner pytest', type: TaskType.python),
+      ];
+    } catch (e) {
+      return [];
+    }
+  }
+}
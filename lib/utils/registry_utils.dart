@@ -0,0 +1,46 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+class RegistryUtils {
+  /// Files bun reads registries and their credentials from, in the order
+  /// they're worth looking at
+  static const configFileNames = ['.npmrc', 'bunfig.toml'];
+
+  static final _authFailure = RegExp(
+    r'\b(?:E?401|E?403|Unauthorized|Forbidden)\b|authentication (?:required|failed)|'
+    r'(?:missing|invalid|expired) (?:auth(?:entication)? )?token',
+    caseSensitive: false,
+  );
+
+  /// Whether install output says a registry rejected the credentials
+  static bool isAuthFailure(String output) => _authFailure.hasMatch(output);
+
+  /// The registry configuration closest to the project: its own, one in a
+  /// parent folder such as a workspace root, or the user's ~/.npmrc
+  static Future<String?> findConfig(String projectPath) async {
+    var directory = projectPath;
+    while (true) {
+      for (final name in configFileNames) {
+        final file = File(path.join(directory, name));
+        if (await file.exists()) {
+          return file.path;
+        }
+      }
+      final parent = path.dirname(directory);
+      if (parent == directory) {
+        break;
+      }
+      directory = parent;
+    }
+
+    final home = Platform.environment[Platform.isWindows ? 'USERPROFILE' : 'HOME'];
+    if (home != null) {
+      final file = File(path.join(home, '.npmrc'));
+      if (await file.exists()) {
+        return file.path;
+      }
+    }
+    return null;
+  }
+}
@@ -0,0 +1,104 @@
+/// Hides secrets in output, so sharing a screen or a log doesn't leak them
+class SecretMasker {
+  static const String mask = '•••';
+
+  // Names of environment variables whose values are secrets
+  static final _secretName = RegExp(r'key|token|secret|passw(?:or)?d|credential|auth', caseSensitive: false);
+
+  // Shorter values would hide ordinary words and numbers
+  static const _minSecretLength = 6;
+
+  final List<RegExp> _patterns;
+  final List<String> _values;
+
+  const SecretMasker._(this._patterns, this._values);
+
+  static const none = SecretMasker._([], []);
+
+  /// Hide matches of [patterns], invalid ones being skipped, and [values]
+  factory SecretMasker({List<String> patterns = const [], Iterable<String> values = const []}) {
+    final regExps = <RegExp>[];
+    for (final pattern in patterns) {
+      try {
+        final regExp = RegExp(pattern);
+        // One matching nothing would hide between every character
+        if (!regExp.hasMatch('')) {
+          regExps.add(regExp);
+        }
+      } on FormatException {
+        // Left out until fixed in the settings
+      }
+    }
+
+    // Longest first, so a secret containing another is hidden whole
+    final secrets = values
+        .where((value) => value.length >= _minSecretLength && !value.contains('\n'))
+        .toSet()
+        .toList()
+      ..sort((a, b) => b.length.compareTo(a.length));
+    return SecretMasker._(regExps, secrets);
+  }
+
+  bool get isEmpty => _patterns.isEmpty && _values.isEmpty;
+
+  /// Values of [environment] whose names look like secrets, e.g. API_KEY
+  static Iterable<String> secretsOf(Map<String, String> environment) {
+    return [
+      for (final MapEntry(:key, :value) in environment.entries)
+        if (_secretName.hasMatch(key)) value,
+    ];
+  }
+
+  String apply(String text) {
+    if (isEmpty) {
+      return text;
+    }
+    var masked = text;
+    for (final value in _values) {
+      masked = masked.replaceAll(value, mask);
+    }
+    for (final pattern in _patterns) {
+      masked = masked.replaceAll(pattern, mask);
+    }
+    return masked;
+  }
+}
+
+/// Masks output arriving in chunks a line at a time, so a secret split
+/// across two chunks is still hidden. The end of an unfinished line is held
+/// back until it ends, or grows past [maxPendingLength].
+class LineSecretMasker {
+  static const int maxPendingLength = 4096;
+
+  // Read on every chunk, since the secrets change as projects run
+  final SecretMasker Function() _masker;
+  String _pending = '';
+
+  LineSecretMasker(this._masker);
+
+  /// The masked part of [chunk] that can go out now
+  String add(String chunk) {
+    final masker = _masker();
+    final text = _pending + chunk;
+    if (masker.isEmpty) {
+      _pending = '';
+      return text;
+    }
+
+    final end = text.lastIndexOf(RegExp('[\r\n]')) + 1;
+    if (end == 0 && text.length <= maxPendingLength) {
+      _pending = text;
+      return '';
+    }
+    final complete = end == 0 ? text : text.substring(0, end);
+    _pending = end == 0 ? '' : text.substring(end);
+    return masker.apply(complete);
+  }
+
+  /// The masked rest of an unfinished line, e.g. when the run ends
+  String flush() {
+    final rest = _pending;
+    _pending = '';
+    return rest.isEmpty ? rest : _masker().apply(rest);
+  }
+}
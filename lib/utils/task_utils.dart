@@ -1,13 +1,175 @@
+import 'dart:io';
+
 import 'package:path/path.dart' as path;
 import '../models/project.dart';
+import 'ansi_parser.dart';
 
 /// Utilities for task-related operations
 class TaskUtils {
+  // Shell words: double-quoted, single-quoted or bare
+  static final _wordRegex = RegExp(r'''"([^"]*)"|'([^']*)'|(\S+)''');
+
+  // Options that take a value, so the next word isn't a command
+  static const _concurrentlyOptionsWithValue = {
+    '-n', '--names', '-c', '--prefix-colors', '-p', '--prefix', '-m', '--max-processes',
+    '-s', '--success', '--restart-tries', '--restart-after', '--timestamp-format',
+    '--default-input-target', '-l', '--prefix-length',
+  };
+  static const _npmRunAllOptionsWithValue = {'--max-parallel', '--npm-path'};
+
+  // Runners that start a package.json script: "npm run x", "bun run x", "yarn x"...
+  static final _runScriptRegex = RegExp(r'^(?:npm|bun|pnpm|yarn)(?:\s+run)?\s+(\S+)$');
+
+  static const _signalNames = {
+    1: 'SIGHUP', 2: 'SIGINT', 3: 'SIGQUIT', 6: 'SIGABRT', 9: 'SIGKILL',
+    11: 'SIGSEGV', 13: 'SIGPIPE', 15: 'SIGTERM',
+  };
+
+  // Dev servers announce themselves with a local URL, or just a port
+  static final _localUrlRegex = RegExp(
+    r'https?://(?:localhost|127\.0\.0\.1|0\.0\.0\.0|\[::1?\])(?::\d+)?(?:/[^\s<>"]*)?',
+    caseSensitive: false,
+  );
+  static final _listeningPortRegex = RegExp(
+    r'\b(?:listening|running|started|serving|ready)\b.*?(?:\bport\s+|:)(\d{2,5})\b',
+    caseSensitive: false,
+  );
+
   /// Generates a unique key for a task based on its project path and task name
   static String getTaskKey(Project project, Task task) {
     // Normalize path to ensure consistent keys across platforms
     return '${path.normalize(project.path)}:${task.name}';
   }
 
+  /// Scripts a composite script runs in parallel through `concurrently`,
+  /// `run-p` or `npm-run-all --parallel`. Returns null when [command] isn't
+  /// such a script or runs anything other than scripts in [scripts].
+  /// Sequential runs are left alone since a group starts its children together.
+  static List<String>? getCompositeScriptParts(String command, Iterable<String> scripts) {
+    if (RegExp(r'&&|\|\||[;|]').hasMatch(command)) {
+      return null;
+    }
+
+    final words = _wordRegex
+        .allMatches(command)
+        .map((m) => m.group(1) ?? m.group(2) ?? m.group(3)!)
+        .toList();
+    final runnerIndex = words.indexWhere(
+      (w) => const {'concurrently', 'npm-run-all', 'run-p'}.contains(w),
+    );
+    if (runnerIndex == -1) {
+      return null;
+    }
+
+    final runner = words[runnerIndex];
+    final args = words.sublist(runnerIndex + 1);
+    final optionsWithValue =
+        runner == 'concurrently' ? _concurrentlyOptionsWithValue : _npmRunAllOptionsWithValue;
+    final patterns = <String>[];
+    var parallel = runner != 'npm-run-all';
+    for (var i = 0; i < args.length; i++) {
+      final arg = args[i];
+      if (arg.startsWith('-')) {
+        if (runner == 'npm-run-all') {
+          if (arg == '-p' || arg == '--parallel') {
+            parallel = true;
+            continue;
+          }
+          if (arg == '-s' || arg == '--sequential' || arg == '--serial') {
+            return null;
+          }
+        }
+        if (!arg.contains('=') && optionsWithValue.contains(arg)) {
+          i++;
+        }
+        continue;
+      }
+
+      if (runner != 'concurrently') {
+        patterns.add(arg);
+      } else if (arg.startsWith('npm:')) {
+        patterns.add(arg.substring('npm:'.length));
+      } else {
+        final match = _runScriptRegex.firstMatch(arg.trim());
+        if (match == null) {
+          return null;
+        }
+        patterns.add(match.group(1)!);
+      }
+    }
+    if (!parallel) {
+      return null;
+    }
+
+    final parts = <String>[];
+    for (final pattern in patterns) {
+      final matches = scripts.where((s) => _matchesScriptPattern(pattern, s)).toList();
+      if (matches.isEmpty) {
+        return null;
+      }
+      parts.addAll(matches.where((s) => !parts.contains(s)));
+    }
+    return parts.length >= 2 ? parts : null;
+  }
+
+  /// Describe how a process ended. On Unix, Dart reports death by signal N
+  /// as exit code -N, while shells exit with 128 + N.
+  static String describeExitCode(int exitCode) {
+    if (!Platform.isWindows) {
+      if (exitCode < 0) {
+        return 'Killed by ${_signalNames[-exitCode] ?? 'signal ${-exitCode}'}';
+      }
+      final signal = _signalNames[exitCode - 128];
+      if (signal != null) {
+        return 'Exited with code $exitCode ($signal)';
+      }
+    }
+    return 'Exited with code $exitCode';
+  }
+
+  /// Format a run duration as 850ms, 12s, 3m 05s or 1h 02m
+  static String formatDuration(Duration duration) {
+    String two(int n) => n.toString().padLeft(2, '0');
+    if (duration.inSeconds < 1) {
+      return '${duration.inMilliseconds}ms';
+    }
+    if (duration.inMinutes < 1) {
+      return '${duration.inSeconds}s';
+    }
+    if (duration.inHours < 1) {
+      return '${duration.inMinutes}m ${two(duration.inSeconds % 60)}s';
+    }
+    return '${duration.inHours}h ${two(duration.inMinutes % 60)}m';
+  }
+
+  /// The URL a dev server announces in a chunk of output, such as
+  /// "Local: http://localhost:5173/" or "Listening on port 3000"
+  static String? detectServerUrl(String output) {
+    final text = AnsiParser.stripAnsi(output);
+
+    final url = _localUrlRegex.firstMatch(text)?.group(0);
+    if (url != null) {
+      // 0.0.0.0 and :: bind every interface but can't be browsed to
+      return url
+          .replaceFirst(RegExp(r'0\.0\.0\.0|\[::\]'), 'localhost')
+          .replaceFirst(RegExp(r'[.,;)]+$'), '');
+    }
+
+    final port = _listeningPortRegex.firstMatch(text)?.group(1);
+    return port != null ? 'http://localhost:$port' : null;
+  }
+
+  /// npm-run-all globs: `*` stays within a `:` segment, `**` spans segments
+  static bool _matchesScriptPattern(String pattern, String script) {
+    if (!pattern.contains('*')) {
+      return pattern == script;
+    }
+
+    final regex = RegExp.escape(pattern)
+        .replaceAll(r'\*\*', '.*')
+        .replaceAll(r'\*', '[^:]*');
+    return RegExp('^$regex\$').hasMatch(script);
+  }
+
   TaskUtils._();
 }
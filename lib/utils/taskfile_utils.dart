@@ -0,0 +1,72 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+import '../models/project.dart';
+
+class TaskfileUtils {
+  /// File names go-task looks for, in the order it tries them
+  static const fileNames = ['Taskfile.yml', 'taskfile.yml', 'Taskfile.yaml', 'taskfile.yaml'];
+
+  static final _key = RegExp(r'''^(\s*)(?:"([^"]+)"|'([^']+)'|([^\s#'"][^#]*?)):(?:\s|$)''');
+
+  /// Names of the tasks under the top-level `tasks:` key, skipping those
+  /// marked `internal: true`. Just enough YAML for that: flow mappings and
+  /// anchors are not interpreted.
+  static List<String> parseTaskNames(String content) {
+    final names = <String>[];
+    var inTasks = false;
+    int? taskIndent;
+    String? current;
+    for (final line in content.split(RegExp(r'\r?\n'))) {
+      if (line.trim().isEmpty || line.trimLeft().startsWith('#')) {
+        continue;
+      }
+      final match = _key.firstMatch(line);
+      final indent = line.length - line.trimLeft().length;
+
+      if (indent == 0) {
+        inTasks = match != null && match.group(4) == 'tasks';
+        taskIndent = null;
+        current = null;
+        continue;
+      }
+      if (!inTasks || match == null) {
+        continue;
+      }
+
+      taskIndent ??= indent;
+      if (indent == taskIndent) {
+        current = match.group(2) ?? match.group(3) ?? match.group(4)!;
+        names.add(current);
+      } else if (current != null &&
+          match.group(4) == 'internal' &&
+          line.substring(match.end).trim() == 'true') {
+        names.remove(current);
+      }
+    }
+    return names;
+  }
+
+  /// Tasks running the tasks of the project's Taskfile, if it has one
+  static Future<List<Task>> readTasks(String projectPath) async {
+    for (final fileName in fileNames) {
+      final file = File(path.join(projectPath, fileName));
+      if (!await file.exists()) {
+        continue;
+      }
+      try {
+        return parseTaskNames(await file.readAsString())
+            .map((name) => Task(
+                  name: 'task $name',
+                  command: 'task $name',
+                  type: TaskType.taskfile,
+                ))
+            .toList();
+      } catch (e) {
+        return [];
+      }
+    }
+    return [];
+  }
+}
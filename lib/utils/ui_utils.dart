@@ -1,4 +1,5 @@
 import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
 
 /// Utility functions for common UI operations
 class UiUtils {
@@ -52,5 +53,13 @@ class UiUtils {
     );
   }
 
+  /// Copy a path to the clipboard and confirm with a snackbar
+  static Future<void> copyPath(BuildContext context, String path) async {
+    await Clipboard.setData(ClipboardData(text: path));
+    if (context.mounted) {
+      showInfoSnackbar(context, 'Copied $path');
+    }
+  }
+
   UiUtils._();
 }
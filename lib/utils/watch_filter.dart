@@ -0,0 +1,118 @@
+import 'dart:io';
+
+import 'package:path/path.dart' as path;
+
+import '../models/project.dart';
+
+/// Decides which files of a project count as source files when watching it.
+/// Paths are relative to the project folder and use / separators.
+class WatchFilter {
+  /// Never watched, whatever the project configuration says
+  static const alwaysExcluded = {'node_modules', '.git'};
+
+  // Swap, backup, lock and temp files editors write around a save: vim
+  // (.swp, ~ backups, its 4913 probe), emacs (.#lock, #autosave#), atomic
+  // saves through .tmp files, JetBrains and Chrome temp files
+  static final _editorNoise = RegExp(
+    r'(\.sw[a-p]|~|\.te?mp(\.\w+)?|___jb_(tmp|old)___|\.crswap)$|^(4913|\.#.*|#.*#|\.DS_Store)$',
+  );
+
+  final List<RegExp> _include;
+  final List<RegExp> _exclude;
+
+  // .gitignore rules in file order, the last one matching a path wins
+  final List<(RegExp, bool)> _gitignore;
+
+  WatchFilter._(this._include, this._exclude, this._gitignore);
+
+  /// Build the filter of a project from its watch globs, reading the
+  /// .gitignore at the root of the project unless that's turned off
+  factory WatchFilter.forProject(Project project) {
+    final gitignore = <(RegExp, bool)>[];
+    final gitignoreFile = File(path.join(project.path, '.gitignore'));
+    if (project.watchGitignore && gitignoreFile.existsSync()) {
+      try {
+        for (var line in gitignoreFile.readAsLinesSync()) {
+          line = line.trim();
+          if (line.isEmpty || line.startsWith('#')) {
+            continue;
+          }
+          final negated = line.startsWith('!');
+          gitignore.add((globToRegExp(negated ? line.substring(1) : line), negated));
+        }
+      } catch (e) {
+        // Unreadable .gitignore, watch everything else
+      }
+    }
+
+    return WatchFilter._(
+      project.watchInclude.map(globToRegExp).toList(),
+      project.watchExclude.map(globToRegExp).toList(),
+      gitignore,
+    );
+  }
+
+  /// Whether changes below [relativePath] are never of interest, so a
+  /// directory there doesn't need watching at all
+  bool excludes(String relativePath) {
+    if (relativePath.split('/').any(alwaysExcluded.contains)) {
+      return true;
+    }
+    if (_exclude.any((glob) => glob.hasMatch(relativePath))) {
+      return true;
+    }
+
+    var ignored = false;
+    for (final (rule, negated) in _gitignore) {
+      if (rule.hasMatch(relativePath)) {
+        ignored = !negated;
+      }
+    }
+    return ignored;
+  }
+
+  /// Whether a change to the file at [relativePath] counts
+  bool matches(String relativePath) {
+    if (excludes(relativePath) || _editorNoise.hasMatch(relativePath.split('/').last)) {
+      return false;
+    }
+    return _include.isEmpty || _include.any((glob) => glob.hasMatch(relativePath));
+  }
+
+  /// Convert a glob the way .gitignore reads it: `**` spans directories,
+  /// `*` and `?` stay within one, patterns without a slash match at any
+  /// depth and a pattern matching a directory matches everything in it
+  static RegExp globToRegExp(String glob) {
+    var pattern = glob.trim();
+    if (pattern.endsWith('/')) {
+      pattern = pattern.substring(0, pattern.length - 1);
+    }
+    final anchored = pattern.contains('/');
+    if (pattern.startsWith('/')) {
+      pattern = pattern.substring(1);
+    }
+
+    final buffer = StringBuffer(anchored ? '^' : r'^(?:.*/)?');
+    var i = 0;
+    while (i < pattern.length) {
+      if (pattern.startsWith('**/', i)) {
+        buffer.write(r'(?:.*/)?');
+        i += 3;
+      } else if (pattern.startsWith('**', i)) {
+        buffer.write('.*');
+        i += 2;
+      } else if (pattern[i] == '*') {
+        buffer.write('[^/]*');
+        i++;
+      } else if (pattern[i] == '?') {
+        buffer.write('[^/]');
+        i++;
+      } else {
+        buffer.write(RegExp.escape(pattern[i]));
+        i++;
+      }
+    }
+    buffer.write(r'(?:/.*)?$');
+    return RegExp(buffer.toString());
+  }
+}
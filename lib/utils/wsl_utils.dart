@@ -0,0 +1,27 @@
+/// Paths of projects kept in WSL, as Windows sees them
+class WslUtils {
+  // \\wsl$\Ubuntu\home\me\app, or \\wsl.localhost\Ubuntu\home\me\app
+  static final _sharePath = RegExp(r'^\\\\wsl(?:\$|\.localhost)\\([^\\]+)(.*)$', caseSensitive: false);
+
+  // C:\Users\me\app, which WSL mounts at /mnt/c/Users/me/app
+  static final _drivePath = RegExp(r'^([A-Za-z]):[\\/]?(.*)$');
+
+  /// Whether [path] is inside a WSL distribution, where the Windows bun
+  /// can't run tasks
+  static bool isWslPath(String path) => _sharePath.hasMatch(path);
+
+  /// Distribution [path] is in, null for Windows paths
+  static String? distroOf(String path) => _sharePath.firstMatch(path)?.group(1);
+
+  /// Where WSL sees [path]
+  static String toLinuxPath(String path) {
+    if (_sharePath.firstMatch(path) case final match?) {
+      final rest = match.group(2)!.replaceAll(r'\', '/');
+      return rest.isEmpty ? '/' : rest;
+    }
+    if (_drivePath.firstMatch(path) case final match?) {
+      return '/mnt/${match.group(1)!.toLowerCase()}/${match.group(2)!.replaceAll(r'\', '/')}';
+    }
+    return path.replaceAll(r'\', '/');
+  }
+}
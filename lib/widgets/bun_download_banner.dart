@@ -0,0 +1,83 @@
+import 'package:flutter/material.dart';
+import 'package:provider/provider.dart';
+
+import '../config/constants.dart';
+import '../providers/project_provider.dart';
+import '../services/binary_manager.dart';
+
+/// Shows bun download progress above [child], and the error with a retry
+/// button when the download fails.
+class BunDownloadBanner extends StatelessWidget {
+  final Widget child;
+
+  const BunDownloadBanner({super.key, required this.child});
+
+  static String _formatMegabytes(int bytes) => (bytes / (1024 * 1024)).toStringAsFixed(1);
+
+  Widget _buildProgress(BuildContext context, BunDownloadEvent event) {
+    final (String label, double? value) = switch (event) {
+      BunDownloadProgress(:final received, :final total, :final fraction) => (
+          total == null
+              ? 'Downloading bun ${event.version}: ${_formatMegabytes(received)} MB'
+              : 'Downloading bun ${event.version}: ${_formatMegabytes(received)} of ${_formatMegabytes(total)} MB',
+          fraction,
+        ),
+      _ => ('Verifying bun ${event.version}...', null),
+    };
+
+    return Container(
+      color: Theme.of(context).colorScheme.surfaceContainerHighest,
+      padding: const EdgeInsets.symmetric(
+        horizontal: AppConstants.spacingM,
+        vertical: AppConstants.spacingS,
+      ),
+      child: Column(
+        crossAxisAlignment: CrossAxisAlignment.start,
+        children: [
+          Text(label, style: Theme.of(context).textTheme.bodySmall),
+          const SizedBox(height: AppConstants.spacingXs),
+          LinearProgressIndicator(value: value),
+        ],
+      ),
+    );
+  }
+
+  Widget _buildError(BuildContext context, BunDownloadFailed event) {
+    final provider = context.read<ProjectProvider>();
+    return MaterialBanner(
+      leading: Icon(Icons.error_outline, color: Theme.of(context).colorScheme.error),
+      content: Text('Failed to download bun ${event.version}: ${event.error}'),
+      actions: [
+        TextButton(
+          onPressed: provider.dismissBunDownloadError,
+          child: const Text('Dismiss'),
+        ),
+        TextButton(
+          onPressed: provider.showSettings,
+          child: const Text('Settings'),
+        ),
+        FilledButton(
+          onPressed: provider.retryBunDownload,
+          child: const Text('Retry'),
+        ),
+      ],
+    );
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return Column(
+      children: [
+        Selector<ProjectProvider, BunDownloadEvent?>(
+          selector: (_, provider) => provider.bunDownload,
+          builder: (context, event, _) => switch (event) {
+            null || BunReady() => const SizedBox.shrink(),
+            BunDownloadFailed failed => _buildError(context, failed),
+            BunDownloadEvent progress => _buildProgress(context, progress),
+          },
+        ),
+        Expanded(child: child),
+      ],
+    );
+  }
+}
@@ -0,0 +1,105 @@
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+
+/// Asks before a destructive action, with a checkbox to stop asking.
+/// Pops whether the user chose not to be asked again, or null to cancel.
+class ConfirmDialog extends StatefulWidget {
+  final String title;
+  final String message;
+  final String confirmLabel;
+
+  const ConfirmDialog({
+    super.key,
+    required this.title,
+    required this.message,
+    required this.confirmLabel,
+  });
+
+  /// Go ahead right away when [ask] says the user opted out, otherwise show
+  /// the dialog and remember a "don't ask again" through [setAsk].
+  /// Completes with whether to go ahead.
+  static Future<bool> confirm(
+    BuildContext context, {
+    required String title,
+    required String message,
+    required String confirmLabel,
+    required Future<bool> Function() ask,
+    required Future<void> Function(bool) setAsk,
+  }) async {
+    if (!await ask()) {
+      return true;
+    }
+    if (!context.mounted) {
+      return false;
+    }
+
+    final dontAskAgain = await showDialog<bool>(
+      context: context,
+      builder: (context) => ConfirmDialog(
+        title: title,
+        message: message,
+        confirmLabel: confirmLabel,
+      ),
+    );
+    if (dontAskAgain == null) {
+      return false;
+    }
+    if (dontAskAgain) {
+      await setAsk(false);
+    }
+    return true;
+  }
+
+  @override
+  State<ConfirmDialog> createState() => _ConfirmDialogState();
+}
+
+class _ConfirmDialogState extends State<ConfirmDialog> {
+  bool _dontAskAgain = false;
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: Text(widget.title),
+      content: SizedBox(
+        width: 400,
+        child: Column(
+          mainAxisSize: MainAxisSize.min,
+          crossAxisAlignment: CrossAxisAlignment.start,
+          children: [
+            Text(widget.message),
+            const SizedBox(height: AppConstants.spacingS),
+            CheckboxListTile(
+              contentPadding: EdgeInsets.zero,
+              controlAffinity: ListTileControlAffinity.leading,
+              dense: true,
+              title: const Text("Don't ask again"),
+              subtitle: const Text('You can turn this back on in Settings.'),
+              value: _dontAskAgain,
+              onChanged: (value) {
+                setState(() {
+                  _dontAskAgain = value ?? false;
+                });
+              },
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          style: FilledButton.styleFrom(
+            backgroundColor: Theme.of(context).colorScheme.error,
+            foregroundColor: Theme.of(context).colorScheme.onError,
+          ),
+          onPressed: () => Navigator.of(context).pop(_dontAskAgain),
+          child: Text(widget.confirmLabel),
+        ),
+      ],
+    );
+  }
+}
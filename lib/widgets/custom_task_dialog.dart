@@ -0,0 +1,136 @@
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../models/project.dart';
+
+/// What the custom task dialog decided: a name and command to save, or
+/// removing the task
+class CustomTaskEdit {
+  final String name;
+  final String command;
+  final bool remove;
+
+  const CustomTaskEdit(this.name, this.command) : remove = false;
+  const CustomTaskEdit.remove()
+      : name = '',
+        command = '',
+        remove = true;
+}
+
+/// Adds a command as a task of a project, or edits one added before when
+/// [task] is set. Pops a [CustomTaskEdit], or null when cancelled.
+class CustomTaskDialog extends StatefulWidget {
+  final Project project;
+  final Task? task;
+
+  const CustomTaskDialog({
+    super.key,
+    required this.project,
+    this.task,
+  });
+
+  @override
+  State<CustomTaskDialog> createState() => _CustomTaskDialogState();
+}
+
+class _CustomTaskDialogState extends State<CustomTaskDialog> {
+  final _nameController = TextEditingController();
+  final _commandController = TextEditingController();
+
+  @override
+  void initState() {
+    super.initState();
+    _nameController.text = widget.task?.name ?? '';
+    _commandController.text = widget.task?.command ?? '';
+  }
+
+  @override
+  void dispose() {
+    _nameController.dispose();
+    _commandController.dispose();
+    super.dispose();
+  }
+
+  String? get _nameError {
+    final name = _nameController.text.trim();
+    final taken = widget.project.tasks.any((t) => t.name == name && t.name != widget.task?.name);
+    return taken ? 'The project already has a task named "$name"' : null;
+  }
+
+  bool get _canSave =>
+      _nameController.text.trim().isNotEmpty &&
+      _commandController.text.trim().isNotEmpty &&
+      _nameError == null;
+
+  void _save() {
+    Navigator.of(context).pop(CustomTaskEdit(
+      _nameController.text.trim(),
+      _commandController.text.trim(),
+    ));
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: Text(widget.task == null
+          ? 'Add a command to ${widget.project.name}'
+          : 'Edit ${widget.task!.name}'),
+      content: SizedBox(
+        width: 480,
+        child: Column(
+          crossAxisAlignment: CrossAxisAlignment.start,
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            TextField(
+              controller: _nameController,
+              autofocus: widget.task == null,
+              decoration: InputDecoration(
+                labelText: 'Name',
+                hintText: 'tunnel',
+                errorText: _nameError,
+                border: const OutlineInputBorder(),
+                isDense: true,
+              ),
+              onChanged: (_) => setState(() {}),
+            ),
+            const SizedBox(height: AppConstants.spacingM),
+            TextField(
+              controller: _commandController,
+              decoration: const InputDecoration(
+                labelText: 'Command',
+                hintText: 'ngrok http 3000',
+                border: OutlineInputBorder(),
+                isDense: true,
+              ),
+              style: const TextStyle(fontFamily: 'monospace'),
+              onChanged: (_) => setState(() {}),
+              onSubmitted: (_) => _canSave ? _save() : null,
+            ),
+            const SizedBox(height: AppConstants.spacingS),
+            Text(
+              'Runs through the shell from the project folder. Stored in bob, not in package.json.',
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                    color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                  ),
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        if (widget.task != null)
+          TextButton(
+            onPressed: () => Navigator.of(context).pop(const CustomTaskEdit.remove()),
+            child: const Text('Remove'),
+          ),
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _canSave ? _save : null,
+          child: const Text('Save'),
+        ),
+      ],
+    );
+  }
+}
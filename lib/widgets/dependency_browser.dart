@@ -0,0 +1,172 @@
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../models/package_dependency.dart';
+import '../models/project.dart';
+import '../services/project_service.dart';
+
+/// Lists the dependencies of a project with the versions actually installed
+class DependencyBrowser extends StatefulWidget {
+  final Project project;
+  final ProjectService projectService;
+  final VoidCallback onClose;
+
+  const DependencyBrowser({
+    super.key,
+    required this.project,
+    required this.projectService,
+    required this.onClose,
+  });
+
+  @override
+  State<DependencyBrowser> createState() => _DependencyBrowserState();
+}
+
+class _DependencyBrowserState extends State<DependencyBrowser> {
+  List<PackageDependency> _dependencies = [];
+  String _query = '';
+  bool _isLoading = true;
+  String? _error;
+
+  @override
+  void initState() {
+    super.initState();
+    _loadDependencies();
+  }
+
+  Future<void> _loadDependencies() async {
+    setState(() {
+      _isLoading = true;
+    });
+
+    try {
+      final dependencies = await widget.projectService.readDependencies(widget.project.path);
+      if (!mounted) return;
+      setState(() {
+        _dependencies = dependencies;
+        _error = null;
+        _isLoading = false;
+      });
+    } catch (e) {
+      if (!mounted) return;
+      setState(() {
+        _error = 'Failed to read dependencies: $e';
+        _isLoading = false;
+      });
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final query = _query.toLowerCase();
+    final matching = _dependencies.where((d) => d.name.toLowerCase().contains(query)).toList();
+
+    return Column(
+      crossAxisAlignment: CrossAxisAlignment.start,
+      children: [
+        // Header
+        Container(
+          decoration: BoxDecoration(
+            color: Theme.of(context).colorScheme.primaryContainer,
+            border: Border(
+              bottom: BorderSide(
+                color: Theme.of(context).dividerColor,
+              ),
+            ),
+          ),
+          padding: const EdgeInsets.all(AppConstants.rightPaneContentPadding),
+          child: Row(
+            children: [
+              Icon(
+                Icons.inventory_2_outlined,
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+              const SizedBox(width: AppConstants.spacingM),
+              Expanded(
+                child: Text(
+                  'Dependencies of ${widget.project.name}',
+                  style: Theme.of(context).textTheme.titleMedium?.copyWith(
+                        fontWeight: FontWeight.bold,
+                        color: Theme.of(context).colorScheme.onPrimaryContainer,
+                      ),
+                ),
+              ),
+              IconButton(
+                icon: const Icon(Icons.refresh),
+                tooltip: 'Refresh',
+                onPressed: _loadDependencies,
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+              IconButton(
+                icon: const Icon(Icons.close),
+                onPressed: widget.onClose,
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+            ],
+          ),
+        ),
+        Padding(
+          padding: const EdgeInsets.all(AppConstants.spacingM),
+          child: TextField(
+            decoration: const InputDecoration(
+              prefixIcon: Icon(Icons.search),
+              hintText: 'Search dependencies',
+              border: OutlineInputBorder(),
+              isDense: true,
+            ),
+            onChanged: (value) {
+              setState(() {
+                _query = value;
+              });
+            },
+          ),
+        ),
+        Expanded(
+          child: _isLoading
+              ? const Center(child: CircularProgressIndicator())
+              : _error != null
+                  ? Center(child: Text(_error!))
+                  : matching.isEmpty
+                      ? Center(
+                          child: Text(
+                            _dependencies.isEmpty ? 'No dependencies' : 'No dependency matches "$_query"',
+                            style: Theme.of(context).textTheme.bodyMedium?.copyWith(
+                                  color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
+                                ),
+                          ),
+                        )
+                      : ListView.builder(
+                          itemCount: matching.length,
+                          itemBuilder: (context, index) => _buildDependency(context, matching[index]),
+                        ),
+        ),
+      ],
+    );
+  }
+
+  Widget _buildDependency(BuildContext context, PackageDependency dependency) {
+    final muted = Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.6);
+    return ListTile(
+      dense: true,
+      title: Row(
+        children: [
+          Flexible(child: Text(dependency.name, overflow: TextOverflow.ellipsis)),
+          if (dependency.isDev) ...[
+            const SizedBox(width: AppConstants.spacingS),
+            Text('dev', style: Theme.of(context).textTheme.labelSmall?.copyWith(color: muted)),
+          ],
+        ],
+      ),
+      subtitle: Text(
+        'Requested ${dependency.requested}',
+        style: const TextStyle(fontFamily: 'monospace'),
+      ),
+      trailing: Text(
+        dependency.installed ?? 'Not installed',
+        style: dependency.installed != null
+            ? const TextStyle(fontFamily: 'monospace')
+            : TextStyle(color: Theme.of(context).colorScheme.error),
+      ),
+    );
+  }
+}
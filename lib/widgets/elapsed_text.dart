@@ -0,0 +1,46 @@
+import 'dart:async';
+
+import 'package:flutter/material.dart';
+
+import '../utils/task_utils.dart';
+
+/// Text showing the time elapsed since [since], updated every second
+class ElapsedText extends StatefulWidget {
+  final DateTime since;
+
+  /// Builds the text from the formatted elapsed time
+  final String Function(String elapsed) label;
+  final TextStyle? style;
+
+  const ElapsedText({
+    super.key,
+    required this.since,
+    required this.label,
+    this.style,
+  });
+
+  @override
+  State<ElapsedText> createState() => _ElapsedTextState();
+}
+
+class _ElapsedTextState extends State<ElapsedText> {
+  late final Timer _timer;
+
+  @override
+  void initState() {
+    super.initState();
+    _timer = Timer.periodic(const Duration(seconds: 1), (_) => setState(() {}));
+  }
+
+  @override
+  void dispose() {
+    _timer.cancel();
+    super.dispose();
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final elapsed = DateTime.now().difference(widget.since);
+    return Text(widget.label(TaskUtils.formatDuration(elapsed)), style: widget.style);
+  }
+}
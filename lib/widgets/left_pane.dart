@@ -1,8 +1,20 @@
+import 'package:flutter/foundation.dart';
 import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
 
 import '../config/constants.dart';
 import '../extensions/task_status_extension.dart';
 import '../models/project.dart';
+import '../services/devcontainer_service.dart';
+import '../services/git_service.dart';
+import '../services/preferences_service.dart';
+import '../services/resource_monitor.dart';
+import '../utils/process_utils.dart';
+import '../utils/task_utils.dart';
+import '../utils/ui_utils.dart';
+import 'confirm_dialog.dart';
+import 'elapsed_text.dart';
+import 'profile_menu.dart';
 
 class LeftPane extends StatelessWidget {
   final List<Project> projects;
@@ -10,18 +22,43 @@ class LeftPane extends StatelessWidget {
   final Site? selectedLaunch;
   final bool isCreationFormVisible;
   final bool showingSettings;
+  final bool readOnly;
   final Set<String> projectsBeingImported;
   final Map<String, String> importErrors;
+  final Map<String, GitStatus> gitStatuses;
+  final Map<String, DevcontainerStatus> devcontainerStatuses;
+  final Map<String, String> manifestErrors;
+  final Set<String> collapsedProjects;
+  final Map<String, String> projectIcons;
+  final List<String> favoriteTasks;
+  final List<String> recentTasks;
+  final Map<String, ProcessUsage> taskUsage;
+  final Map<String, (int, int)> problemCounts;
   final VoidCallback onImportProject;
+  final VoidCallback onImportWorkspace;
+  final VoidCallback onAddRemoteProject;
   final VoidCallback onCreateProject;
   final Function(Task) onTaskSelected;
   final Function(Site) onLaunchSelected;
   final Function(int, int) onReorderProjects;
+  final Function(Project) onToggleProjectCollapsed;
   final Function(Project) onRemoveProject;
   final Function(Project, Task) onTaskToggle;
+  final Function(Project, Task) onTaskKill;
+  final Function(Project, Task) onToggleFavorite;
+  final Function(Project) onRunAll;
+  final Function(Project, Task, List<String>?) onSetScriptGroup;
   final Function(Project, Site) onLaunchToggle;
   final Function(Project) onOpenInExplorer;
+  final Function(Project) onOpenInEditor;
+  final Function(Project) onOpenTerminal;
   final Function(Project) onConfigureProject;
+  final Function(Project) onBrowseLogs;
+  final Function(Project) onBrowseDependencies;
+  final Function(Project) onEditScripts;
+  final Function(Project) onToggleDevcontainer;
+  final Function(Project) onSetProjectIcon;
+  final Function(Project, Task?) onEditCustomTask;
   final Function(Project) onCreateLaunchTarget;
   final VoidCallback onOpenSettings;
   final Function(String) onDismissError;
@@ -34,26 +71,163 @@ class LeftPane extends StatelessWidget {
     required this.selectedLaunch,
     required this.isCreationFormVisible,
     required this.showingSettings,
+    required this.readOnly,
     required this.projectsBeingImported,
     required this.importErrors,
+    required this.gitStatuses,
+    required this.devcontainerStatuses,
+    required this.manifestErrors,
+    required this.collapsedProjects,
+    required this.projectIcons,
+    required this.favoriteTasks,
+    required this.recentTasks,
+    required this.taskUsage,
+    required this.problemCounts,
     required this.onImportProject,
+    required this.onImportWorkspace,
+    required this.onAddRemoteProject,
     required this.onCreateProject,
     required this.onTaskSelected,
     required this.onLaunchSelected,
     required this.onReorderProjects,
+    required this.onToggleProjectCollapsed,
     required this.onRemoveProject,
     required this.onTaskToggle,
+    required this.onTaskKill,
+    required this.onToggleFavorite,
+    required this.onRunAll,
+    required this.onSetScriptGroup,
     required this.onLaunchToggle,
     required this.onOpenInExplorer,
+    required this.onOpenInEditor,
+    required this.onOpenTerminal,
     required this.onConfigureProject,
+    required this.onBrowseLogs,
+    required this.onBrowseDependencies,
+    required this.onEditScripts,
+    required this.onToggleDevcontainer,
+    required this.onSetProjectIcon,
+    required this.onEditCustomTask,
     required this.onCreateLaunchTarget,
     required this.onOpenSettings,
     required this.onDismissError,
     required this.onRetryImport,
   });
 
+  /// Everything a project's section renders, so unchanged sections aren't rebuilt.
+  /// Tasks are replaced on every output chunk, so their fields are compared instead.
+  List<Object?> _signatureOf(Project project, bool collapsed) {
+    return [
+      project,
+      collapsed,
+      projectIcons[project.path],
+      readOnly,
+      gitStatuses[project.path],
+      devcontainerStatuses[project.path],
+      manifestErrors[project.path],
+      project.tasks.contains(selectedTask) ? selectedTask!.name : null,
+      project.sites.contains(selectedLaunch) ? selectedLaunch : null,
+      for (final task in project.tasks) ...[
+        task.name,
+        task.alias,
+        task.command,
+        task.type,
+        task.status,
+        task.lastExitCode,
+        task.isStopping,
+        task.startedAt,
+        task.lastDuration,
+        task.children.join(','),
+        taskUsage[TaskUtils.getTaskKey(project, task)],
+        problemCounts[TaskUtils.getTaskKey(project, task)],
+      ],
+      ...project.sites,
+    ];
+  }
+
+  /// Tasks with their projects by task key, skipping those no longer listed
+  List<(Project, Task)> _resolveTasks(List<Project> listedProjects, Iterable<String> taskKeys) {
+    final byKey = {
+      for (final project in listedProjects)
+        for (final task in project.tasks) TaskUtils.getTaskKey(project, task): (project, task),
+    };
+    return [
+      for (final taskKey in taskKeys)
+        if (byKey[taskKey] case final resolved?) resolved,
+    ];
+  }
+
+  /// A starred or recently run task, listed above the projects
+  Widget _buildPinnedTask(BuildContext context, Project project, Task task, {required bool favorite}) {
+    final isSelected = task.name == selectedTask?.name && project.tasks.contains(selectedTask);
+    final colorScheme = Theme.of(context).colorScheme;
+    final icon = projectIcons[project.path];
+
+    return _SidebarRow(
+      id: (section: favorite ? 'favorites' : 'recent', projectPath: project.path, task: task.name),
+      onActivate: () => onTaskSelected(task),
+      child: Container(
+        decoration: isSelected ? BoxDecoration(color: colorScheme.primaryContainer) : null,
+        child: ListTile(
+          dense: true,
+          leading: favorite
+              ? Tooltip(
+                  message: 'Remove from favorites',
+                  child: InkWell(
+                    onTap: () => onToggleFavorite(project, task),
+                    child: const Icon(Icons.star, size: 18, color: Colors.amber),
+                  ),
+                )
+              : Icon(Icons.history, size: 18, color: colorScheme.onSurface.withValues(alpha: 0.5)),
+          title: Text(
+            task.displayName,
+            overflow: TextOverflow.ellipsis,
+            style: TextStyle(
+              fontWeight: isSelected ? FontWeight.bold : FontWeight.normal,
+              color: isSelected ? colorScheme.onPrimaryContainer : null,
+            ),
+          ),
+          subtitle: Text(
+            icon != null ? '$icon ${project.name}' : project.name,
+            overflow: TextOverflow.ellipsis,
+            style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                  color: colorScheme.onSurface.withValues(alpha: 0.5),
+                ),
+          ),
+          trailing: IconButton(
+            icon: Icon(
+              task.status.getIcon(),
+              color: isSelected ? colorScheme.onPrimaryContainer : task.status.getColor(context),
+            ),
+            onPressed: readOnly || task.isStopping ? null : () => onTaskToggle(project, task),
+            padding: EdgeInsets.zero,
+          ),
+          onTap: () => onTaskSelected(task),
+        ),
+      ),
+    );
+  }
+
   @override
   Widget build(BuildContext context) {
+    final listedProjects = projects
+        .where((p) => !projectsBeingImported.contains(p.path) && !importErrors.containsKey(p.path))
+        .toList();
+    final favorites = _resolveTasks(listedProjects, favoriteTasks);
+    // Starred tasks are a click away already
+    final recents = _resolveTasks(listedProjects, recentTasks.where((key) => !favoriteTasks.contains(key)));
+    // Every row the arrow keys move between, built or not, top to bottom
+    final rows = <_SidebarRowId>[
+      for (final (project, task) in favorites) (section: 'favorites', projectPath: project.path, task: task.name),
+      for (final (project, task) in recents) (section: 'recent', projectPath: project.path, task: task.name),
+      for (final project in listedProjects) ...[
+        (section: 'projects', projectPath: project.path, task: null),
+        if (!collapsedProjects.contains(project.path))
+          for (final (task, _) in _ProjectTile.listedTasks(project))
+            (section: 'projects', projectPath: project.path, task: task.name),
+      ],
+    ];
+
     return Container(
       decoration: BoxDecoration(
         color: Theme.of(context).colorScheme.surfaceContainerLow,
@@ -74,28 +248,55 @@ class LeftPane extends StatelessWidget {
                       'Projects',
                       style: Theme.of(context).textTheme.labelLarge,
                     ),
+                    const SizedBox(width: AppConstants.spacingS),
+                    const ProfileMenu(),
                   ],
                 ),
                 const Spacer(),
                 Row(
                   mainAxisSize: MainAxisSize.min,
                   children: [
-                    IconButton(
-                      icon: const Icon(Icons.create_new_folder),
-                      tooltip: 'Create',
-                      onPressed: onCreateProject,
-                      color: isCreationFormVisible
-                          ? Theme.of(context).colorScheme.primary
-                          : Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
-                      padding: EdgeInsets.zero,
-                    ),
-                    IconButton(
-                      icon: const Icon(Icons.folder_open),
-                      tooltip: 'Import',
-                      onPressed: onImportProject,
-                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
-                      padding: EdgeInsets.zero,
-                    ),
+                    if (readOnly)
+                      Tooltip(
+                        message: 'Read-only mode: tasks can\'t be started or stopped',
+                        child: Icon(
+                          Icons.lock_outline,
+                          size: 20,
+                          color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                        ),
+                      )
+                    else ...[
+                      IconButton(
+                        icon: const Icon(Icons.create_new_folder),
+                        tooltip: 'Create',
+                        onPressed: onCreateProject,
+                        color: isCreationFormVisible
+                            ? Theme.of(context).colorScheme.primary
+                            : Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                        padding: EdgeInsets.zero,
+                      ),
+                      IconButton(
+                        icon: const Icon(Icons.folder_open),
+                        tooltip: 'Import',
+                        onPressed: onImportProject,
+                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                        padding: EdgeInsets.zero,
+                      ),
+                      IconButton(
+                        icon: const Icon(Icons.folder_copy),
+                        tooltip: 'Import workspace',
+                        onPressed: onImportWorkspace,
+                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                        padding: EdgeInsets.zero,
+                      ),
+                      IconButton(
+                        icon: const Icon(Icons.dns),
+                        tooltip: 'Add remote project',
+                        onPressed: onAddRemoteProject,
+                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                        padding: EdgeInsets.zero,
+                      ),
+                    ],
                     const SizedBox(width: AppConstants.spacingS),
                   ],
                 ),
@@ -139,160 +340,221 @@ class LeftPane extends StatelessWidget {
                 ],
               ),
             ),
-          Expanded(
-            child: ListView(
-              children: [
-                // Show importing projects
-                ...projectsBeingImported.map((path) {
-                  final name = path.split(r'\').last.split('/').last;
-                  return ListTile(
-                    dense: true,
-                    leading: Row(
-                      mainAxisSize: MainAxisSize.min,
-                      children: [
-                        const SizedBox(
-                          width: 16,
-                          height: 16,
-                          child: CircularProgressIndicator(strokeWidth: 2),
+          _SidebarNavigation(
+            rows: rows,
+            // Slivers build only the rows scrolled into view
+            child: CustomScrollView(
+              slivers: [
+                if (favorites.isNotEmpty)
+                  SliverList.list(
+                    children: [
+                      Padding(
+                        padding: const EdgeInsets.fromLTRB(
+                          AppConstants.leftPaneHeaderPadding,
+                          AppConstants.spacingS,
+                          AppConstants.leftPaneHeaderPadding,
+                          0,
                         ),
-                        const SizedBox(width: AppConstants.spacingS),
-                        Icon(
-                          Icons.folder,
-                          color: Theme.of(context).colorScheme.primary.withValues(alpha: 0.5),
+                        child: Text('Favorites', style: Theme.of(context).textTheme.labelMedium),
+                      ),
+                      for (final (project, task) in favorites)
+                        _buildPinnedTask(context, project, task, favorite: true),
+                      const Divider(height: 1),
+                    ],
+                  ),
+                if (recents.isNotEmpty)
+                  SliverList.list(
+                    children: [
+                      Padding(
+                        padding: const EdgeInsets.fromLTRB(
+                          AppConstants.leftPaneHeaderPadding,
+                          AppConstants.spacingS,
+                          AppConstants.leftPaneHeaderPadding,
+                          0,
                         ),
-                      ],
-                    ),
-                    title: Text(
-                      name,
-                      style: TextStyle(
-                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
+                        child: Text('Recently run', style: Theme.of(context).textTheme.labelMedium),
                       ),
-                    ),
-                    subtitle: Text(
-                      'Importing...',
-                      style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      for (final (project, task) in recents)
+                        _buildPinnedTask(context, project, task, favorite: false),
+                      const Divider(height: 1),
+                    ],
+                  ),
+                SliverList.list(
+                  children: [
+                    // Show importing projects
+                    ...projectsBeingImported.map((path) {
+                      final name = path.split(r'\').last.split('/').last;
+                      return ListTile(
+                        dense: true,
+                        leading: Row(
+                          mainAxisSize: MainAxisSize.min,
+                          children: [
+                            const SizedBox(
+                              width: 16,
+                              height: 16,
+                              child: CircularProgressIndicator(strokeWidth: 2),
+                            ),
+                            const SizedBox(width: AppConstants.spacingS),
+                            Icon(
+                              Icons.folder,
+                              color: Theme.of(context).colorScheme.primary.withValues(alpha: 0.5),
+                            ),
+                          ],
+                        ),
+                        title: Text(
+                          name,
+                          style: TextStyle(
                             color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
                           ),
-                    ),
-                  );
-                }),
-                // Show import errors
-                ...importErrors.entries.map((entry) {
-                  final path = entry.key;
-                  final error = entry.value;
-                  final name = path.split(r'\').last.split('/').last;
-                  return ListTile(
-                    dense: true,
-                    leading: Row(
-                      mainAxisSize: MainAxisSize.min,
-                      children: [
-                        Icon(
-                          Icons.error_outline,
-                          size: 16,
-                          color: Theme.of(context).colorScheme.error,
                         ),
-                        const SizedBox(width: AppConstants.spacingS),
-                        Icon(
-                          Icons.folder,
-                          color: Theme.of(context).colorScheme.error.withValues(alpha: 0.7),
-                        ),
-                      ],
-                    ),
-                    title: Text(
-                      name,
-                      style: TextStyle(
-                        color: Theme.of(context).colorScheme.error,
-                      ),
-                    ),
-                    subtitle: Column(
-                      crossAxisAlignment: CrossAxisAlignment.start,
-                      children: [
-                        Text(
-                          error,
+                        subtitle: Text(
+                          'Importing...',
                           style: Theme.of(context).textTheme.bodySmall?.copyWith(
-                                color: Theme.of(context).colorScheme.error.withValues(alpha: 0.8),
+                                color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
                               ),
-                          maxLines: 2,
-                          overflow: TextOverflow.ellipsis,
                         ),
-                        const SizedBox(height: AppConstants.spacingXs),
-                        Row(
+                      );
+                    }),
+                    // Show import errors
+                    ...importErrors.entries.map((entry) {
+                      final path = entry.key;
+                      final error = entry.value;
+                      final name = path.split(r'\').last.split('/').last;
+                      return ListTile(
+                        dense: true,
+                        leading: Row(
+                          mainAxisSize: MainAxisSize.min,
                           children: [
-                            TextButton.icon(
-                              onPressed: () => onRetryImport(path),
-                              icon: const Icon(Icons.refresh, size: 14),
-                              label: const Text('Retry'),
-                              style: TextButton.styleFrom(
-                                padding: const EdgeInsets.symmetric(horizontal: 8, vertical: 4),
-                                minimumSize: Size.zero,
-                                tapTargetSize: MaterialTapTargetSize.shrinkWrap,
-                              ),
+                            Icon(
+                              Icons.error_outline,
+                              size: 16,
+                              color: Theme.of(context).colorScheme.error,
                             ),
                             const SizedBox(width: AppConstants.spacingS),
-                            TextButton.icon(
-                              onPressed: () => onDismissError(path),
-                              icon: const Icon(Icons.close, size: 14),
-                              label: const Text('Dismiss'),
-                              style: TextButton.styleFrom(
-                                padding: const EdgeInsets.symmetric(horizontal: 8, vertical: 4),
-                                minimumSize: Size.zero,
-                                tapTargetSize: MaterialTapTargetSize.shrinkWrap,
-                              ),
+                            Icon(
+                              Icons.folder,
+                              color: Theme.of(context).colorScheme.error.withValues(alpha: 0.7),
                             ),
                           ],
                         ),
-                      ],
-                    ),
-                  );
-                }),
+                        title: Text(
+                          name,
+                          style: TextStyle(
+                            color: Theme.of(context).colorScheme.error,
+                          ),
+                        ),
+                        subtitle: Column(
+                          crossAxisAlignment: CrossAxisAlignment.start,
+                          children: [
+                            Text(
+                              error,
+                              style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                                    color: Theme.of(context).colorScheme.error.withValues(alpha: 0.8),
+                                  ),
+                              maxLines: 2,
+                              overflow: TextOverflow.ellipsis,
+                            ),
+                            const SizedBox(height: AppConstants.spacingXs),
+                            Row(
+                              children: [
+                                TextButton.icon(
+                                  onPressed: () => onRetryImport(path),
+                                  icon: const Icon(Icons.refresh, size: 14),
+                                  label: const Text('Retry'),
+                                  style: TextButton.styleFrom(
+                                    padding: const EdgeInsets.symmetric(horizontal: 8, vertical: 4),
+                                    minimumSize: Size.zero,
+                                    tapTargetSize: MaterialTapTargetSize.shrinkWrap,
+                                  ),
+                                ),
+                                const SizedBox(width: AppConstants.spacingS),
+                                TextButton.icon(
+                                  onPressed: () => onDismissError(path),
+                                  icon: const Icon(Icons.close, size: 14),
+                                  label: const Text('Dismiss'),
+                                  style: TextButton.styleFrom(
+                                    padding: const EdgeInsets.symmetric(horizontal: 8, vertical: 4),
+                                    minimumSize: Size.zero,
+                                    tapTargetSize: MaterialTapTargetSize.shrinkWrap,
+                                  ),
+                                ),
+                              ],
+                            ),
+                          ],
+                        ),
+                      );
+                    }),
+                  ],
+                ),
                 // Regular project list (exclude projects currently being imported and those with errors)
-                ReorderableListView.builder(
-                  shrinkWrap: true,
-                  physics: const NeverScrollableScrollPhysics(),
-                  itemCount: projects.where((p) => !projectsBeingImported.contains(p.path) && !importErrors.containsKey(p.path)).length,
+                SliverReorderableList(
+                  itemCount: listedProjects.length,
                   onReorder: (oldFilteredIndex, newFilteredIndex) {
                     // Map filtered indices to original indices in the full projects list
-                    final filteredProjects = projects.where((p) => !projectsBeingImported.contains(p.path) && !importErrors.containsKey(p.path)).toList();
-
-                    final projectToMove = filteredProjects[oldFilteredIndex];
+                    final projectToMove = listedProjects[oldFilteredIndex];
 
                     // Find the actual index in the full projects list
                     final oldIndex = projects.indexWhere((p) => p.path == projectToMove.path);
 
                     // Calculate the new index in the full list
                     int newIndex;
-                    if (newFilteredIndex >= filteredProjects.length) {
+                    if (newFilteredIndex >= listedProjects.length) {
                       // Moving to the end
                       newIndex = projects.length;
                     } else {
-                      // Adjust for the reorder offset
-                      final adjustedFilteredIndex = newFilteredIndex > oldFilteredIndex ? newFilteredIndex : newFilteredIndex;
-                      final projectAtNewPosition = filteredProjects[adjustedFilteredIndex];
+                      final projectAtNewPosition = listedProjects[newFilteredIndex];
                       newIndex = projects.indexWhere((p) => p.path == projectAtNewPosition.path);
                     }
 
                     onReorderProjects(oldIndex, newIndex);
                   },
-                  buildDefaultDragHandles: false,
+                  proxyDecorator: (child, index, animation) => Material(
+                    elevation: 4,
+                    child: child,
+                  ),
                   itemBuilder: (context, index) {
-                    final filteredProjects = projects.where((p) => !projectsBeingImported.contains(p.path) && !importErrors.containsKey(p.path)).toList();
-                    final project = filteredProjects[index];
+                    final project = listedProjects[index];
+                    final collapsed = collapsedProjects.contains(project.path);
 
                     return ReorderableDragStartListener(
                       key: ValueKey(project.path),
                       index: index,
-                      child: _ProjectTile(
-                        project: project,
-                        selectedTask: selectedTask,
-                        selectedLaunch: selectedLaunch,
-                        onTaskSelected: onTaskSelected,
-                        onLaunchSelected: onLaunchSelected,
-                        onRemoveProject: onRemoveProject,
-                        onTaskToggle: onTaskToggle,
-                        onLaunchToggle: onLaunchToggle,
-                        onOpenInExplorer: onOpenInExplorer,
-                        onConfigureProject: onConfigureProject,
-                        onCreateLaunchTarget: onCreateLaunchTarget,
+                      enabled: !readOnly,
+                      child: _MemoizedProjectTile(
+                        signature: _signatureOf(project, collapsed),
+                        tile: _ProjectTile(
+                          project: project,
+                          collapsed: collapsed,
+                          icon: projectIcons[project.path],
+                          taskUsage: taskUsage,
+                          problemCounts: problemCounts,
+                          readOnly: readOnly,
+                          gitStatus: gitStatuses[project.path],
+                          devcontainerStatus: devcontainerStatuses[project.path],
+                          manifestError: manifestErrors[project.path],
+                          selectedTask: selectedTask,
+                          selectedLaunch: selectedLaunch,
+                          onToggleCollapsed: onToggleProjectCollapsed,
+                          onTaskSelected: onTaskSelected,
+                          onLaunchSelected: onLaunchSelected,
+                          onRemoveProject: onRemoveProject,
+                          onTaskToggle: onTaskToggle,
+                          onTaskKill: onTaskKill,
+                          onRunAll: onRunAll,
+                          onSetScriptGroup: onSetScriptGroup,
+                          onLaunchToggle: onLaunchToggle,
+                          onOpenInExplorer: onOpenInExplorer,
+                          onOpenInEditor: onOpenInEditor,
+                          onOpenTerminal: onOpenTerminal,
+                          onConfigureProject: onConfigureProject,
+                          onBrowseLogs: onBrowseLogs,
+                          onBrowseDependencies: onBrowseDependencies,
+                          onEditScripts: onEditScripts,
+                          onToggleDevcontainer: onToggleDevcontainer,
+                          onSetProjectIcon: onSetProjectIcon,
+                          onEditCustomTask: onEditCustomTask,
+                          onCreateLaunchTarget: onCreateLaunchTarget,
+                        ),
                       ),
                     );
                   },
@@ -334,220 +596,779 @@ class LeftPane extends StatelessWidget {
   }
 }
 
+/// Keeps the last built project section while its signature is unchanged
+class _MemoizedProjectTile extends StatefulWidget {
+  final List<Object?> signature;
+  final _ProjectTile tile;
+
+  const _MemoizedProjectTile({
+    required this.signature,
+    required this.tile,
+  });
+
+  @override
+  State<_MemoizedProjectTile> createState() => _MemoizedProjectTileState();
+}
+
+class _MemoizedProjectTileState extends State<_MemoizedProjectTile> {
+  late _ProjectTile _tile = widget.tile;
+
+  @override
+  void didUpdateWidget(_MemoizedProjectTile oldWidget) {
+    super.didUpdateWidget(oldWidget);
+    if (!listEquals(oldWidget.signature, widget.signature)) {
+      _tile = widget.tile;
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) => _tile;
+}
+
 class _ProjectTile extends StatelessWidget {
   final Project project;
+  final bool collapsed;
+
+  /// Emoji shown instead of the folder icon
+  final String? icon;
+
+  final Map<String, ProcessUsage> taskUsage;
+  final Map<String, (int, int)> problemCounts;
+  final GitStatus? gitStatus;
+  final DevcontainerStatus? devcontainerStatus;
+  final String? manifestError;
+  final bool readOnly;
   final Task? selectedTask;
   final Site? selectedLaunch;
   final Function(Task) onTaskSelected;
   final Function(Site) onLaunchSelected;
+  final Function(Project) onToggleCollapsed;
   final Function(Project) onRemoveProject;
   final Function(Project, Task) onTaskToggle;
+  final Function(Project, Task) onTaskKill;
+  final Function(Project) onRunAll;
+  final Function(Project, Task, List<String>?) onSetScriptGroup;
   final Function(Project, Site) onLaunchToggle;
   final Function(Project) onOpenInExplorer;
+  final Function(Project) onOpenInEditor;
+  final Function(Project) onOpenTerminal;
   final Function(Project) onConfigureProject;
+  final Function(Project) onBrowseLogs;
+  final Function(Project) onBrowseDependencies;
+  final Function(Project) onEditScripts;
+  final Function(Project) onToggleDevcontainer;
+  final Function(Project) onSetProjectIcon;
+  final Function(Project, Task?) onEditCustomTask;
   final Function(Project) onCreateLaunchTarget;
 
   const _ProjectTile({
     required this.project,
+    required this.collapsed,
+    required this.icon,
+    required this.taskUsage,
+    required this.problemCounts,
+    required this.gitStatus,
+    required this.devcontainerStatus,
+    required this.manifestError,
+    required this.readOnly,
     required this.selectedTask,
     required this.selectedLaunch,
     required this.onTaskSelected,
     required this.onLaunchSelected,
+    required this.onToggleCollapsed,
     required this.onRemoveProject,
     required this.onTaskToggle,
+    required this.onTaskKill,
+    required this.onRunAll,
+    required this.onSetScriptGroup,
     required this.onLaunchToggle,
     required this.onOpenInExplorer,
+    required this.onOpenInEditor,
+    required this.onOpenTerminal,
     required this.onConfigureProject,
+    required this.onBrowseLogs,
+    required this.onBrowseDependencies,
+    required this.onEditScripts,
+    required this.onToggleDevcontainer,
+    required this.onSetProjectIcon,
+    required this.onEditCustomTask,
     required this.onCreateLaunchTarget,
   });
 
-  @override
-  Widget build(BuildContext context) {
-    return Column(
-      mainAxisSize: MainAxisSize.min,
-      children: [
-        ListTile(
+  /// Kill a stopping task without waiting for the rest of its grace period
+  Future<void> _killTask(BuildContext context, Task task) async {
+    final confirmed = await ConfirmDialog.confirm(
+      context,
+      title: 'Kill ${task.name}?',
+      message: 'The task is still stopping. Killing it skips the rest of its grace period, '
+          'so it may leave temporary files or locks behind.',
+      confirmLabel: 'Kill',
+      ask: PreferencesService().getConfirmKillTask,
+      setAsk: PreferencesService().setConfirmKillTask,
+    );
+    if (confirmed) {
+      onTaskKill(project, task);
+    }
+  }
+
+  /// The task's current instance, since this tile may outlive the one it was built with
+  Task _current(Task task) {
+    return project.tasks.firstWhere((t) => t.name == task.name, orElse: () => task);
+  }
+
+  Widget? _buildTaskSubtitle(Task task, ProcessUsage? usage, TextStyle? style) {
+    final duration = task.lastDuration != null ? TaskUtils.formatDuration(task.lastDuration!) : null;
+
+    if (task.isStopping) {
+      return Text('Stopping...', style: style);
+    }
+    if (task.status == TaskStatus.running) {
+      final usageLabel = usage != null ? ' · ${usage.formattedCpu} · ${usage.formattedRss}' : '';
+      if (task.startedAt == null) {
+        return Text('Running...$usageLabel', style: style);
+      }
+      return ElapsedText(
+        since: task.startedAt!,
+        label: (elapsed) => 'Running · $elapsed$usageLabel',
+        style: style,
+      );
+    }
+    if (task.status != TaskStatus.idle && task.lastExitCode != null && task.lastExitCode != 0) {
+      final exit = task.lastExitCode! < 0
+          ? TaskUtils.describeExitCode(task.lastExitCode!)
+          : 'Exit: ${task.lastExitCode}';
+      return Text('$exit${duration != null ? ' · $duration' : ''}', style: style);
+    }
+    if (task.status == TaskStatus.success && duration != null) {
+      return Text('Done in $duration', style: style);
+    }
+    if (task.description != null) {
+      return Text(task.description!, maxLines: 1, overflow: TextOverflow.ellipsis, style: style);
+    }
+    return null;
+  }
+
+  /// File a task was read from, for tasks that don't come from package.json
+  String? _manifestOf(TaskType type) {
+    return switch (type) {
+      TaskType.make => 'Makefile',
+      TaskType.taskfile => 'Taskfile',
+      TaskType.python => 'pyproject',
+      TaskType.procfile => 'Procfile',
+      _ => null,
+    };
+  }
+
+  Widget _buildManifestBadge(BuildContext context, String manifest) {
+    final colorScheme = Theme.of(context).colorScheme;
+    return Container(
+      padding: const EdgeInsets.symmetric(horizontal: AppConstants.spacingXs),
+      decoration: BoxDecoration(
+        border: Border.all(color: colorScheme.outline.withValues(alpha: 0.5)),
+        borderRadius: BorderRadius.circular(4),
+      ),
+      child: Text(
+        manifest,
+        style: Theme.of(context).textTheme.labelSmall?.copyWith(
+              color: colorScheme.onSurface.withValues(alpha: 0.6),
+            ),
+      ),
+    );
+  }
+
+  /// Errors and warnings found in the task's output, as "3⚠ 1✖"
+  Widget _buildProblemBadge(BuildContext context, int errors, int warnings) {
+    final style = Theme.of(context).textTheme.labelSmall;
+    return Text.rich(
+      TextSpan(
+        children: [
+          if (warnings > 0)
+            TextSpan(text: '$warnings⚠', style: const TextStyle(color: Colors.orange)),
+          if (warnings > 0 && errors > 0) const TextSpan(text: ' '),
+          if (errors > 0)
+            TextSpan(text: '$errors✖', style: TextStyle(color: Theme.of(context).colorScheme.error)),
+        ],
+      ),
+      style: style?.copyWith(fontWeight: FontWeight.bold),
+    );
+  }
+
+  Widget _buildTask(BuildContext context, Task task, {int depth = 0}) {
+    final isTaskSelected = task.name == selectedTask?.name && project.tasks.contains(selectedTask);
+    final usage = taskUsage[TaskUtils.getTaskKey(project, task)];
+    final compositeParts = task.type == TaskType.script
+        ? TaskUtils.getCompositeScriptParts(
+            task.command,
+            project.tasks.where((t) => t.type == TaskType.script && t.name != task.name).map((t) => t.name),
+          )
+        : null;
+    return _SidebarRow(
+      id: (section: 'projects', projectPath: project.path, task: task.name),
+      onActivate: () => onTaskSelected(_current(task)),
+      child: Container(
+        decoration: isTaskSelected
+            ? BoxDecoration(
+                color: Theme.of(context).colorScheme.primaryContainer,
+              )
+            : (task.status == TaskStatus.failed
+                ? BoxDecoration(
+                    color: Theme.of(context).colorScheme.errorContainer,
+                  )
+                : null),
+        child: ListTile(
           dense: true,
           leading: Row(
             mainAxisSize: MainAxisSize.min,
             children: [
+              SizedBox(width: AppConstants.spacingXl + depth * AppConstants.taskIndent),
               Icon(
-                Icons.drag_indicator,
-                size: 16,
+                switch (task.type) {
+                TaskType.group => Icons.account_tree_outlined,
+                TaskType.pipeline => Icons.linear_scale,
+                TaskType.make => Icons.construction,
+                TaskType.procfile => Icons.dns_outlined,
+                TaskType.python => Icons.code,
+                TaskType.taskfile => Icons.checklist,
+                TaskType.custom => Icons.terminal,
+                _ => Icons.subdirectory_arrow_right,
+              },
                 color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.3),
               ),
-              Icon(
-                Icons.folder,
-                color: Theme.of(context).colorScheme.primary.withValues(alpha: 0.7),
-              ),
             ],
           ),
-          title: Row(
-            children: [
-              Expanded(
-                child: Column(
-                  crossAxisAlignment: CrossAxisAlignment.start,
-                  children: [
-                    Text(project.name),
-                    Text(
-                      project.path,
-                      style: Theme.of(context).textTheme.bodySmall?.copyWith(
-                            color: Theme.of(context)
-                                .colorScheme
-                                .onSurface
-                                .withValues(alpha: 0.5),
-                          ),
-                      overflow: TextOverflow.ellipsis,
+          title: Tooltip(
+            message: [
+              if (task.alias != null) task.name,
+              if (task.description != null) task.description!,
+              if (task.lastExitCode != null) TaskUtils.describeExitCode(task.lastExitCode!),
+            ].join('\n'),
+            child: Row(
+              children: [
+                Flexible(
+                  child: Text(
+                    task.displayName,
+                    overflow: TextOverflow.ellipsis,
+                    style: TextStyle(
+                      fontWeight:
+                          isTaskSelected ? FontWeight.bold : FontWeight.normal,
+                      color: isTaskSelected
+                          ? Theme.of(context).colorScheme.onPrimaryContainer
+                          : (task.status == TaskStatus.failed
+                              ? Theme.of(context).colorScheme.onErrorContainer
+                              : null),
                     ),
-                  ],
+                  ),
                 ),
-              ),
-            ],
+                if (_manifestOf(task.type) case final manifest?) ...[
+                  const SizedBox(width: AppConstants.spacingS),
+                  _buildManifestBadge(context, manifest),
+                ],
+                if (problemCounts[TaskUtils.getTaskKey(project, task)] case (final errors, final warnings)) ...[
+                  const SizedBox(width: AppConstants.spacingS),
+                  _buildProblemBadge(context, errors, warnings),
+                ],
+              ],
+            ),
           ),
-          trailing: PopupMenuButton<String>(
-            padding: EdgeInsets.zero,
-            icon: const Icon(Icons.more_vert),
-            tooltip: 'Project actions',
-            onSelected: (value) {
-              switch (value) {
-                case 'configure':
-                  onConfigureProject(project);
-                  break;
-                case 'open':
-                  onOpenInExplorer(project);
-                  break;
-                case 'remove':
-                  showDialog(
-                    context: context,
-                    builder: (context) => AlertDialog(
-                      title: const Text('Remove Project'),
-                      content: Text(
-                        'Remove "${project.name}" from the list?\n\nThis will not delete the project files.',
-                      ),
-                      actions: [
-                        TextButton(
-                          onPressed: () => Navigator.of(context).pop(),
-                          child: const Text('Cancel'),
-                        ),
-                        FilledButton(
-                          onPressed: () {
-                            Navigator.of(context).pop();
-                            onRemoveProject(project);
-                          },
-                          child: const Text('Remove'),
-                        ),
-                      ],
-                    ),
-                  );
-                  break;
-              }
-            },
-            itemBuilder: (context) => [
-              const PopupMenuItem(
-                value: 'configure',
-                child: Row(
-                  children: [
-                    Icon(Icons.settings),
-                    SizedBox(width: AppConstants.spacingM),
-                    Text('Configure'),
-                  ],
+          subtitle: _buildTaskSubtitle(
+            task,
+            usage,
+            Theme.of(context).textTheme.bodySmall?.copyWith(
+                  color: isTaskSelected
+                      ? Theme.of(context).colorScheme.onPrimaryContainer.withValues(alpha: 0.7)
+                      : (task.status == TaskStatus.failed
+                          ? Theme.of(context).colorScheme.onErrorContainer.withValues(alpha: 0.7)
+                          : null),
                 ),
-              ),
-              const PopupMenuItem(
-                value: 'open',
-                child: Row(
-                  children: [
-                    Icon(Icons.folder_open),
-                    SizedBox(width: AppConstants.spacingM),
-                    Text('Manage files'),
-                  ],
+          ),
+          trailing: Row(
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              if (!readOnly && compositeParts != null)
+                IconButton(
+                  icon: const Icon(Icons.unfold_more, size: 18),
+                  tooltip: 'Expand into a group of ${compositeParts.join(', ')}',
+                  onPressed: () => onSetScriptGroup(project, _current(task), compositeParts),
+                  padding: EdgeInsets.zero,
                 ),
-              ),
-              const PopupMenuItem(
-                value: 'remove',
-                child: Row(
-                  children: [
-                    Icon(Icons.delete_outline),
-                    SizedBox(width: AppConstants.spacingM),
-                    Text('Remove from list'),
-                  ],
+              if (!readOnly && task.type == TaskType.custom)
+                IconButton(
+                  icon: const Icon(Icons.edit_outlined, size: 18),
+                  tooltip: task.status == TaskStatus.running ? 'Stop the command to edit it' : 'Edit command',
+                  onPressed: task.status == TaskStatus.running
+                      ? null
+                      : () => onEditCustomTask(project, _current(task)),
+                  padding: EdgeInsets.zero,
+                ),
+              if (!readOnly && task.type == TaskType.group)
+                IconButton(
+                  icon: const Icon(Icons.unfold_less, size: 18),
+                  tooltip: 'Collapse group',
+                  onPressed: () => onSetScriptGroup(project, _current(task), null),
+                  padding: EdgeInsets.zero,
+                ),
+              if (task.isStopping)
+                IconButton(
+                  icon: Icon(Icons.dangerous_outlined, color: Theme.of(context).colorScheme.error),
+                  tooltip: 'Kill now',
+                  onPressed: readOnly ? null : () => _killTask(context, _current(task)),
+                  padding: EdgeInsets.zero,
+                )
+              else
+                IconButton(
+                  icon: Icon(
+                    task.status.getIcon(),
+                    color: isTaskSelected
+                        ? Theme.of(context).colorScheme.onPrimaryContainer
+                        : (task.status == TaskStatus.failed
+                            ? Theme.of(context).colorScheme.onErrorContainer
+                            : task.status.getColor(context)),
+                  ),
+                  onPressed: readOnly
+                      ? null
+                      : () {
+                          onTaskToggle(project, _current(task));
+                        },
+                  padding: EdgeInsets.zero,
                 ),
-              ),
             ],
           ),
+          onTap: () => onTaskSelected(_current(task)),
         ),
-        if (project.tasks.isNotEmpty)
-          ...project.tasks.map((task) {
-            final isTaskSelected = task == selectedTask;
-            return Container(
-              decoration: isTaskSelected
-                  ? BoxDecoration(
-                      color: Theme.of(context).colorScheme.primaryContainer,
-                    )
-                  : (task.status == TaskStatus.failed
-                      ? BoxDecoration(
-                          color: Theme.of(context).colorScheme.errorContainer,
-                        )
-                      : null),
-                child: ListTile(
-                  dense: true,
-                  leading: Row(
-                    mainAxisSize: MainAxisSize.min,
+      ),
+    );
+  }
+
+  /// Tasks in the order they're listed, with how deep they're nested.
+  /// Children of a group are listed under it rather than at the top level.
+  static List<(Task, int)> listedTasks(Project project) {
+    final groupedScripts = project.tasks
+        .where((task) => task.type == TaskType.group)
+        .expand((task) => task.children)
+        .toSet();
+
+    return [
+      for (final task in project.tasks.where((task) => !groupedScripts.contains(task.name))) ...[
+        (task, 0),
+        if (task.type == TaskType.group)
+          for (final child in project.tasks.where((child) => task.children.contains(child.name))) (child, 1),
+      ],
+    ];
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return Column(
+      mainAxisSize: MainAxisSize.min,
+      children: [
+        _SidebarRow(
+          id: (section: 'projects', projectPath: project.path, task: null),
+          onActivate: () => onToggleCollapsed(project),
+          onExpand: collapsed ? () => onToggleCollapsed(project) : null,
+          onCollapse: collapsed ? null : () => onToggleCollapsed(project),
+          child: ListTile(
+            dense: true,
+            leading: Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                Icon(
+                  Icons.drag_indicator,
+                  size: 16,
+                  color: Theme.of(context).colorScheme.onSurface.withValues(alpha: readOnly ? 0 : 0.3),
+                ),
+                InkWell(
+                  onTap: () => onToggleCollapsed(project),
+                  child: Tooltip(
+                    message: collapsed ? 'Show tasks' : 'Hide tasks',
+                    child: Icon(
+                      collapsed ? Icons.chevron_right : Icons.expand_more,
+                      size: 16,
+                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
+                    ),
+                  ),
+                ),
+                if (icon != null)
+                  SizedBox(
+                    width: 24,
+                    child: Text(icon!, textAlign: TextAlign.center, style: const TextStyle(fontSize: 18)),
+                  )
+                else
+                  Icon(
+                    Icons.folder,
+                    color: Theme.of(context).colorScheme.primary.withValues(alpha: 0.7),
+                  ),
+              ],
+            ),
+            title: Row(
+              children: [
+                Expanded(
+                  child: Column(
+                    crossAxisAlignment: CrossAxisAlignment.start,
                     children: [
-                      const SizedBox(width: AppConstants.spacingXl),
-                      Icon(
-                        Icons.subdirectory_arrow_right,
-                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.3),
+                      Row(
+                        children: [
+                          Flexible(
+                            child: Text(
+                              project.name,
+                              overflow: TextOverflow.ellipsis,
+                            ),
+                          ),
+                          if (gitStatus != null) ...[
+                            const SizedBox(width: AppConstants.spacingS),
+                            Icon(
+                              Icons.call_split,
+                              size: 12,
+                              color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
+                            ),
+                            const SizedBox(width: 2),
+                            Flexible(
+                              child: Tooltip(
+                                message: gitStatus!.isDetached
+                                    ? 'Detached HEAD at ${gitStatus!.branch}'
+                                    : 'Branch ${gitStatus!.branch}',
+                                child: Text(
+                                  gitStatus!.branch,
+                                  overflow: TextOverflow.ellipsis,
+                                  style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                                        fontFamily: 'monospace',
+                                        fontStyle: gitStatus!.isDetached ? FontStyle.italic : null,
+                                        color: Theme.of(context)
+                                            .colorScheme
+                                            .onSurface
+                                            .withValues(alpha: 0.6),
+                                      ),
+                                ),
+                              ),
+                            ),
+                            if (gitStatus!.isDirty) ...[
+                              const SizedBox(width: AppConstants.spacingXs),
+                              Tooltip(
+                                message: 'Uncommitted changes',
+                                child: Container(
+                                  width: 6,
+                                  height: 6,
+                                  decoration: const BoxDecoration(
+                                    color: Colors.orange,
+                                    shape: BoxShape.circle,
+                                  ),
+                                ),
+                              ),
+                            ],
+                            if (gitStatus!.ahead > 0 || gitStatus!.behind > 0) ...[
+                              const SizedBox(width: AppConstants.spacingXs),
+                              Tooltip(
+                                message: '${gitStatus!.ahead} ahead, ${gitStatus!.behind} behind upstream',
+                                child: Text(
+                                  [
+                                    if (gitStatus!.ahead > 0) '↑${gitStatus!.ahead}',
+                                    if (gitStatus!.behind > 0) '↓${gitStatus!.behind}',
+                                  ].join(' '),
+                                  style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                                        color: Theme.of(context)
+                                            .colorScheme
+                                            .onSurface
+                                            .withValues(alpha: 0.6),
+                                      ),
+                                ),
+                              ),
+                            ],
+                          ],
+                          if (devcontainerStatus != null) ...[
+                            const SizedBox(width: AppConstants.spacingS),
+                            Tooltip(
+                              message: '${devcontainerStatus!.displayName}\n'
+                                  '${(project.docker?.devcontainer ?? false) ? 'Tasks run in it' : 'Tasks run on this computer'}',
+                              child: Icon(
+                                Icons.view_in_ar,
+                                size: 12,
+                                color: devcontainerStatus == DevcontainerStatus.running
+                                    ? Colors.green
+                                    : Theme.of(context).colorScheme.onSurface.withValues(
+                                        alpha: devcontainerStatus == DevcontainerStatus.stopped ? 0.5 : 0.3),
+                              ),
+                            ),
+                          ],
+                        ],
+                      ),
+                      Tooltip(
+                        message: '${project.path}\nClick to copy',
+                        child: InkWell(
+                          onTap: () => UiUtils.copyPath(context, project.path),
+                          child: Text(
+                            project.path,
+                            style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                                  color: Theme.of(context)
+                                      .colorScheme
+                                      .onSurface
+                                      .withValues(alpha: 0.5),
+                                ),
+                            overflow: TextOverflow.ellipsis,
+                          ),
+                        ),
                       ),
+                      if (manifestError != null)
+                        Tooltip(
+                          message: '$manifestError\nShowing the last valid tasks until package.json is fixed.',
+                          child: Container(
+                            margin: const EdgeInsets.only(top: AppConstants.spacingXs),
+                            padding: const EdgeInsets.symmetric(
+                              horizontal: AppConstants.spacingXs,
+                              vertical: 1,
+                            ),
+                            decoration: BoxDecoration(
+                              color: Theme.of(context).colorScheme.errorContainer,
+                              borderRadius: BorderRadius.circular(4),
+                            ),
+                            child: Row(
+                              mainAxisSize: MainAxisSize.min,
+                              children: [
+                                Icon(
+                                  Icons.warning_amber,
+                                  size: 12,
+                                  color: Theme.of(context).colorScheme.onErrorContainer,
+                                ),
+                                const SizedBox(width: AppConstants.spacingXs),
+                                Text(
+                                  'manifest invalid',
+                                  style: Theme.of(context).textTheme.labelSmall?.copyWith(
+                                        color: Theme.of(context).colorScheme.onErrorContainer,
+                                      ),
+                                ),
+                              ],
+                            ),
+                          ),
+                        ),
                     ],
                   ),
-                  title: Text(
-                    task.name,
-                    style: TextStyle(
-                      fontWeight:
-                          isTaskSelected ? FontWeight.bold : FontWeight.normal,
-                      color: isTaskSelected
-                          ? Theme.of(context).colorScheme.onPrimaryContainer
-                          : (task.status == TaskStatus.failed
-                              ? Theme.of(context).colorScheme.onErrorContainer
-                              : null),
-                    ),
-                  ),
-                  subtitle: task.status != TaskStatus.idle &&
-                             (task.status == TaskStatus.running ||
-                              (task.lastExitCode != null && task.lastExitCode != 0))
-                      ? Text(
-                          task.status == TaskStatus.running
-                              ? 'Running...'
-                              : 'Exit: ${task.lastExitCode ?? 'N/A'}',
-                          style: Theme.of(context).textTheme.bodySmall?.copyWith(
-                                color: isTaskSelected
-                                    ? Theme.of(context).colorScheme.onPrimaryContainer.withValues(alpha: 0.7)
-                                    : (task.status == TaskStatus.failed
-                                        ? Theme.of(context).colorScheme.onErrorContainer.withValues(alpha: 0.7)
-                                        : null),
-                              ),
-                        )
-                      : null,
-                  trailing: IconButton(
-                    icon: Icon(
-                      task.status.getIcon(),
-                      color: isTaskSelected
-                          ? Theme.of(context).colorScheme.onPrimaryContainer
-                          : (task.status == TaskStatus.failed
-                              ? Theme.of(context).colorScheme.onErrorContainer
-                              : task.status.getColor(context)),
-                    ),
-                    onPressed: () {
-                      onTaskToggle(project, task);
-                    },
+                ),
+              ],
+            ),
+            trailing: Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                if (!readOnly && project.tasks.any((t) => t.type == TaskType.script))
+                  IconButton(
+                    icon: const Icon(Icons.playlist_play),
+                    tooltip: 'Run all tasks',
+                    onPressed: () => onRunAll(project),
                     padding: EdgeInsets.zero,
                   ),
-                  onTap: () => onTaskSelected(task),
+                PopupMenuButton<String>(
+                  padding: EdgeInsets.zero,
+                  icon: const Icon(Icons.more_vert),
+                  tooltip: 'Project actions',
+                  onSelected: (value) {
+                    switch (value) {
+                      case 'configure':
+                        onConfigureProject(project);
+                        break;
+                      case 'scripts':
+                        onEditScripts(project);
+                        break;
+                      case 'devcontainer':
+                        onToggleDevcontainer(project);
+                        break;
+                      case 'icon':
+                        onSetProjectIcon(project);
+                        break;
+                      case 'command':
+                        onEditCustomTask(project, null);
+                        break;
+                      case 'install':
+                        final install = project.tasks.where((t) => t.type == TaskType.install).firstOrNull;
+                        if (install != null) {
+                          onTaskToggle(project, install);
+                        }
+                        break;
+                      case 'open':
+                        onOpenInExplorer(project);
+                        break;
+                      case 'editor':
+                        onOpenInEditor(project);
+                        break;
+                      case 'terminal':
+                        onOpenTerminal(project);
+                        break;
+                      case 'copy_path':
+                        UiUtils.copyPath(context, project.path);
+                        break;
+                      case 'logs':
+                        onBrowseLogs(project);
+                        break;
+                      case 'dependencies':
+                        onBrowseDependencies(project);
+                        break;
+                      case 'remove':
+                        ConfirmDialog.confirm(
+                          context,
+                          title: 'Remove Project',
+                          message: 'Remove "${project.name}" from the list?\n\nThis will not delete the project files.',
+                          confirmLabel: 'Remove',
+                          ask: PreferencesService().getConfirmRemoveProject,
+                          setAsk: PreferencesService().setConfirmRemoveProject,
+                        ).then((confirmed) {
+                          if (confirmed) {
+                            onRemoveProject(project);
+                          }
+                        });
+                        break;
+                    }
+                  },
+                  // Files of remote projects are out of reach, so is what edits or opens them
+                  itemBuilder: (context) => [
+                    if (!readOnly && !project.isRemote)
+                      const PopupMenuItem(
+                        value: 'configure',
+                        child: Row(
+                          children: [
+                            Icon(Icons.settings),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Configure'),
+                          ],
+                        ),
+                      ),
+                    if (!readOnly && !project.isRemote)
+                      const PopupMenuItem(
+                        value: 'scripts',
+                        child: Row(
+                          children: [
+                            Icon(Icons.edit_note),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Edit scripts'),
+                          ],
+                        ),
+                      ),
+                    if (!readOnly && project.hasDevcontainer)
+                      PopupMenuItem(
+                        value: 'devcontainer',
+                        child: Row(
+                          children: [
+                            const Icon(Icons.view_in_ar),
+                            const SizedBox(width: AppConstants.spacingM),
+                            Text((project.docker?.devcontainer ?? false)
+                                ? 'Run tasks on this computer'
+                                : 'Run tasks in devcontainer'),
+                          ],
+                        ),
+                      ),
+                    if (!readOnly)
+                      const PopupMenuItem(
+                        value: 'icon',
+                        child: Row(
+                          children: [
+                            Icon(Icons.emoji_emotions_outlined),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Set icon'),
+                          ],
+                        ),
+                      ),
+                    if (!readOnly)
+                      const PopupMenuItem(
+                        value: 'command',
+                        child: Row(
+                          children: [
+                            Icon(Icons.add_box_outlined),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Add command'),
+                          ],
+                        ),
+                      ),
+                    if (!readOnly && project.tasks.any((t) => t.type == TaskType.install))
+                      const PopupMenuItem(
+                        value: 'install',
+                        child: Row(
+                          children: [
+                            Icon(Icons.download),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Install dependencies'),
+                          ],
+                        ),
+                      ),
+                    if (!project.isRemote) ...[
+                      PopupMenuItem(
+                        value: 'open',
+                        child: Row(
+                          children: [
+                            const Icon(Icons.folder_open),
+                            const SizedBox(width: AppConstants.spacingM),
+                            Text('Reveal in ${ProcessUtils.fileManagerName}'),
+                          ],
+                        ),
+                      ),
+                      const PopupMenuItem(
+                        value: 'editor',
+                        child: Row(
+                          children: [
+                            Icon(Icons.code),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Open in editor'),
+                          ],
+                        ),
+                      ),
+                      const PopupMenuItem(
+                        value: 'terminal',
+                        child: Row(
+                          children: [
+                            Icon(Icons.terminal),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Open terminal here'),
+                          ],
+                        ),
+                      ),
+                    ],
+                    const PopupMenuItem(
+                      value: 'copy_path',
+                      child: Row(
+                        children: [
+                          Icon(Icons.copy),
+                          SizedBox(width: AppConstants.spacingM),
+                          Text('Copy path'),
+                        ],
+                      ),
+                    ),
+                    const PopupMenuItem(
+                      value: 'logs',
+                      child: Row(
+                        children: [
+                          Icon(Icons.description),
+                          SizedBox(width: AppConstants.spacingM),
+                          Text('Logs'),
+                        ],
+                      ),
+                    ),
+                    if (!project.isRemote)
+                      const PopupMenuItem(
+                        value: 'dependencies',
+                        child: Row(
+                          children: [
+                            Icon(Icons.inventory_2_outlined),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Dependencies'),
+                          ],
+                        ),
+                      ),
+                    if (!readOnly)
+                      const PopupMenuItem(
+                        value: 'remove',
+                        child: Row(
+                          children: [
+                            Icon(Icons.delete_outline),
+                            SizedBox(width: AppConstants.spacingM),
+                            Text('Remove from list'),
+                          ],
+                        ),
+                      ),
+                  ],
                 ),
-              );
-          }),
-        if (project.sites.isEmpty)
+              ],
+            ),
+          ),
+        ),
+        if (!collapsed)
+          for (final (task, depth) in listedTasks(project)) _buildTask(context, task, depth: depth),
+        if (collapsed)
+          const SizedBox.shrink()
+        else if (project.sites.isEmpty)
           ListTile(
             dense: true,
             leading: Row(
@@ -567,7 +1388,7 @@ class _ProjectTile extends StatelessWidget {
                 fontStyle: FontStyle.italic,
               ),
             ),
-            onTap: () => onCreateLaunchTarget(project),
+            onTap: readOnly ? null : () => onCreateLaunchTarget(project),
           )
         else
           ...project.sites.map((target) {
@@ -636,9 +1457,11 @@ class _ProjectTile extends StatelessWidget {
                             ? Theme.of(context).colorScheme.onErrorContainer
                             : target.status.getColor(context)),
                   ),
-                  onPressed: () {
-                    onLaunchToggle(project, target);
-                  },
+                  onPressed: readOnly
+                      ? null
+                      : () {
+                          onLaunchToggle(project, target);
+                        },
                   padding: EdgeInsets.zero,
                 ),
                 onTap: () => onLaunchSelected(target),
@@ -650,6 +1473,212 @@ class _ProjectTile extends StatelessWidget {
   }
 }
 
+/// A row of the sidebar: a project, or a task listed under it or pinned
+/// above the projects in the favorites or recent section
+typedef _SidebarRowId = ({String section, String projectPath, String? task});
+
+/// The scrolling part of the sidebar, filling the rest of it. Knows every
+/// row from the projects and tasks, so the arrow keys can reach rows the
+/// list hasn't built yet.
+class _SidebarNavigation extends StatefulWidget {
+  final List<_SidebarRowId> rows;
+  final Widget child;
+
+  const _SidebarNavigation({required this.rows, required this.child});
+
+  static _SidebarNavigationState? maybeOf(BuildContext context) {
+    return context.dependOnInheritedWidgetOfExactType<_SidebarNavigationScope>()?.navigation;
+  }
+
+  @override
+  State<_SidebarNavigation> createState() => _SidebarNavigationState();
+}
+
+class _SidebarNavigationState extends State<_SidebarNavigation> {
+  // Focus nodes of the rows built so far
+  final Map<_SidebarRowId, FocusNode> _nodes = {};
+
+  void register(_SidebarRowId id, FocusNode node) => _nodes[id] = node;
+
+  void unregister(_SidebarRowId id, FocusNode node) {
+    if (identical(_nodes[id], node)) {
+      _nodes.remove(id);
+    }
+  }
+
+  /// The row [offset] rows below [id], or above when negative
+  _SidebarRowId? rowAt(_SidebarRowId id, int offset) {
+    final index = widget.rows.indexOf(id);
+    final target = index + offset;
+    if (index == -1 || target < 0 || target >= widget.rows.length) {
+      return null;
+    }
+    return widget.rows[target];
+  }
+
+  /// The row of the project a task is listed under, none for pinned tasks
+  _SidebarRowId? projectOf(_SidebarRowId id) {
+    final _SidebarRowId project = (section: id.section, projectPath: id.projectPath, task: null);
+    return widget.rows.contains(project) ? project : null;
+  }
+
+  /// Focus a row and scroll it into view. One that isn't built yet is
+  /// scrolled toward half a screen at a time until it is.
+  void focus(_SidebarRowId id, ScrollPosition? position, {required bool down, int attempts = 20}) {
+    final node = _nodes[id];
+    if (node?.context case final rowContext?) {
+      node!.requestFocus();
+      Scrollable.ensureVisible(
+        rowContext,
+        duration: const Duration(milliseconds: 100),
+        alignmentPolicy:
+            down ? ScrollPositionAlignmentPolicy.keepVisibleAtEnd : ScrollPositionAlignmentPolicy.keepVisibleAtStart,
+      );
+      return;
+    }
+    if (!mounted || position == null || !position.hasPixels || attempts == 0) {
+      return;
+    }
+
+    final step = position.viewportDimension / 2;
+    final offset =
+        (position.pixels + (down ? step : -step)).clamp(position.minScrollExtent, position.maxScrollExtent).toDouble();
+    if (offset == position.pixels) {
+      return;
+    }
+    position.jumpTo(offset);
+    WidgetsBinding.instance.addPostFrameCallback((_) => focus(id, position, down: down, attempts: attempts - 1));
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return Expanded(
+      child: _SidebarNavigationScope(navigation: this, child: widget.child),
+    );
+  }
+}
+
+class _SidebarNavigationScope extends InheritedWidget {
+  final _SidebarNavigationState navigation;
+
+  const _SidebarNavigationScope({required this.navigation, required super.child});
+
+  @override
+  bool updateShouldNotify(_SidebarNavigationScope oldWidget) => navigation != oldWidget.navigation;
+}
+
+/// A project or task row of the sidebar, reached with the arrow keys and
+/// outlined while focused. Up and down move between rows, left collapses a
+/// project or goes from a task to its project, right expands a project or
+/// enters it, and Enter acts like a click.
+class _SidebarRow extends StatefulWidget {
+  final _SidebarRowId id;
+  final VoidCallback onActivate;
+  final VoidCallback? onExpand;
+  final VoidCallback? onCollapse;
+  final Widget child;
+
+  const _SidebarRow({
+    required this.id,
+    required this.onActivate,
+    this.onExpand,
+    this.onCollapse,
+    required this.child,
+  });
+
+  @override
+  State<_SidebarRow> createState() => _SidebarRowState();
+}
+
+class _SidebarRowState extends State<_SidebarRow> {
+  final _focusNode = FocusNode(debugLabel: 'Sidebar row');
+  _SidebarNavigationState? _navigation;
+
+  @override
+  void didChangeDependencies() {
+    super.didChangeDependencies();
+    _navigation?.unregister(widget.id, _focusNode);
+    _navigation = _SidebarNavigation.maybeOf(context)?..register(widget.id, _focusNode);
+  }
+
+  @override
+  void didUpdateWidget(_SidebarRow oldWidget) {
+    super.didUpdateWidget(oldWidget);
+    if (oldWidget.id != widget.id) {
+      _navigation?.unregister(oldWidget.id, _focusNode);
+      _navigation?.register(widget.id, _focusNode);
+    }
+  }
+
+  @override
+  void dispose() {
+    _navigation?.unregister(widget.id, _focusNode);
+    _focusNode.dispose();
+    super.dispose();
+  }
+
+  KeyEventResult _onKeyEvent(FocusNode node, KeyEvent event) {
+    // Outside of a sidebar there are no other rows to move to
+    final navigation = _navigation;
+    if (navigation == null || (event is! KeyDownEvent && event is! KeyRepeatEvent)) {
+      return KeyEventResult.ignored;
+    }
+
+    final id = widget.id;
+    final position = Scrollable.maybeOf(context)?.position;
+    final next = navigation.rowAt(id, 1);
+    final previous = navigation.rowAt(id, -1);
+    switch (event.logicalKey) {
+      case LogicalKeyboardKey.arrowDown:
+        if (next != null) {
+          navigation.focus(next, position, down: true);
+        }
+      case LogicalKeyboardKey.arrowUp:
+        if (previous != null) {
+          navigation.focus(previous, position, down: false);
+        }
+      case LogicalKeyboardKey.arrowRight when id.task == null:
+        if (widget.onExpand case final expand?) {
+          expand();
+        } else if (next != null && next.task != null) {
+          navigation.focus(next, position, down: true);
+        }
+      case LogicalKeyboardKey.arrowLeft when id.task == null:
+        widget.onCollapse?.call();
+      case LogicalKeyboardKey.arrowLeft:
+        if (navigation.projectOf(id) case final project?) {
+          navigation.focus(project, position, down: false);
+        }
+      case LogicalKeyboardKey.enter || LogicalKeyboardKey.numpadEnter when event is KeyDownEvent:
+        widget.onActivate();
+      default:
+        return KeyEventResult.ignored;
+    }
+    return KeyEventResult.handled;
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return Focus(
+      focusNode: _focusNode,
+      onKeyEvent: _onKeyEvent,
+      child: ListenableBuilder(
+        listenable: _focusNode,
+        builder: (context, child) => Container(
+          foregroundDecoration: _focusNode.hasFocus
+              ? BoxDecoration(
+                  border: Border.all(color: Theme.of(context).colorScheme.primary, width: 2),
+                  borderRadius: BorderRadius.circular(4),
+                )
+              : null,
+          child: child,
+        ),
+        child: widget.child,
+      ),
+    );
+  }
+}
+
 class _TrianglePainter extends CustomPainter {
   final Color color;
 
@@ -0,0 +1,265 @@
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../models/project.dart';
+import '../services/editor_service.dart';
+import '../services/log_file_service.dart';
+import '../services/preferences_service.dart';
+import '../utils/task_utils.dart';
+import '../utils/ui_utils.dart';
+import 'terminal_output.dart';
+
+/// Browses the log files saved for a project. Files are read one chunk at a
+/// time so large logs never have to be loaded into memory.
+class LogBrowser extends StatefulWidget {
+  final Project project;
+  final LogFileService logFileService;
+  final PreferencesService preferencesService;
+  final EditorService editorService;
+  final VoidCallback onClose;
+
+  const LogBrowser({
+    super.key,
+    required this.project,
+    required this.logFileService,
+    required this.preferencesService,
+    required this.editorService,
+    required this.onClose,
+  });
+
+  @override
+  State<LogBrowser> createState() => _LogBrowserState();
+}
+
+class _LogBrowserState extends State<LogBrowser> {
+  static const int _chunkSize = 64 * 1024;
+
+  List<LogFileInfo> _logs = [];
+  bool _persistLogs = true;
+  bool _isLoading = true;
+  LogFileInfo? _selectedLog;
+  int _offset = 0;
+  int _size = 0;
+  String _chunk = '';
+
+  @override
+  void initState() {
+    super.initState();
+    _loadLogs();
+  }
+
+  Future<void> _loadLogs() async {
+    final logs = await widget.logFileService.listLogs(widget.project);
+    final persistLogs = await widget.preferencesService.getPersistLogs();
+    if (!mounted) return;
+
+    setState(() {
+      _logs = logs;
+      _persistLogs = persistLogs;
+      _isLoading = false;
+    });
+  }
+
+  Future<void> _openLog(LogFileInfo log) async {
+    // Start at the end of the file, where the latest output is
+    final size = await widget.logFileService.sizeOf(log.path);
+    await _readAt(log, size - _chunkSize);
+  }
+
+  Future<void> _readAt(LogFileInfo log, int offset) async {
+    // The log may still be written to, so its size is looked up every time
+    final size = await widget.logFileService.sizeOf(log.path);
+    final clamped = offset.clamp(0, size > 0 ? size - 1 : 0);
+    final chunk = await widget.logFileService.readChunk(log.path, clamped, _chunkSize);
+    if (!mounted) return;
+
+    setState(() {
+      _selectedLog = log;
+      _offset = clamped;
+      _size = size;
+      _chunk = chunk;
+    });
+  }
+
+  Future<void> _openLocation(String filePath, int? line, int? column) async {
+    final result = await widget.editorService.openLocation(
+      widget.project.path,
+      filePath,
+      line: line,
+      column: column,
+    );
+    if (result.isFailure && mounted) {
+      UiUtils.showErrorSnackbar(context, result.error!);
+    }
+  }
+
+  String _formatSize(int bytes) {
+    if (bytes < 1024) return '$bytes B';
+    if (bytes < 1024 * 1024) return '${(bytes / 1024).toStringAsFixed(1)} KB';
+    return '${(bytes / (1024 * 1024)).toStringAsFixed(1)} MB';
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return Column(
+      crossAxisAlignment: CrossAxisAlignment.start,
+      children: [
+        // Header
+        Container(
+          decoration: BoxDecoration(
+            color: Theme.of(context).colorScheme.primaryContainer,
+            border: Border(
+              bottom: BorderSide(
+                color: Theme.of(context).dividerColor,
+              ),
+            ),
+          ),
+          padding: const EdgeInsets.all(AppConstants.rightPaneContentPadding),
+          child: Row(
+            children: [
+              Icon(
+                Icons.description,
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+              const SizedBox(width: AppConstants.spacingM),
+              Expanded(
+                child: Text(
+                  'Logs of ${widget.project.name}',
+                  style: Theme.of(context).textTheme.titleMedium?.copyWith(
+                        fontWeight: FontWeight.bold,
+                        color: Theme.of(context).colorScheme.onPrimaryContainer,
+                      ),
+                ),
+              ),
+              IconButton(
+                icon: const Icon(Icons.refresh),
+                tooltip: 'Refresh',
+                onPressed: _loadLogs,
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+              IconButton(
+                icon: const Icon(Icons.close),
+                onPressed: widget.onClose,
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+            ],
+          ),
+        ),
+        if (!_persistLogs)
+          Container(
+            width: double.infinity,
+            padding: const EdgeInsets.all(AppConstants.spacingM),
+            color: Theme.of(context).colorScheme.surfaceContainerHighest,
+            child: const Text('Saving logs to disk is disabled. Enable it in Settings to record new runs.'),
+          ),
+        Expanded(
+          child: _isLoading
+              ? const Center(child: CircularProgressIndicator())
+              : Row(
+                  crossAxisAlignment: CrossAxisAlignment.stretch,
+                  children: [
+                    SizedBox(
+                      width: 280,
+                      child: _buildLogList(context),
+                    ),
+                    const VerticalDivider(width: 1),
+                    Expanded(child: _buildViewer(context)),
+                  ],
+                ),
+        ),
+      ],
+    );
+  }
+
+  Widget _buildLogList(BuildContext context) {
+    if (_logs.isEmpty) {
+      return Center(
+        child: Text(
+          'No saved logs',
+          style: Theme.of(context).textTheme.bodyMedium?.copyWith(
+                color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
+              ),
+        ),
+      );
+    }
+
+    return ListView.builder(
+      itemCount: _logs.length,
+      itemBuilder: (context, index) {
+        final log = _logs[index];
+        final isSelected = log.path == _selectedLog?.path;
+        return ListTile(
+          dense: true,
+          selected: isSelected,
+          selectedTileColor: Theme.of(context).colorScheme.primaryContainer,
+          title: Text(log.name, overflow: TextOverflow.ellipsis),
+          subtitle: Text([
+            _formatSize(log.size),
+            if (log.duration != null) TaskUtils.formatDuration(log.duration!),
+          ].join(' · ')),
+          onTap: () => _openLog(log),
+        );
+      },
+    );
+  }
+
+  Widget _buildViewer(BuildContext context) {
+    final log = _selectedLog;
+    if (log == null) {
+      return Center(
+        child: Text(
+          'Select a log file to view it',
+          style: Theme.of(context).textTheme.bodyMedium?.copyWith(
+                color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
+              ),
+        ),
+      );
+    }
+
+    final end = (_offset + _chunkSize).clamp(0, _size);
+    return Column(
+      children: [
+        Padding(
+          padding: const EdgeInsets.symmetric(horizontal: AppConstants.spacingS),
+          child: Row(
+            children: [
+              Text(
+                'Bytes $_offset–$end of $_size',
+                style: Theme.of(context).textTheme.bodySmall,
+              ),
+              const Spacer(),
+              IconButton(
+                icon: const Icon(Icons.first_page),
+                tooltip: 'Start',
+                onPressed: _offset > 0 ? () => _readAt(log, 0) : null,
+              ),
+              IconButton(
+                icon: const Icon(Icons.chevron_left),
+                tooltip: 'Earlier',
+                onPressed: _offset > 0 ? () => _readAt(log, _offset - _chunkSize) : null,
+              ),
+              IconButton(
+                icon: const Icon(Icons.chevron_right),
+                tooltip: 'Later',
+                onPressed: end < _size ? () => _readAt(log, _offset + _chunkSize) : null,
+              ),
+              // Always there, to catch up with a log still being written
+              IconButton(
+                icon: const Icon(Icons.last_page),
+                tooltip: 'End',
+                onPressed: () => _openLog(log),
+              ),
+            ],
+          ),
+        ),
+        Expanded(
+          child: TerminalOutput(
+            output: _chunk,
+            autoScroll: false,
+            onLocationTap: _openLocation,
+          ),
+        ),
+      ],
+    );
+  }
+}
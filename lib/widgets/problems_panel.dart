@@ -0,0 +1,96 @@
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../models/problem.dart';
+
+/// Errors and warnings found in a task's output, each opening its location
+/// in the editor
+class ProblemsPanel extends StatefulWidget {
+  final List<Problem> problems;
+  final void Function(Problem problem) onOpen;
+
+  const ProblemsPanel({
+    super.key,
+    required this.problems,
+    required this.onOpen,
+  });
+
+  @override
+  State<ProblemsPanel> createState() => _ProblemsPanelState();
+}
+
+class _ProblemsPanelState extends State<ProblemsPanel> {
+  bool _expanded = true;
+
+  @override
+  Widget build(BuildContext context) {
+    final errors = widget.problems.where((problem) => !problem.isWarning).length;
+    final warnings = widget.problems.length - errors;
+    final muted = Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7);
+
+    return Container(
+      decoration: BoxDecoration(
+        border: Border(
+          bottom: BorderSide(color: Theme.of(context).dividerColor),
+        ),
+      ),
+      child: Column(
+        crossAxisAlignment: CrossAxisAlignment.start,
+        children: [
+          InkWell(
+            onTap: () => setState(() => _expanded = !_expanded),
+            child: Padding(
+              padding: const EdgeInsets.symmetric(
+                horizontal: AppConstants.spacingM,
+                vertical: AppConstants.spacingXs,
+              ),
+              child: Row(
+                children: [
+                  Icon(_expanded ? Icons.expand_more : Icons.chevron_right, size: 18),
+                  const SizedBox(width: AppConstants.spacingXs),
+                  Text('Problems', style: Theme.of(context).textTheme.titleSmall),
+                  const SizedBox(width: AppConstants.spacingS),
+                  if (errors > 0) ...[
+                    Icon(Icons.error, size: 14, color: Theme.of(context).colorScheme.error),
+                    const SizedBox(width: 2),
+                    Text('$errors', style: Theme.of(context).textTheme.bodySmall),
+                    const SizedBox(width: AppConstants.spacingS),
+                  ],
+                  if (warnings > 0) ...[
+                    const Icon(Icons.warning, size: 14, color: Colors.orange),
+                    const SizedBox(width: 2),
+                    Text('$warnings', style: Theme.of(context).textTheme.bodySmall),
+                  ],
+                ],
+              ),
+            ),
+          ),
+          if (_expanded)
+            ConstrainedBox(
+              constraints: const BoxConstraints(maxHeight: 160),
+              child: ListView.builder(
+                shrinkWrap: true,
+                itemCount: widget.problems.length,
+                itemBuilder: (context, index) {
+                  final problem = widget.problems[index];
+                  return ListTile(
+                    dense: true,
+                    visualDensity: VisualDensity.compact,
+                    leading: problem.isWarning
+                        ? const Icon(Icons.warning, size: 16, color: Colors.orange)
+                        : Icon(Icons.error, size: 16, color: Theme.of(context).colorScheme.error),
+                    title: Text(problem.message, maxLines: 1, overflow: TextOverflow.ellipsis),
+                    subtitle: Text(
+                      problem.location,
+                      style: Theme.of(context).textTheme.bodySmall?.copyWith(color: muted),
+                    ),
+                    onTap: () => widget.onOpen(problem),
+                  );
+                },
+              ),
+            ),
+        ],
+      ),
+    );
+  }
+}
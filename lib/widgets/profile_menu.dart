@@ -0,0 +1,184 @@
+import 'package:flutter/material.dart';
+import 'package:provider/provider.dart';
+
+import '../config/constants.dart';
+import '../providers/project_provider.dart';
+
+// A profile to switch to, null for the default one, or a new one to name
+typedef _ProfileChoice = ({String? profile, bool create});
+
+/// Switches between profiles, each with its own projects and settings
+class ProfileMenu extends StatelessWidget {
+  const ProfileMenu({super.key});
+
+  Future<void> _select(BuildContext context, _ProfileChoice choice) async {
+    final provider = context.read<ProjectProvider>();
+
+    var profile = choice.profile;
+    if (choice.create) {
+      profile = await showDialog<String>(
+        context: context,
+        builder: (_) => _NewProfileDialog(existing: provider.profiles),
+      );
+      if (profile == null || profile.isEmpty) return;
+    }
+    if (profile == provider.profile) return;
+
+    // Tasks belong to the projects of the profile being left
+    final runningTasks = provider.runningTaskCount;
+    if (runningTasks > 0) {
+      if (!context.mounted) return;
+      final tasks = runningTasks == 1 ? '1 task is' : '$runningTasks tasks are';
+      final confirmed = await showDialog<bool>(
+        context: context,
+        builder: (context) => AlertDialog(
+          title: Text('Switch to ${profile ?? 'Default'}?'),
+          content: Text('$tasks still running. Switching profiles stops them.'),
+          actions: [
+            TextButton(
+              onPressed: () => Navigator.of(context).pop(),
+              child: const Text('Cancel'),
+            ),
+            FilledButton(
+              onPressed: () => Navigator.of(context).pop(true),
+              child: const Text('Stop tasks and switch'),
+            ),
+          ],
+        ),
+      );
+      if (confirmed != true) return;
+    }
+
+    await provider.switchProfile(profile);
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final (profile, profiles, readOnly) =
+        context.select((ProjectProvider p) => (p.profile, p.profiles, p.isReadOnly));
+
+    return PopupMenuButton<_ProfileChoice>(
+      // Read-only is set per profile, switching would get out of it
+      enabled: !readOnly,
+      tooltip: readOnly ? 'Profiles can\'t be switched in read-only mode' : 'Switch profile',
+      onSelected: (choice) => _select(context, choice),
+      itemBuilder: (context) => [
+        CheckedPopupMenuItem(
+          value: (profile: null, create: false),
+          checked: profile == null,
+          child: const Text('Default'),
+        ),
+        for (final name in profiles)
+          CheckedPopupMenuItem(
+            value: (profile: name, create: false),
+            checked: profile == name,
+            child: Text(name),
+          ),
+        const PopupMenuDivider(),
+        const PopupMenuItem(
+          value: (profile: null, create: true),
+          child: Row(
+            children: [
+              Icon(Icons.add),
+              SizedBox(width: AppConstants.spacingM),
+              Text('New profile…'),
+            ],
+          ),
+        ),
+      ],
+      child: Row(
+        mainAxisSize: MainAxisSize.min,
+        children: [
+          Text(
+            profile ?? 'Default',
+            style: Theme.of(context).textTheme.labelMedium?.copyWith(
+                  color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                ),
+          ),
+          Icon(
+            Icons.arrow_drop_down,
+            size: 18,
+            color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+          ),
+        ],
+      ),
+    );
+  }
+}
+
+/// Names a new profile. Pops the name, or null when cancelled.
+class _NewProfileDialog extends StatefulWidget {
+  final List<String> existing;
+
+  const _NewProfileDialog({required this.existing});
+
+  @override
+  State<_NewProfileDialog> createState() => _NewProfileDialogState();
+}
+
+class _NewProfileDialogState extends State<_NewProfileDialog> {
+  final _nameController = TextEditingController();
+  String? _error;
+
+  @override
+  void dispose() {
+    _nameController.dispose();
+    super.dispose();
+  }
+
+  void _create() {
+    final name = _nameController.text.trim();
+    if (name.isEmpty || name == 'Default' || widget.existing.contains(name)) {
+      setState(() {
+        _error = name.isEmpty ? 'Enter a name' : 'A profile with this name already exists';
+      });
+      return;
+    }
+    Navigator.of(context).pop(name);
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: const Text('New profile'),
+      content: SizedBox(
+        width: 400,
+        child: Column(
+          crossAxisAlignment: CrossAxisAlignment.start,
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            TextField(
+              controller: _nameController,
+              autofocus: true,
+              decoration: InputDecoration(
+                labelText: 'Name',
+                hintText: 'work',
+                errorText: _error,
+                border: const OutlineInputBorder(),
+                isDense: true,
+              ),
+              onSubmitted: (_) => _create(),
+            ),
+            const SizedBox(height: AppConstants.spacingS),
+            Text(
+              'Starts with no projects and default settings. API keys are shared by all profiles.',
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                    color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                  ),
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _create,
+          child: const Text('Create'),
+        ),
+      ],
+    );
+  }
+}
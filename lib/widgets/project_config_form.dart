@@ -1,9 +1,28 @@
+import 'dart:io';
+
 import 'package:flutter/material.dart';
 import 'package:url_launcher/url_launcher.dart';
 
 import '../config/constants.dart';
+import '../models/install_script_dependency.dart';
 import '../models/project.dart';
+import '../services/preferences_service.dart';
 import '../services/project_service.dart';
+import '../services/secret_store.dart';
+import '../utils/env_utils.dart';
+import '../utils/wsl_utils.dart';
+
+/// Where the tasks of a project run
+enum _Backend {
+  local('This computer'),
+  image('New container'),
+  container('Running container'),
+  devcontainer('Devcontainer');
+
+  final String displayName;
+
+  const _Backend(this.displayName);
+}
 
 class _LaunchTargetEntry {
   final String id;
@@ -40,6 +59,7 @@ class TaskEntry {
   final String id;
   String name;
   String command;
+  List<String> dependsOn;
 
   static int _idCounter = 0;
 
@@ -47,9 +67,37 @@ class TaskEntry {
     String? id,
     required this.name,
     required this.command,
+    this.dependsOn = const [],
   }) : id = id ?? 'task_${_idCounter++}';
 }
 
+class _PipelineEntry {
+  final String id;
+  String name;
+  List<String> steps;
+
+  static int _idCounter = 0;
+
+  _PipelineEntry({
+    String? id,
+    required this.name,
+    required this.steps,
+  }) : id = id ?? 'pipeline_${_idCounter++}';
+}
+
+class _SecretEntry {
+  final String id;
+  String name;
+  String value;
+
+  static int _idCounter = 0;
+
+  _SecretEntry({
+    required this.name,
+    required this.value,
+  }) : id = 'secret_${_idCounter++}';
+}
+
 class ProjectConfigForm extends StatefulWidget {
   final Project project;
   final ProjectService projectService;
@@ -71,7 +119,28 @@ class ProjectConfigForm extends StatefulWidget {
 class _ProjectConfigFormState extends State<ProjectConfigForm> {
   List<TaskEntry> _tasks = [];
   List<_LaunchTargetEntry> _sites = [];
+  List<_PipelineEntry> _pipelines = [];
+  List<_SecretEntry> _secrets = [];
+  // Secrets stored when the form loaded, to delete those removed
+  List<String> _storedSecrets = [];
   final _launchDirectoryController = TextEditingController();
+  final _environmentController = TextEditingController();
+  final _envFilesController = TextEditingController();
+  bool _loadEnvFiles = true;
+  final _watchIncludeController = TextEditingController();
+  final _watchExcludeController = TextEditingController();
+  bool _watchGitignore = true;
+  Map<String, String> _inheritedEnvironment = {};
+  bool _frozenLockfile = false;
+  bool _ignoreScripts = false;
+  InstallPolicy _installPolicy = InstallPolicy.never;
+  _Backend _backend = _Backend.local;
+  final _dockerImageController = TextEditingController();
+  final _dockerContainerController = TextEditingController();
+  final _dockerWorkdirController = TextEditingController();
+  bool _runInWsl = false;
+  final _wslDistroController = TextEditingController();
+  List<InstallScriptDependency> _installScriptDependencies = [];
   bool _isLoading = true;
   String? _error;
 
@@ -93,6 +162,14 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
   @override
   void dispose() {
     _launchDirectoryController.dispose();
+    _environmentController.dispose();
+    _envFilesController.dispose();
+    _watchIncludeController.dispose();
+    _watchExcludeController.dispose();
+    _dockerImageController.dispose();
+    _dockerContainerController.dispose();
+    _dockerWorkdirController.dispose();
+    _wslDistroController.dispose();
     super.dispose();
   }
 
@@ -100,18 +177,77 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
     try {
       final packageJson = await widget.projectService.readPackageJson(widget.project.path);
 
-      // Load tasks from scripts
+      // Load launch configuration from bob
+      final bob = packageJson['bob'] as Map<String, dynamic>?;
+
+      // Load tasks from scripts, with their dependencies from bob.dependsOn
       final scripts = packageJson['scripts'] as Map<String, dynamic>? ?? {};
+      final dependencies = bob?['dependsOn'] as Map<String, dynamic>? ?? {};
       _tasks = scripts.entries
-          .map((e) => TaskEntry(name: e.key, command: e.value as String))
+          .map((e) => TaskEntry(
+                name: e.key,
+                command: e.value as String,
+                dependsOn: (dependencies[e.key] as List<dynamic>? ?? []).whereType<String>().toList(),
+              ))
           .toList();
 
-      // Load launch configuration from bob
-      final bob = packageJson['bob'] as Map<String, dynamic>?;
-
       // Load launch directory
       final directory = bob?['directory'] as String? ?? '';
       _launchDirectoryController.text = directory;
+      _frozenLockfile = bob?['frozenLockfile'] as bool? ?? false;
+      _ignoreScripts = bob?['ignoreScripts'] as bool? ?? false;
+      _installPolicy = InstallPolicy.values.where((policy) => policy.name == bob?['install']).firstOrNull ??
+          InstallPolicy.never;
+      _installScriptDependencies =
+          await widget.projectService.findInstallScriptDependencies(widget.project.path);
+
+      // Load the Docker backend from bob.docker
+      final docker = DockerConfig.fromJson(bob?['docker'] as Map<String, dynamic>?);
+      _backend = switch (docker) {
+        null => _Backend.local,
+        DockerConfig(devcontainer: true) => _Backend.devcontainer,
+        DockerConfig(container: _?) => _Backend.container,
+        _ => _Backend.image,
+      };
+      _dockerImageController.text = docker?.image ?? '';
+      _dockerContainerController.text = docker?.container ?? '';
+      _dockerWorkdirController.text = docker?.workdir ?? DockerConfig.defaultWorkdir;
+      _runInWsl = bob?['wsl'] as bool? ?? WslUtils.isWslPath(widget.project.path);
+      _wslDistroController.text = bob?['wslDistro'] as String? ?? '';
+
+      // Load pipelines from bob.pipelines
+      final pipelines = bob?['pipelines'] as Map<String, dynamic>? ?? {};
+      _pipelines = pipelines.entries
+          .map((e) => _PipelineEntry(
+                name: e.key,
+                steps: (e.value as List<dynamic>? ?? []).whereType<String>().toList(),
+              ))
+          .toList();
+
+      // Load environment variables from bob.env, and those inherited from workspaces
+      final environment = bob?['env'] as Map<String, dynamic>? ?? {};
+      _environmentController.text =
+          EnvUtils.format(environment.map((key, value) => MapEntry(key, value.toString())));
+      _storedSecrets = (bob?['secretEnv'] as List<dynamic>? ?? []).whereType<String>().toList();
+      final secretValues = await SecretStore().readAll(widget.project.path, _storedSecrets);
+      _secrets = [
+        for (final name in _storedSecrets) _SecretEntry(name: name, value: secretValues[name] ?? ''),
+      ];
+      _loadEnvFiles = bob?['loadEnvFiles'] as bool? ?? true;
+      _envFilesController.text =
+          (bob?['envFiles'] as List<dynamic>? ?? []).whereType<String>().join(', ');
+      // Load file watching configuration from bob.watch
+      final watch = bob?['watch'] as Map<String, dynamic>? ?? {};
+      _watchIncludeController.text =
+          (watch['include'] as List<dynamic>? ?? []).whereType<String>().join(', ');
+      _watchExcludeController.text =
+          (watch['exclude'] as List<dynamic>? ?? []).whereType<String>().join(', ');
+      _watchGitignore = watch['gitignore'] as bool? ?? true;
+
+      _inheritedEnvironment = EnvUtils.inheritedFor(
+        widget.project.path,
+        await PreferencesService().getWorkspaceEnvironments(),
+      );
 
       // Load sites from bob.sites
       final sites = bob?['sites'] as Map<String, dynamic>? ?? {};
@@ -130,6 +266,14 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
     }
   }
 
+  List<String> _splitList(String text) {
+    return text
+        .split(',')
+        .map((item) => item.trim())
+        .where((item) => item.isNotEmpty)
+        .toList();
+  }
+
   Future<void> _saveConfiguration() async {
     try {
       setState(() {
@@ -141,9 +285,13 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
 
       // Update scripts
       final scripts = <String, String>{};
+      final dependencies = <String, List<String>>{};
       for (final task in _tasks) {
         if (task.name.isNotEmpty && task.command.isNotEmpty) {
           scripts[task.name] = task.command;
+          if (task.dependsOn.isNotEmpty) {
+            dependencies[task.name] = task.dependsOn;
+          }
         }
       }
       packageJson['scripts'] = scripts;
@@ -157,15 +305,122 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
         }
       }
 
-      // Build bob object
-      if (directory.isNotEmpty || sites.isNotEmpty) {
-        final bob = <String, dynamic>{};
-        if (directory.isNotEmpty) {
-          bob['directory'] = directory;
-        }
-        if (sites.isNotEmpty) {
-          bob['sites'] = sites;
-        }
+      // Update bob object, keeping settings this form doesn't edit such as groups
+      final bob = packageJson['bob'] as Map<String, dynamic>? ?? {};
+      if (directory.isNotEmpty) {
+        bob['directory'] = directory;
+      } else {
+        bob.remove('directory');
+      }
+      if (sites.isNotEmpty) {
+        bob['sites'] = sites;
+      } else {
+        bob.remove('sites');
+      }
+      if (_frozenLockfile) {
+        bob['frozenLockfile'] = true;
+      } else {
+        bob.remove('frozenLockfile');
+      }
+      if (_ignoreScripts) {
+        bob['ignoreScripts'] = true;
+      } else {
+        bob.remove('ignoreScripts');
+      }
+      if (_installPolicy != InstallPolicy.never) {
+        bob['install'] = _installPolicy.name;
+      } else {
+        bob.remove('install');
+      }
+      final dockerImage = _dockerImageController.text.trim();
+      final dockerContainer = _dockerContainerController.text.trim();
+      final dockerWorkdir = _dockerWorkdirController.text.trim().isNotEmpty
+          ? _dockerWorkdirController.text.trim()
+          : DockerConfig.defaultWorkdir;
+      final docker = switch (_backend) {
+        _Backend.image when dockerImage.isNotEmpty => DockerConfig(image: dockerImage, workdir: dockerWorkdir),
+        _Backend.container when dockerContainer.isNotEmpty =>
+          DockerConfig(container: dockerContainer, workdir: dockerWorkdir),
+        _Backend.devcontainer => const DockerConfig(devcontainer: true),
+        _ => null,
+      };
+      if (docker != null) {
+        bob['docker'] = docker.toJson();
+      } else {
+        bob.remove('docker');
+      }
+      // Projects in WSL run there unless told otherwise
+      if (_runInWsl != WslUtils.isWslPath(widget.project.path)) {
+        bob['wsl'] = _runInWsl;
+      } else {
+        bob.remove('wsl');
+      }
+      final wslDistro = _wslDistroController.text.trim();
+      if (_runInWsl && wslDistro.isNotEmpty) {
+        bob['wslDistro'] = wslDistro;
+      } else {
+        bob.remove('wslDistro');
+      }
+      if (dependencies.isNotEmpty) {
+        bob['dependsOn'] = dependencies;
+      } else {
+        bob.remove('dependsOn');
+      }
+      final pipelines = {
+        for (final pipeline in _pipelines)
+          if (pipeline.name.isNotEmpty && pipeline.steps.isNotEmpty) pipeline.name: pipeline.steps,
+      };
+      if (pipelines.isNotEmpty) {
+        bob['pipelines'] = pipelines;
+      } else {
+        bob.remove('pipelines');
+      }
+      final environment = EnvUtils.parse(_environmentController.text);
+      if (environment.isNotEmpty) {
+        bob['env'] = environment;
+      } else {
+        bob.remove('env');
+      }
+      final secrets = {
+        for (final secret in _secrets)
+          if (secret.name.trim().isNotEmpty) secret.name.trim(): secret.value,
+      };
+      for (final MapEntry(:key, :value) in secrets.entries) {
+        await SecretStore().write(widget.project.path, key, value);
+      }
+      for (final name in _storedSecrets.where((name) => !secrets.containsKey(name))) {
+        await SecretStore().delete(widget.project.path, name);
+      }
+      _storedSecrets = secrets.keys.toList();
+      if (secrets.isNotEmpty) {
+        bob['secretEnv'] = secrets.keys.toList();
+      } else {
+        bob.remove('secretEnv');
+      }
+      if (_loadEnvFiles) {
+        bob.remove('loadEnvFiles');
+      } else {
+        bob['loadEnvFiles'] = false;
+      }
+      final envFiles = _splitList(_envFilesController.text);
+      if (envFiles.isNotEmpty) {
+        bob['envFiles'] = envFiles;
+      } else {
+        bob.remove('envFiles');
+      }
+      final watchInclude = _splitList(_watchIncludeController.text);
+      final watchExclude = _splitList(_watchExcludeController.text);
+      final watch = {
+        if (watchInclude.isNotEmpty) 'include': watchInclude,
+        if (watchExclude.isNotEmpty) 'exclude': watchExclude,
+        if (!_watchGitignore) 'gitignore': false,
+      };
+      if (watch.isNotEmpty) {
+        bob['watch'] = watch;
+      } else {
+        bob.remove('watch');
+      }
+      if (bob.isNotEmpty) {
         packageJson['bob'] = bob;
       } else {
         packageJson.remove('bob');
@@ -229,6 +484,30 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
     });
   }
 
+  void _addPipeline() {
+    setState(() {
+      _pipelines.add(_PipelineEntry(name: '', steps: []));
+    });
+  }
+
+  void _addSecret() {
+    setState(() {
+      _secrets.add(_SecretEntry(name: '', value: ''));
+    });
+  }
+
+  void _removeSecret(int index) {
+    setState(() {
+      _secrets.removeAt(index);
+    });
+  }
+
+  void _removePipeline(int index) {
+    setState(() {
+      _pipelines.removeAt(index);
+    });
+  }
+
   void _reorderLaunchTargets(int oldIndex, int newIndex) {
     setState(() {
       if (newIndex > oldIndex) {
@@ -327,6 +606,23 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
                 const SizedBox(height: AppConstants.spacingM),
                 _buildTasksList(),
                 const SizedBox(height: AppConstants.spacingXl),
+                // Pipelines section
+                _buildSectionHeader(
+                  context,
+                  'Pipelines',
+                  Icons.linear_scale,
+                  _addPipeline,
+                ),
+                const SizedBox(height: AppConstants.spacingS),
+                Text(
+                  'Tasks run one after the other, stopping at the first failure.',
+                  style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingM),
+                _buildPipelinesList(),
+                const SizedBox(height: AppConstants.spacingXl),
                 // Launch directory section
                 Text(
                   'Uploaded Directory',
@@ -353,6 +649,237 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
                   onSubmitted: (_) => _saveConfiguration(),
                 ),
                 const SizedBox(height: AppConstants.spacingXl),
+                // Docker section
+                Text(
+                  'Run tasks in',
+                  style: Theme.of(context).textTheme.titleMedium?.copyWith(
+                        fontWeight: FontWeight.bold,
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingS),
+                Text(
+                  'Run tasks in Docker to use the toolchains of an image without installing them. '
+                  'A new container gets the project folder mounted; a running container needs it mounted already. '
+                  'The devcontainer of .devcontainer/devcontainer.json is built and started when a task needs it.',
+                  style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingM),
+                SegmentedButton<_Backend>(
+                  segments: _Backend.values
+                      .where((backend) =>
+                          backend != _Backend.devcontainer || widget.project.hasDevcontainer || _backend == backend)
+                      .map((backend) => ButtonSegment(value: backend, label: Text(backend.displayName)))
+                      .toList(),
+                  selected: {_backend},
+                  onSelectionChanged: (selection) {
+                    setState(() {
+                      _backend = selection.first;
+                    });
+                  },
+                ),
+                if (_backend == _Backend.image || _backend == _Backend.container) ...[
+                  const SizedBox(height: AppConstants.spacingM),
+                  TextField(
+                    controller: _backend == _Backend.image ? _dockerImageController : _dockerContainerController,
+                    decoration: InputDecoration(
+                      border: const OutlineInputBorder(),
+                      labelText: _backend == _Backend.image ? 'Image' : 'Container',
+                      hintText: _backend == _Backend.image ? 'oven/bun:1' : 'Name or ID',
+                    ),
+                  ),
+                  const SizedBox(height: AppConstants.spacingM),
+                  TextField(
+                    controller: _dockerWorkdirController,
+                    decoration: const InputDecoration(
+                      border: OutlineInputBorder(),
+                      labelText: 'Project folder in the container',
+                      hintText: DockerConfig.defaultWorkdir,
+                    ),
+                  ),
+                ],
+                if (Platform.isWindows && _backend == _Backend.local) ...[
+                  SwitchListTile(
+                    contentPadding: EdgeInsets.zero,
+                    title: const Text('Run in WSL'),
+                    subtitle: const Text(
+                      'Run tasks with the bun of a WSL distribution, which should have bun installed. '
+                      'The Windows bun can\'t run projects kept in WSL.',
+                    ),
+                    value: _runInWsl,
+                    onChanged: (value) {
+                      setState(() {
+                        _runInWsl = value;
+                      });
+                    },
+                  ),
+                  if (_runInWsl)
+                    TextField(
+                      controller: _wslDistroController,
+                      decoration: InputDecoration(
+                        border: const OutlineInputBorder(),
+                        labelText: 'Distribution',
+                        hintText: WslUtils.distroOf(widget.project.path) ?? 'Default distribution',
+                      ),
+                    ),
+                ],
+                const SizedBox(height: AppConstants.spacingXl),
+                // Install section
+                Text(
+                  'Install',
+                  style: Theme.of(context).textTheme.titleMedium?.copyWith(
+                        fontWeight: FontWeight.bold,
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingS),
+                Text(
+                  'When to install dependencies before starting a script. The install task can always be run on its own.',
+                  style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingM),
+                SegmentedButton<InstallPolicy>(
+                  segments: InstallPolicy.values
+                      .map((policy) => ButtonSegment(value: policy, label: Text(policy.displayName)))
+                      .toList(),
+                  selected: {_installPolicy},
+                  onSelectionChanged: (selection) {
+                    setState(() {
+                      _installPolicy = selection.first;
+                    });
+                  },
+                ),
+                SwitchListTile(
+                  contentPadding: EdgeInsets.zero,
+                  title: const Text('Frozen lockfile'),
+                  subtitle: const Text(
+                    'Install with --frozen-lockfile so bun.lock is never modified. Fails when it is out of date with package.json.',
+                  ),
+                  value: _frozenLockfile,
+                  onChanged: (value) {
+                    setState(() {
+                      _frozenLockfile = value;
+                    });
+                  },
+                ),
+                SwitchListTile(
+                  contentPadding: EdgeInsets.zero,
+                  title: const Text('Ignore install scripts'),
+                  subtitle: const Text(
+                    'Install with --ignore-scripts so no lifecycle scripts run, including the project\'s own. '
+                    'Safer, but packages that build native code or download binaries may not work.',
+                  ),
+                  value: _ignoreScripts,
+                  onChanged: (value) {
+                    setState(() {
+                      _ignoreScripts = value;
+                    });
+                  },
+                ),
+                _buildInstallScriptDependencies(),
+                const SizedBox(height: AppConstants.spacingXl),
+                // Environment section
+                Text(
+                  'Environment',
+                  style: Theme.of(context).textTheme.titleMedium?.copyWith(
+                        fontWeight: FontWeight.bold,
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingS),
+                Text(
+                  'Variables set for every task, one KEY=value per line. '
+                  'They override variables inherited from workspaces in Settings.',
+                  style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingM),
+                TextField(
+                  controller: _environmentController,
+                  decoration: const InputDecoration(
+                    border: OutlineInputBorder(),
+                    labelText: 'Variables',
+                    hintText: 'API_URL=http://localhost:3000',
+                  ),
+                  style: const TextStyle(fontFamily: 'monospace'),
+                  minLines: 2,
+                  maxLines: 8,
+                  onChanged: (_) => setState(() {}),
+                ),
+                _buildInheritedEnvironment(),
+                const SizedBox(height: AppConstants.spacingM),
+                _buildSecretsList(),
+                SwitchListTile(
+                  contentPadding: EdgeInsets.zero,
+                  title: const Text('Load env files'),
+                  subtitle: const Text(
+                    'Read .env, then .env.local, then the files below. Later files win, '
+                    'variables above win over all of them, and none replace variables already set in the system.',
+                  ),
+                  value: _loadEnvFiles,
+                  onChanged: (value) {
+                    setState(() {
+                      _loadEnvFiles = value;
+                    });
+                  },
+                ),
+                if (_loadEnvFiles)
+                  TextField(
+                    controller: _envFilesController,
+                    decoration: const InputDecoration(
+                      border: OutlineInputBorder(),
+                      labelText: 'More env files',
+                      hintText: '.env.development, config/.env',
+                    ),
+                  ),
+                const SizedBox(height: AppConstants.spacingXl),
+                // File watching section
+                Text(
+                  'File watching',
+                  style: Theme.of(context).textTheme.titleMedium?.copyWith(
+                        fontWeight: FontWeight.bold,
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingS),
+                Text(
+                  'Which changes restart tasks that restart on file change. '
+                  'Comma-separated globs relative to the project; node_modules and .git are never watched.',
+                  style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                        color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                      ),
+                ),
+                const SizedBox(height: AppConstants.spacingM),
+                TextField(
+                  controller: _watchIncludeController,
+                  decoration: const InputDecoration(
+                    border: OutlineInputBorder(),
+                    labelText: 'Only watch',
+                    hintText: 'src/**, *.config.ts',
+                    helperText: 'Leave empty to watch every file',
+                  ),
+                ),
+                const SizedBox(height: AppConstants.spacingM),
+                TextField(
+                  controller: _watchExcludeController,
+                  decoration: const InputDecoration(
+                    border: OutlineInputBorder(),
+                    labelText: 'Ignore',
+                    hintText: 'dist/, *.log',
+                  ),
+                ),
+                SwitchListTile(
+                  contentPadding: EdgeInsets.zero,
+                  title: const Text('Ignore files in .gitignore'),
+                  value: _watchGitignore,
+                  onChanged: (value) {
+                    setState(() {
+                      _watchGitignore = value;
+                    });
+                  },
+                ),
+                const SizedBox(height: AppConstants.spacingXl),
                 // Launch targets section
                 _buildSectionHeader(
                   context,
@@ -412,6 +939,217 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
     );
   }
 
+  Widget _buildSecretsList() {
+    return Column(
+      crossAxisAlignment: CrossAxisAlignment.start,
+      children: [
+        Row(
+          children: [
+            Expanded(
+              child: Text(
+                'Secret variables are kept in the keychain of this computer, not in package.json, '
+                'which only lists their names. Other computers set their own values.',
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                    ),
+              ),
+            ),
+            TextButton.icon(
+              icon: const Icon(Icons.key, size: 18),
+              label: const Text('Add secret'),
+              onPressed: _addSecret,
+            ),
+          ],
+        ),
+        for (final (index, secret) in _secrets.indexed)
+          Padding(
+            key: ValueKey(secret.id),
+            padding: const EdgeInsets.only(top: AppConstants.spacingS),
+            child: Row(
+              children: [
+                Expanded(
+                  child: TextField(
+                    decoration: const InputDecoration(
+                      labelText: 'Name',
+                      hintText: 'API_KEY',
+                      border: OutlineInputBorder(),
+                      isDense: true,
+                    ),
+                    controller: TextEditingController(text: secret.name)
+                      ..selection = TextSelection.collapsed(offset: secret.name.length),
+                    style: const TextStyle(fontFamily: 'monospace'),
+                    onChanged: (value) {
+                      secret.name = value;
+                    },
+                  ),
+                ),
+                const SizedBox(width: AppConstants.spacingM),
+                Expanded(
+                  flex: 2,
+                  child: TextField(
+                    decoration: const InputDecoration(
+                      labelText: 'Value',
+                      border: OutlineInputBorder(),
+                      isDense: true,
+                    ),
+                    controller: TextEditingController(text: secret.value)
+                      ..selection = TextSelection.collapsed(offset: secret.value.length),
+                    obscureText: true,
+                    onChanged: (value) {
+                      secret.value = value;
+                    },
+                  ),
+                ),
+                const SizedBox(width: AppConstants.spacingM),
+                IconButton(
+                  icon: const Icon(Icons.delete),
+                  onPressed: () => _removeSecret(index),
+                  tooltip: 'Remove',
+                ),
+              ],
+            ),
+          ),
+      ],
+    );
+  }
+
+  Widget _buildInheritedEnvironment() {
+    if (_inheritedEnvironment.isEmpty) {
+      return const SizedBox.shrink();
+    }
+
+    final overridden = EnvUtils.parse(_environmentController.text);
+    return Padding(
+      padding: const EdgeInsets.only(top: AppConstants.spacingM),
+      child: Column(
+        crossAxisAlignment: CrossAxisAlignment.start,
+        children: [
+          Text(
+            'Inherited from workspace',
+            style: Theme.of(context).textTheme.labelMedium,
+          ),
+          const SizedBox(height: AppConstants.spacingXs),
+          for (final entry in _inheritedEnvironment.entries)
+            Text(
+              '${entry.key}=${entry.value}',
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                    fontFamily: 'monospace',
+                    color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                    decoration: overridden.containsKey(entry.key) ? TextDecoration.lineThrough : null,
+                  ),
+            ),
+        ],
+      ),
+    );
+  }
+
+  Widget _buildPipelinesList() {
+    return Column(
+      children: [
+        for (final (index, pipeline) in _pipelines.indexed)
+          Card(
+            key: ValueKey(pipeline.id),
+            margin: const EdgeInsets.only(bottom: AppConstants.spacingS),
+            child: Padding(
+              padding: const EdgeInsets.all(AppConstants.spacingM),
+              child: Row(
+                children: [
+                  Expanded(
+                    flex: 1,
+                    child: TextField(
+                      decoration: const InputDecoration(
+                        labelText: 'Name',
+                        border: OutlineInputBorder(),
+                        isDense: true,
+                      ),
+                      controller: TextEditingController(text: pipeline.name)
+                        ..selection = TextSelection.collapsed(offset: pipeline.name.length),
+                      textInputAction: TextInputAction.done,
+                      onChanged: (value) {
+                        pipeline.name = value;
+                      },
+                      onSubmitted: (_) => _saveConfiguration(),
+                    ),
+                  ),
+                  const SizedBox(width: AppConstants.spacingM),
+                  Expanded(
+                    flex: 3,
+                    child: TextField(
+                      decoration: const InputDecoration(
+                        labelText: 'Tasks',
+                        hintText: 'build, test, deploy',
+                        border: OutlineInputBorder(),
+                        isDense: true,
+                      ),
+                      controller: TextEditingController(text: pipeline.steps.join(', '))
+                        ..selection = TextSelection.collapsed(offset: pipeline.steps.join(', ').length),
+                      textInputAction: TextInputAction.done,
+                      onChanged: (value) {
+                        pipeline.steps = value
+                            .split(',')
+                            .map((name) => name.trim())
+                            .where((name) => name.isNotEmpty)
+                            .toList();
+                      },
+                      onSubmitted: (_) => _saveConfiguration(),
+                    ),
+                  ),
+                  const SizedBox(width: AppConstants.spacingM),
+                  IconButton(
+                    icon: const Icon(Icons.delete),
+                    onPressed: () => _removePipeline(index),
+                    tooltip: 'Remove',
+                  ),
+                ],
+              ),
+            ),
+          ),
+      ],
+    );
+  }
+
+  Widget _buildInstallScriptDependencies() {
+    final mutedStyle = Theme.of(context).textTheme.bodySmall?.copyWith(
+          color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+        );
+
+    if (_installScriptDependencies.isEmpty) {
+      return Text('No installed dependency declares install scripts.', style: mutedStyle);
+    }
+
+    return Column(
+      crossAxisAlignment: CrossAxisAlignment.start,
+      children: [
+        Text(
+          'Installed dependencies with install scripts. bun only runs them for packages listed in trustedDependencies.',
+          style: mutedStyle,
+        ),
+        const SizedBox(height: AppConstants.spacingS),
+        for (final dependency in _installScriptDependencies)
+          ListTile(
+            dense: true,
+            contentPadding: EdgeInsets.zero,
+            leading: Icon(
+              dependency.trusted ? Icons.verified_user_outlined : Icons.block,
+              size: 18,
+              color: dependency.trusted
+                  ? Theme.of(context).colorScheme.primary
+                  : Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
+            ),
+            title: Text(dependency.name),
+            subtitle: Text(
+              dependency.scripts.entries.map((e) => '${e.key}: ${e.value}').join('\n'),
+              style: mutedStyle?.copyWith(fontFamily: 'monospace'),
+            ),
+            trailing: Text(
+              _ignoreScripts ? 'skipped' : (dependency.trusted ? 'trusted' : 'not trusted'),
+              style: mutedStyle,
+            ),
+          ),
+      ],
+    );
+  }
+
   Widget _buildTasksList() {
     if (_tasks.isEmpty) {
       return Container(
@@ -491,6 +1229,29 @@ class _ProjectConfigFormState extends State<ProjectConfigForm> {
                     ),
                   ),
                   const SizedBox(width: AppConstants.spacingM),
+                  Expanded(
+                    flex: 1,
+                    child: TextField(
+                      decoration: const InputDecoration(
+                        labelText: 'Depends on',
+                        hintText: 'codegen, build:css',
+                        border: OutlineInputBorder(),
+                        isDense: true,
+                      ),
+                      controller: TextEditingController(text: task.dependsOn.join(', '))
+                        ..selection = TextSelection.collapsed(offset: task.dependsOn.join(', ').length),
+                      textInputAction: TextInputAction.done,
+                      onChanged: (value) {
+                        task.dependsOn = value
+                            .split(',')
+                            .map((name) => name.trim())
+                            .where((name) => name.isNotEmpty)
+                            .toList();
+                      },
+                      onSubmitted: (_) => _saveConfiguration(),
+                    ),
+                  ),
+                  const SizedBox(width: AppConstants.spacingM),
                   IconButton(
                     icon: const Icon(Icons.delete),
                     onPressed: () => _removeTask(index),
@@ -0,0 +1,39 @@
+import 'package:desktop_drop/desktop_drop.dart';
+import 'package:flutter/material.dart';
+
+/// Accepts OS-level file drops (folders or package.json files) and reports
+/// the dropped paths. The builder receives whether a drag is hovering so
+/// callers can highlight the drop area.
+class ProjectDropTarget extends StatefulWidget {
+  final Function(List<String>) onDrop;
+  final Widget Function(BuildContext context, bool isDragging) builder;
+
+  const ProjectDropTarget({
+    super.key,
+    required this.onDrop,
+    required this.builder,
+  });
+
+  @override
+  State<ProjectDropTarget> createState() => _ProjectDropTargetState();
+}
+
+class _ProjectDropTargetState extends State<ProjectDropTarget> {
+  bool _isDragging = false;
+
+  @override
+  Widget build(BuildContext context) {
+    return DropTarget(
+      onDragEntered: (_) => setState(() => _isDragging = true),
+      onDragExited: (_) => setState(() => _isDragging = false),
+      onDragDone: (details) {
+        setState(() => _isDragging = false);
+        final paths = details.files.map((file) => file.path).toList();
+        if (paths.isNotEmpty) {
+          widget.onDrop(paths);
+        }
+      },
+      child: widget.builder(context, _isDragging),
+    );
+  }
+}
@@ -0,0 +1,100 @@
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../models/project.dart';
+
+/// Picks the emoji shown before a project's name. Pops it, an empty string
+/// to go back to the folder icon, or null when cancelled.
+class ProjectIconDialog extends StatefulWidget {
+  final Project project;
+  final String? icon;
+
+  const ProjectIconDialog({super.key, required this.project, this.icon});
+
+  @override
+  State<ProjectIconDialog> createState() => _ProjectIconDialogState();
+}
+
+class _ProjectIconDialogState extends State<ProjectIconDialog> {
+  static const _suggestions = [
+    '🚀', '🌐', '🛒', '📦', '🧪', '📚', '🎨', '⚙️', '🔧', '🗄️', //
+    '📱', '🤖', '🎮', '💬', '📊', '🔒', '🐳', '🔥', '⭐', '🧩',
+  ];
+
+  final _iconController = TextEditingController();
+
+  @override
+  void initState() {
+    super.initState();
+    _iconController.text = widget.icon ?? '';
+  }
+
+  @override
+  void dispose() {
+    _iconController.dispose();
+    super.dispose();
+  }
+
+  void _save([String? icon]) {
+    Navigator.of(context).pop((icon ?? _iconController.text).trim());
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: Text('Icon of ${widget.project.name}'),
+      content: SizedBox(
+        width: 400,
+        child: Column(
+          crossAxisAlignment: CrossAxisAlignment.start,
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            Wrap(
+              spacing: AppConstants.spacingXs,
+              runSpacing: AppConstants.spacingXs,
+              children: [
+                for (final suggestion in _suggestions)
+                  InkWell(
+                    borderRadius: BorderRadius.circular(4),
+                    onTap: () => _save(suggestion),
+                    child: Padding(
+                      padding: const EdgeInsets.all(AppConstants.spacingXs),
+                      child: Text(suggestion, style: const TextStyle(fontSize: 22)),
+                    ),
+                  ),
+              ],
+            ),
+            const SizedBox(height: AppConstants.spacingM),
+            TextField(
+              controller: _iconController,
+              autofocus: true,
+              decoration: const InputDecoration(
+                labelText: 'Emoji',
+                border: OutlineInputBorder(),
+                isDense: true,
+              ),
+              onSubmitted: (_) => _save(),
+            ),
+            const SizedBox(height: AppConstants.spacingS),
+            Text(
+              'Shown before the project\'s name in the sidebar and in tabs. Leave empty for the folder icon.',
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                    color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                  ),
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _save,
+          child: const Text('Save'),
+        ),
+      ],
+    );
+  }
+}
@@ -0,0 +1,201 @@
+import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
+import 'package:provider/provider.dart';
+
+import '../config/constants.dart';
+import '../extensions/task_status_extension.dart';
+import '../models/project.dart';
+import '../providers/project_provider.dart';
+import '../utils/task_utils.dart';
+
+/// Finds a task of any project by typing a few letters of its name. Pops
+/// the picked task with its project, or null when dismissed.
+class QuickLauncher extends StatefulWidget {
+  const QuickLauncher({super.key});
+
+  @override
+  State<QuickLauncher> createState() => _QuickLauncherState();
+}
+
+class _QuickLauncherState extends State<QuickLauncher> {
+  final _queryController = TextEditingController();
+  late final List<(Project, Task)> _tasks = _rankedTasks();
+  List<(Project, Task)> _matches = [];
+  int _highlighted = 0;
+
+  @override
+  void initState() {
+    super.initState();
+    _matches = _tasks;
+  }
+
+  @override
+  void dispose() {
+    _queryController.dispose();
+    super.dispose();
+  }
+
+  /// Every task, those run lately first, then starred ones, then the rest
+  /// in sidebar order
+  List<(Project, Task)> _rankedTasks() {
+    final provider = context.read<ProjectProvider>();
+    final tasks = [
+      for (final project in provider.projects)
+        for (final task in project.tasks)
+          if (task.type != TaskType.create) (project, task),
+    ];
+    int rankOf((Project, Task) entry) {
+      final taskKey = TaskUtils.getTaskKey(entry.$1, entry.$2);
+      final recent = provider.recentTasks.indexOf(taskKey);
+      if (recent != -1) {
+        return recent;
+      }
+      return provider.favoriteTasks.contains(taskKey) ? AppConstants.recentTasksLimit : AppConstants.recentTasksLimit + 1;
+    }
+
+    // Sorting is stable, so the sidebar order holds within each rank
+    final ranks = {for (final entry in tasks) entry: rankOf(entry)};
+    return tasks..sort((a, b) => ranks[a]!.compareTo(ranks[b]!));
+  }
+
+  /// How loosely [query] matches [text], lower being closer. Null when its
+  /// letters don't all appear in order.
+  static int? _fuzzyScore(String text, String query) {
+    final haystack = text.toLowerCase();
+    var score = 0;
+    var position = -1;
+    for (final letter in query.toLowerCase().split('')) {
+      if (letter == ' ') {
+        continue;
+      }
+      final found = haystack.indexOf(letter, position + 1);
+      if (found == -1) {
+        return null;
+      }
+      score += found - position - 1;
+      position = found;
+    }
+    return score;
+  }
+
+  void _search(String query) {
+    if (query.trim().isEmpty) {
+      setState(() {
+        _matches = _tasks;
+        _highlighted = 0;
+      });
+      return;
+    }
+
+    final scores = <(Project, Task), int>{};
+    for (final entry in _tasks) {
+      final (project, task) = entry;
+      final byTask = _fuzzyScore(task.displayName, query);
+      final byProject = _fuzzyScore('${project.name} ${task.displayName}', query);
+      final score = switch ((byTask, byProject)) {
+        (final a?, final b?) => a < b ? a : b,
+        (final a?, null) => a,
+        (null, final b?) => b,
+        _ => null,
+      };
+      if (score != null) {
+        scores[entry] = score;
+      }
+    }
+
+    setState(() {
+      _matches = scores.keys.toList()..sort((a, b) => scores[a]!.compareTo(scores[b]!));
+      _highlighted = 0;
+    });
+  }
+
+  void _move(int delta) {
+    if (_matches.isEmpty) {
+      return;
+    }
+    setState(() {
+      _highlighted = (_highlighted + delta).clamp(0, _matches.length - 1);
+    });
+  }
+
+  void _pick([int? index]) {
+    final picked = index ?? _highlighted;
+    if (picked < _matches.length) {
+      Navigator.of(context).pop(_matches[picked]);
+    }
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final colorScheme = Theme.of(context).colorScheme;
+    final icons = context.read<ProjectProvider>().projectIcons;
+
+    return Dialog(
+      alignment: Alignment.topCenter,
+      child: SizedBox(
+        width: AppConstants.launcherWidth,
+        height: AppConstants.launcherHeight - AppConstants.spacingXl * 2,
+        child: CallbackShortcuts(
+          bindings: {
+            const SingleActivator(LogicalKeyboardKey.arrowDown): () => _move(1),
+            const SingleActivator(LogicalKeyboardKey.arrowUp): () => _move(-1),
+          },
+          child: Column(
+            children: [
+              Padding(
+                padding: const EdgeInsets.all(AppConstants.spacingM),
+                child: TextField(
+                  controller: _queryController,
+                  autofocus: true,
+                  decoration: const InputDecoration(
+                    prefixIcon: Icon(Icons.search),
+                    hintText: 'Run a task',
+                    border: OutlineInputBorder(),
+                    isDense: true,
+                  ),
+                  onChanged: _search,
+                  onSubmitted: (_) => _pick(),
+                ),
+              ),
+              Expanded(
+                child: _matches.isEmpty
+                    ? Center(
+                        child: Text(
+                          'No task matches',
+                          style: Theme.of(context).textTheme.bodyMedium?.copyWith(
+                                color: colorScheme.onSurface.withValues(alpha: 0.5),
+                              ),
+                        ),
+                      )
+                    : ListView.builder(
+                        itemCount: _matches.length,
+                        itemBuilder: (context, index) {
+                          final (project, task) = _matches[index];
+                          final icon = icons[project.path];
+                          return ListTile(
+                            dense: true,
+                            selected: index == _highlighted,
+                            selectedTileColor: colorScheme.primaryContainer,
+                            leading: Icon(
+                              Icons.circle,
+                              size: 10,
+                              color: task.status == TaskStatus.idle ? Colors.transparent : task.status.getColor(context),
+                            ),
+                            title: Text(task.displayName, overflow: TextOverflow.ellipsis),
+                            subtitle: Text(
+                              icon != null ? '$icon ${project.name}' : project.name,
+                              overflow: TextOverflow.ellipsis,
+                            ),
+                            trailing: task.status == TaskStatus.running ? const Text('Running') : null,
+                            onTap: () => _pick(index),
+                          );
+                        },
+                      ),
+              ),
+            ],
+          ),
+        ),
+      ),
+    );
+  }
+}
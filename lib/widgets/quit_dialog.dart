@@ -0,0 +1,44 @@
+import 'package:flutter/material.dart';
+
+/// What happens to running tasks when bob quits
+enum QuitChoice {
+  stopTasks,
+  keepTasksRunning,
+}
+
+/// Asks what to do with the tasks still running when the window closes.
+/// Pops a [QuitChoice], or null to stay open.
+class QuitDialog extends StatelessWidget {
+  final int runningTasks;
+
+  const QuitDialog({super.key, required this.runningTasks});
+
+  @override
+  Widget build(BuildContext context) {
+    final tasks = runningTasks == 1 ? '1 task is' : '$runningTasks tasks are';
+    return AlertDialog(
+      title: Text('$tasks still running'),
+      content: const SizedBox(
+        width: 400,
+        child: Text(
+          'Quitting stops them and their child processes, giving each the stop grace period. '
+          'Tasks left running keep their ports and have to be stopped by hand.',
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(QuitChoice.keepTasksRunning),
+          child: const Text('Quit and keep them running'),
+        ),
+        FilledButton(
+          onPressed: () => Navigator.of(context).pop(QuitChoice.stopTasks),
+          child: const Text('Stop tasks and quit'),
+        ),
+      ],
+    );
+  }
+}
@@ -0,0 +1,90 @@
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+
+/// Asks for the SSH host and folder of a remote project. Pops them, or null
+/// when cancelled.
+class RemoteProjectDialog extends StatefulWidget {
+  const RemoteProjectDialog({super.key});
+
+  @override
+  State<RemoteProjectDialog> createState() => _RemoteProjectDialogState();
+}
+
+class _RemoteProjectDialogState extends State<RemoteProjectDialog> {
+  final _hostController = TextEditingController();
+  final _pathController = TextEditingController();
+
+  @override
+  void dispose() {
+    _hostController.dispose();
+    _pathController.dispose();
+    super.dispose();
+  }
+
+  bool get _isValid => _hostController.text.trim().isNotEmpty && _pathController.text.trim().isNotEmpty;
+
+  void _save() {
+    if (!_isValid) {
+      return;
+    }
+    Navigator.of(context).pop((_hostController.text.trim(), _pathController.text.trim()));
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: const Text('Add remote project'),
+      content: SizedBox(
+        width: 400,
+        child: Column(
+          crossAxisAlignment: CrossAxisAlignment.start,
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            TextField(
+              controller: _hostController,
+              autofocus: true,
+              decoration: const InputDecoration(
+                labelText: 'Host',
+                hintText: 'user@example.com',
+                border: OutlineInputBorder(),
+                isDense: true,
+              ),
+              onChanged: (_) => setState(() {}),
+              onSubmitted: (_) => _save(),
+            ),
+            const SizedBox(height: AppConstants.spacingM),
+            TextField(
+              controller: _pathController,
+              decoration: const InputDecoration(
+                labelText: 'Path',
+                hintText: '/home/user/app',
+                border: OutlineInputBorder(),
+                isDense: true,
+              ),
+              onChanged: (_) => setState(() {}),
+              onSubmitted: (_) => _save(),
+            ),
+            const SizedBox(height: AppConstants.spacingS),
+            Text(
+              'Tasks run over ssh, so the host needs to let you in without a password prompt, e.g. with a key.',
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                    color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                  ),
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _isValid ? _save : null,
+          child: const Text('Add'),
+        ),
+      ],
+    );
+  }
+}
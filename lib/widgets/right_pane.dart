@@ -1,23 +1,278 @@
 import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
+import 'package:path/path.dart' as path;
 import 'package:provider/provider.dart';
+import 'package:url_launcher/url_launcher.dart';
 import '../config/constants.dart';
+import '../models/problem.dart';
 import '../models/project.dart';
+import '../models/task_settings.dart';
 import '../providers/project_provider.dart';
+import '../services/project_service.dart';
+import '../services/resource_monitor.dart';
+import '../utils/process_utils.dart';
+import '../utils/task_utils.dart';
+import '../utils/ui_utils.dart';
+import '../widgets/task_alias_dialog.dart';
+import '../widgets/task_settings_dialog.dart';
 import '../widgets/terminal_output.dart';
 import '../widgets/launch_status.dart';
+import '../widgets/problems_panel.dart';
+import '../widgets/task_details_panel.dart';
+import '../widgets/usage_sparkline.dart';
 
 class RightPane extends StatelessWidget {
   final Task? selectedTask;
   final Site? selectedLaunch;
   final Widget? customContent;
 
+  /// Task shown beside the selected one
+  final Task? splitTask;
+
+  /// Stack the split tasks instead of showing them side by side
+  final bool splitVertically;
+
   const RightPane({
     super.key,
     this.selectedTask,
     this.selectedLaunch,
     this.customContent,
+    this.splitTask,
+    this.splitVertically = false,
   });
 
+  Future<void> _openInEditor(BuildContext context, Task task, String? filePath, {int? line, int? column}) async {
+    final provider = context.read<ProjectProvider>();
+    final project = provider.projectForTask(task);
+    if (project == null) {
+      return;
+    }
+
+    final result = filePath == null
+        ? await provider.editorService.openProject(project.path)
+        : await provider.editorService.openLocation(project.path, filePath, line: line, column: column);
+    if (result.isFailure && context.mounted) {
+      UiUtils.showErrorSnackbar(context, result.error!);
+    }
+  }
+
+  Future<void> _revealProject(BuildContext context, Task task) async {
+    final project = context.read<ProjectProvider>().projectForTask(task);
+    if (project == null) {
+      return;
+    }
+
+    try {
+      await ProcessUtils.openInFileExplorer(project.path);
+    } catch (e) {
+      if (context.mounted) {
+        UiUtils.showErrorSnackbar(context, 'Failed to open file explorer: ${e.toString()}');
+      }
+    }
+  }
+
+  void _clearOutput(BuildContext context, Task task) {
+    final provider = context.read<ProjectProvider>();
+    final project = provider.projectForTask(task);
+    if (project != null) {
+      provider.clearTaskOutput(project, task);
+    }
+  }
+
+  Future<void> _popOut(BuildContext context, Task task) async {
+    final provider = context.read<ProjectProvider>();
+    final project = provider.projectForTask(task);
+    if (project == null) {
+      return;
+    }
+
+    try {
+      await provider.popOutTask(project, task);
+    } catch (e) {
+      if (context.mounted) {
+        UiUtils.showErrorSnackbar(context, 'Failed to pop out ${task.displayName}: ${e.toString()}');
+      }
+    }
+  }
+
+  Future<void> _editTaskSettings(BuildContext context, Task task) async {
+    final provider = context.read<ProjectProvider>();
+    final project = provider.projectForTask(task);
+    if (project == null) {
+      return;
+    }
+
+    final updated = await showDialog<TaskSettings>(
+      context: context,
+      builder: (context) => TaskSettingsDialog(
+        task: task,
+        settings: provider.taskSettingsFor(project, task),
+        otherTasks: project.tasks
+            .where((t) => t.name != task.name && t.type == TaskType.script)
+            .map((t) => t.name)
+            .toList(),
+      ),
+    );
+    if (updated != null) {
+      await provider.setTaskSettings(project, task, updated);
+    }
+  }
+
+  /// Give a task a display name in bob.aliases
+  Future<void> _renameTask(BuildContext context, Task task) async {
+    final provider = context.read<ProjectProvider>();
+    final project = provider.projectForTask(task);
+    if (project == null) {
+      return;
+    }
+
+    final alias = await showDialog<String>(
+      context: context,
+      builder: (context) => TaskAliasDialog(task: task),
+    );
+    if (alias == null) {
+      return;
+    }
+
+    final result = await ProjectService().setTaskAlias(project, task.name, alias.isEmpty ? null : alias);
+    if (!context.mounted) return;
+
+    if (result.isSuccess) {
+      provider.updateProjectAfterGrouping(result.data!);
+    } else {
+      UiUtils.showErrorSnackbar(context, result.error!);
+    }
+  }
+
+  Future<void> _openServerUrl(BuildContext context, String url) async {
+    final uri = Uri.tryParse(url);
+    if (uri == null || !await launchUrl(uri, mode: LaunchMode.externalApplication)) {
+      if (context.mounted) {
+        UiUtils.showErrorSnackbar(context, 'Failed to open $url');
+      }
+    }
+  }
+
+  Widget _buildServerUrl(BuildContext context, String url) {
+    return Padding(
+      padding: const EdgeInsets.only(top: AppConstants.spacingXs),
+      child: Row(
+        children: [
+          ActionChip(
+            avatar: const Icon(Icons.open_in_browser, size: 16),
+            label: Text(url),
+            tooltip: 'Open in browser',
+            visualDensity: VisualDensity.compact,
+            onPressed: () => _openServerUrl(context, url),
+          ),
+          IconButton(
+            icon: const Icon(Icons.copy, size: 14),
+            tooltip: 'Copy URL',
+            visualDensity: VisualDensity.compact,
+            onPressed: () async {
+              await Clipboard.setData(ClipboardData(text: url));
+              if (context.mounted) {
+                UiUtils.showInfoSnackbar(context, 'Copied $url');
+              }
+            },
+            color: Theme.of(context).colorScheme.onPrimaryContainer,
+          ),
+        ],
+      ),
+    );
+  }
+
+  Widget _buildRegistryAuthFailure(BuildContext context, Task task, String? configPath) {
+    return Padding(
+      padding: const EdgeInsets.only(top: AppConstants.spacingXs),
+      child: Row(
+        children: [
+          Icon(Icons.lock_outline, size: 14, color: Theme.of(context).colorScheme.error),
+          const SizedBox(width: AppConstants.spacingXs),
+          Text(
+            'The registry refused the credentials',
+            style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                  color: Theme.of(context).colorScheme.onPrimaryContainer,
+                ),
+          ),
+          if (configPath != null) ...[
+            const SizedBox(width: AppConstants.spacingS),
+            ActionChip(
+              avatar: const Icon(Icons.edit_note, size: 16),
+              label: Text('Open ${path.basename(configPath)}'),
+              tooltip: configPath,
+              visualDensity: VisualDensity.compact,
+              onPressed: () => _openInEditor(context, task, configPath),
+            ),
+          ],
+        ],
+      ),
+    );
+  }
+
+  /// Pick a task among the open tabs to watch beside [task]
+  Widget _buildSplitMenu(BuildContext context, Task task) {
+    final provider = context.read<ProjectProvider>();
+    final others = provider.openTabs.where((tab) => tab.$2 != task).toList();
+    return PopupMenuButton<Task>(
+      icon: Icon(Icons.vertical_split, color: Theme.of(context).colorScheme.onPrimaryContainer),
+      tooltip: 'Split view',
+      onSelected: provider.splitWith,
+      itemBuilder: (context) => others.isEmpty
+          ? [
+              const PopupMenuItem(
+                enabled: false,
+                child: Text('Open another task to watch it beside this one'),
+              ),
+            ]
+          : [
+              for (final (project, other) in others)
+                PopupMenuItem(
+                  value: other,
+                  child: Text(
+                    project.tasks.contains(task) ? other.displayName : '${other.displayName} · ${project.name}',
+                  ),
+                ),
+            ],
+    );
+  }
+
+  Widget _buildExitHooks(BuildContext context, TaskSettings settings) {
+    if (settings.onSuccess == null && settings.onFailure == null) {
+      return const SizedBox.shrink();
+    }
+
+    final style = Theme.of(context).textTheme.bodySmall?.copyWith(
+          color: Theme.of(context).colorScheme.onPrimaryContainer.withValues(alpha: 0.7),
+        );
+    return Padding(
+      padding: const EdgeInsets.only(top: AppConstants.spacingXs),
+      child: Wrap(
+        spacing: AppConstants.spacingM,
+        children: [
+          if (settings.onSuccess != null)
+            Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                const Icon(Icons.check_circle_outline, size: 14, color: Colors.green),
+                const SizedBox(width: AppConstants.spacingXs),
+                Text('On success → ${settings.onSuccess}', style: style),
+              ],
+            ),
+          if (settings.onFailure != null)
+            Row(
+              mainAxisSize: MainAxisSize.min,
+              children: [
+                Icon(Icons.error_outline, size: 14, color: Theme.of(context).colorScheme.error),
+                const SizedBox(width: AppConstants.spacingXs),
+                Text('On failure → ${settings.onFailure}', style: style),
+              ],
+            ),
+        ],
+      ),
+    );
+  }
+
   @override
   Widget build(BuildContext context) {
     if (customContent != null) {
@@ -121,6 +376,29 @@ class RightPane extends StatelessWidget {
       );
     }
 
+    final split = splitTask;
+    if (split == null || split == selectedTask) {
+      return _buildTaskView(context, selectedTask!);
+    }
+
+    return Flex(
+      direction: splitVertically ? Axis.vertical : Axis.horizontal,
+      children: [
+        Expanded(child: _buildTaskView(context, selectedTask!)),
+        if (splitVertically) const Divider(height: 1) else const VerticalDivider(width: 1),
+        Expanded(child: _buildTaskView(context, split, inSplit: true)),
+      ],
+    );
+  }
+
+  /// Header and output of [task]. The one shown [inSplit] beside the
+  /// selected task can close the split instead of starting one.
+  Widget _buildTaskView(BuildContext context, Task task, {bool inSplit = false}) {
+    final project = context.read<ProjectProvider>().projectForTask(task);
+    final projectPath = project?.path;
+    final taskKey = project == null ? null : TaskUtils.getTaskKey(project, task);
+    final problems = project == null ? const <Problem>[] : context.read<ProjectProvider>().problemsFor(project, task);
+
     return Column(
       crossAxisAlignment: CrossAxisAlignment.start,
       children: [
@@ -145,16 +423,19 @@ class RightPane extends StatelessWidget {
                 child: Column(
                   crossAxisAlignment: CrossAxisAlignment.start,
                   children: [
-                    Text(
-                      selectedTask!.name,
-                      style: Theme.of(context).textTheme.titleMedium?.copyWith(
-                            fontWeight: FontWeight.bold,
-                            color: Theme.of(context).colorScheme.onPrimaryContainer,
-                          ),
+                    Tooltip(
+                      message: task.alias != null ? task.name : '',
+                      child: Text(
+                        task.displayName,
+                        style: Theme.of(context).textTheme.titleMedium?.copyWith(
+                              fontWeight: FontWeight.bold,
+                              color: Theme.of(context).colorScheme.onPrimaryContainer,
+                            ),
+                      ),
                     ),
                     const SizedBox(height: AppConstants.spacingXs),
                     Text(
-                      selectedTask!.command,
+                      task.command,
                       style: Theme.of(context).textTheme.bodySmall?.copyWith(
                             fontFamily: 'monospace',
                             color: Theme.of(context)
@@ -163,17 +444,174 @@ class RightPane extends StatelessWidget {
                                 .withValues(alpha: 0.7),
                           ),
                     ),
+                    if (projectPath != null)
+                      Selector<ProjectProvider, String?>(
+                        selector: (_, provider) {
+                          final project = provider.projectForTask(task);
+                          return project == null
+                              ? null
+                              : provider.serverUrls[TaskUtils.getTaskKey(project, task)];
+                        },
+                        builder: (context, url, _) =>
+                            url == null ? const SizedBox.shrink() : _buildServerUrl(context, url),
+                      ),
+                    if (taskKey != null)
+                      Selector<ProjectProvider, (bool, String?)>(
+                        selector: (_, provider) => (
+                          provider.registryAuthFailures.containsKey(taskKey),
+                          provider.registryAuthFailures[taskKey],
+                        ),
+                        builder: (context, data, _) => data.$1
+                            ? _buildRegistryAuthFailure(context, task, data.$2)
+                            : const SizedBox.shrink(),
+                      ),
+                    if (taskKey != null)
+                      Selector<ProjectProvider, ProcessUsage?>(
+                        selector: (_, provider) => provider.taskUsage[taskKey],
+                        builder: (context, usage, _) => usage == null
+                            ? const SizedBox.shrink()
+                            : UsageSparkline(
+                                history: List.of(context.read<ProjectProvider>().taskUsageHistory(taskKey)),
+                              ),
+                      ),
+                    if (projectPath != null)
+                      Selector<ProjectProvider, TaskSettings>(
+                        selector: (_, provider) {
+                          final project = provider.projectForTask(task);
+                          return project == null
+                              ? const TaskSettings()
+                              : provider.taskSettings[TaskUtils.getTaskKey(project, task)] ??
+                                  const TaskSettings();
+                        },
+                        builder: (context, settings, _) => _buildExitHooks(context, settings),
+                      ),
+                    if (projectPath != null)
+                      Row(
+                        children: [
+                          Flexible(
+                            child: Tooltip(
+                              message: projectPath,
+                              child: SelectableText(
+                                projectPath,
+                                maxLines: 1,
+                                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                                      fontFamily: 'monospace',
+                                      color: Theme.of(context)
+                                          .colorScheme
+                                          .onPrimaryContainer
+                                          .withValues(alpha: 0.5),
+                                    ),
+                              ),
+                            ),
+                          ),
+                          IconButton(
+                            icon: const Icon(Icons.copy, size: 14),
+                            tooltip: 'Copy path',
+                            visualDensity: VisualDensity.compact,
+                            onPressed: () => UiUtils.copyPath(context, projectPath),
+                            color: Theme.of(context).colorScheme.onPrimaryContainer,
+                          ),
+                        ],
+                      ),
                   ],
                 ),
               ),
+              if (project != null)
+                Selector<ProjectProvider, bool>(
+                  selector: (_, provider) => provider.isFavorite(project, task),
+                  builder: (context, favorite, _) => IconButton(
+                    icon: Icon(favorite ? Icons.star : Icons.star_border),
+                    tooltip: favorite ? 'Remove from favorites' : 'Add to favorites',
+                    onPressed: () => context.read<ProjectProvider>().toggleFavorite(project, task),
+                    color: favorite ? Colors.amber : Theme.of(context).colorScheme.onPrimaryContainer,
+                  ),
+                ),
+              if (!context.read<ProjectProvider>().isReadOnly &&
+                  (task.type == TaskType.script ||
+                      task.type == TaskType.group ||
+                      task.type == TaskType.pipeline))
+                IconButton(
+                  icon: const Icon(Icons.drive_file_rename_outline),
+                  tooltip: 'Rename',
+                  onPressed: () => _renameTask(context, task),
+                  color: Theme.of(context).colorScheme.onPrimaryContainer,
+                ),
+              if (task.type == TaskType.script || task.type == TaskType.custom)
+                IconButton(
+                  icon: const Icon(Icons.tune),
+                  tooltip: 'Run configuration',
+                  onPressed: () => _editTaskSettings(context, task),
+                  color: Theme.of(context).colorScheme.onPrimaryContainer,
+                ),
+              if (inSplit) ...[
+                IconButton(
+                  icon: Icon(splitVertically ? Icons.vertical_split : Icons.horizontal_split),
+                  tooltip: splitVertically ? 'Show side by side' : 'Stack vertically',
+                  onPressed: () => context.read<ProjectProvider>().setSplitVertically(!splitVertically),
+                  color: Theme.of(context).colorScheme.onPrimaryContainer,
+                ),
+                IconButton(
+                  icon: const Icon(Icons.close),
+                  tooltip: 'Close split',
+                  onPressed: context.read<ProjectProvider>().closeSplit,
+                  color: Theme.of(context).colorScheme.onPrimaryContainer,
+                ),
+              ] else
+                _buildSplitMenu(context, task),
+              IconButton(
+                icon: const Icon(Icons.clear_all),
+                tooltip: 'Clear output',
+                onPressed: task.output.isEmpty ? null : () => _clearOutput(context, task),
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+              IconButton(
+                icon: const Icon(Icons.open_in_new),
+                tooltip: 'Pop out into a terminal window',
+                onPressed: () => _popOut(context, task),
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+              IconButton(
+                icon: const Icon(Icons.folder_open),
+                tooltip: 'Reveal in ${ProcessUtils.fileManagerName}',
+                onPressed: () => _revealProject(context, task),
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
+              IconButton(
+                icon: const Icon(Icons.code),
+                tooltip: 'Open in editor',
+                onPressed: () => _openInEditor(context, task, null),
+                color: Theme.of(context).colorScheme.onPrimaryContainer,
+              ),
             ],
           ),
         ),
+        if (project != null)
+          TaskDetailsPanel(
+            key: ValueKey('details-${taskKey ?? task.name}'),
+            project: project,
+            task: task,
+            settings: context.read<ProjectProvider>().taskSettingsFor(project, task),
+            onEditEnvironment: () => context.read<ProjectProvider>().showProjectConfiguration(project),
+            onOpenFolder: () => _revealProject(context, task),
+            onViewHistory: () => context.read<ProjectProvider>().showLogBrowser(project),
+          ),
+        if (problems.isNotEmpty)
+          ProblemsPanel(
+            key: ValueKey('problems-${taskKey ?? task.name}'),
+            problems: problems,
+            onOpen: (problem) =>
+                _openInEditor(context, task, problem.file, line: problem.line, column: problem.column),
+          ),
         Expanded(
           child: TerminalOutput(
-            output: selectedTask!.output,
+            key: ValueKey(taskKey ?? task.name),
+            output: task.output,
+            stderrLines: project == null ? const {} : context.read<ProjectProvider>().stderrLinesFor(project, task),
+            droppedLines: project == null ? 0 : context.read<ProjectProvider>().droppedLinesFor(project, task),
             autoScroll: true,
-            placeholder: selectedTask!.status == TaskStatus.idle
+            onLocationTap: (filePath, line, column) =>
+                _openInEditor(context, task, filePath, line: line, column: column),
+            placeholder: task.status == TaskStatus.idle
                 ? Text(
                     'Task not started yet',
                     style: Theme.of(context).textTheme.bodyMedium?.copyWith(
@@ -0,0 +1,127 @@
+import 'dart:io';
+
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../models/project.dart';
+
+/// Picks which tasks of a project "Run all" starts and how many run at once.
+/// Pops a [RunAllRequest], or null when cancelled.
+class RunAllDialog extends StatefulWidget {
+  final Project project;
+  final int concurrency;
+
+  const RunAllDialog({
+    super.key,
+    required this.project,
+    required this.concurrency,
+  });
+
+  @override
+  State<RunAllDialog> createState() => _RunAllDialogState();
+}
+
+class RunAllRequest {
+  final List<String> taskNames;
+  final int concurrency;
+
+  const RunAllRequest(this.taskNames, this.concurrency);
+}
+
+class _RunAllDialogState extends State<RunAllDialog> {
+  late final List<Task> _tasks;
+  late Set<String> _selected;
+  late int _concurrency;
+
+  @override
+  void initState() {
+    super.initState();
+    _tasks = widget.project.tasks.where((t) => t.type == TaskType.script).toList();
+    _selected = _tasks.map((t) => t.name).toSet();
+    _concurrency = widget.concurrency.clamp(1, _maxConcurrency);
+  }
+
+  int get _maxConcurrency => Platform.numberOfProcessors < 2 ? 2 : Platform.numberOfProcessors;
+
+  void _run() {
+    Navigator.of(context).pop(RunAllRequest(
+      _tasks.map((t) => t.name).where(_selected.contains).toList(),
+      _concurrency,
+    ));
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: Text('Run tasks of ${widget.project.name}'),
+      content: SizedBox(
+        width: 480,
+        child: SingleChildScrollView(
+          child: Column(
+            crossAxisAlignment: CrossAxisAlignment.start,
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              for (final task in _tasks)
+                CheckboxListTile(
+                  dense: true,
+                  contentPadding: EdgeInsets.zero,
+                  title: Text(task.name),
+                  subtitle: Text(
+                    task.command,
+                    maxLines: 1,
+                    overflow: TextOverflow.ellipsis,
+                    style: const TextStyle(fontFamily: 'monospace'),
+                  ),
+                  value: _selected.contains(task.name),
+                  onChanged: (value) {
+                    setState(() {
+                      if (value == true) {
+                        _selected.add(task.name);
+                      } else {
+                        _selected.remove(task.name);
+                      }
+                    });
+                  },
+                ),
+              const SizedBox(height: AppConstants.spacingM),
+              Text(
+                'Run at most $_concurrency at a time',
+                style: Theme.of(context).textTheme.titleSmall?.copyWith(
+                      fontWeight: FontWeight.bold,
+                    ),
+              ),
+              Slider(
+                value: _concurrency.toDouble(),
+                min: 1,
+                max: _maxConcurrency.toDouble(),
+                divisions: _maxConcurrency - 1,
+                label: '$_concurrency',
+                onChanged: (value) {
+                  setState(() {
+                    _concurrency = value.round();
+                  });
+                },
+              ),
+              Text(
+                'Queued tasks start as running ones exit, so long-running tasks such as dev servers keep their slot.',
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                    ),
+              ),
+            ],
+          ),
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _selected.isEmpty ? null : _run,
+          child: Text('Run ${_selected.length}'),
+        ),
+      ],
+    );
+  }
+}
@@ -0,0 +1,255 @@
+import 'dart:convert';
+
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../models/project.dart';
+
+class _ScriptRow {
+  final TextEditingController name;
+  final TextEditingController command;
+
+  _ScriptRow(String name, String command)
+      : name = TextEditingController(text: name),
+        command = TextEditingController(text: command);
+
+  void dispose() {
+    name.dispose();
+    command.dispose();
+  }
+}
+
+/// Quick editor for the scripts of a project's package.json, as a table or
+/// as raw JSON. Pops the new scripts in order, or null when cancelled.
+class ScriptsEditorDialog extends StatefulWidget {
+  final Project project;
+  final Map<String, String> scripts;
+
+  const ScriptsEditorDialog({
+    super.key,
+    required this.project,
+    required this.scripts,
+  });
+
+  @override
+  State<ScriptsEditorDialog> createState() => _ScriptsEditorDialogState();
+}
+
+class _ScriptsEditorDialogState extends State<ScriptsEditorDialog> {
+  final List<_ScriptRow> _rows = [];
+  final _jsonController = TextEditingController();
+  bool _editingJson = false;
+  String? _error;
+
+  @override
+  void initState() {
+    super.initState();
+    _setRows(widget.scripts);
+  }
+
+  @override
+  void dispose() {
+    for (final row in _rows) {
+      row.dispose();
+    }
+    _jsonController.dispose();
+    super.dispose();
+  }
+
+  void _setRows(Map<String, String> scripts) {
+    for (final row in _rows) {
+      row.dispose();
+    }
+    _rows
+      ..clear()
+      ..addAll(scripts.entries.map((e) => _ScriptRow(e.key, e.value)));
+  }
+
+  /// Scripts from the table, or null with [_error] set when they're invalid
+  Map<String, String>? _scriptsFromRows() {
+    final scripts = <String, String>{};
+    for (final row in _rows) {
+      final name = row.name.text.trim();
+      final command = row.command.text.trim();
+      if (name.isEmpty && command.isEmpty) {
+        continue;
+      }
+      if (name.isEmpty) {
+        _error = 'Every script needs a name';
+        return null;
+      }
+      if (scripts.containsKey(name)) {
+        _error = 'There are two scripts named "$name"';
+        return null;
+      }
+      scripts[name] = command;
+    }
+    return scripts;
+  }
+
+  /// Scripts from the JSON text, or null with [_error] set when it's invalid
+  Map<String, String>? _scriptsFromJson() {
+    final Object? decoded;
+    try {
+      decoded = jsonDecode(_jsonController.text);
+    } on FormatException catch (e) {
+      _error = 'Invalid JSON: ${e.message}';
+      return null;
+    }
+    if (decoded is! Map<String, dynamic> || decoded.values.any((value) => value is! String)) {
+      _error = 'Scripts must be an object of names to command strings';
+      return null;
+    }
+    return decoded.map((key, value) => MapEntry(key, value as String));
+  }
+
+  void _toggleJson() {
+    setState(() {
+      _error = null;
+      if (_editingJson) {
+        final scripts = _scriptsFromJson();
+        if (scripts == null) {
+          return;
+        }
+        _setRows(scripts);
+      } else {
+        final scripts = _scriptsFromRows();
+        if (scripts == null) {
+          return;
+        }
+        _jsonController.text = const JsonEncoder.withIndent('  ').convert(scripts);
+      }
+      _editingJson = !_editingJson;
+    });
+  }
+
+  void _save() {
+    _error = null;
+    final scripts = _editingJson ? _scriptsFromJson() : _scriptsFromRows();
+    if (scripts == null) {
+      setState(() {});
+      return;
+    }
+    Navigator.of(context).pop(scripts);
+  }
+
+  Widget _buildTable() {
+    return Column(
+      mainAxisSize: MainAxisSize.min,
+      children: [
+        for (final (index, row) in _rows.indexed)
+          Padding(
+            padding: const EdgeInsets.only(bottom: AppConstants.spacingS),
+            child: Row(
+              children: [
+                Expanded(
+                  flex: 1,
+                  child: TextField(
+                    controller: row.name,
+                    decoration: const InputDecoration(
+                      labelText: 'Name',
+                      border: OutlineInputBorder(),
+                      isDense: true,
+                    ),
+                  ),
+                ),
+                const SizedBox(width: AppConstants.spacingS),
+                Expanded(
+                  flex: 3,
+                  child: TextField(
+                    controller: row.command,
+                    decoration: const InputDecoration(
+                      labelText: 'Command',
+                      border: OutlineInputBorder(),
+                      isDense: true,
+                    ),
+                    style: const TextStyle(fontFamily: 'monospace'),
+                  ),
+                ),
+                IconButton(
+                  icon: const Icon(Icons.delete_outline),
+                  tooltip: 'Remove script',
+                  onPressed: () {
+                    setState(() {
+                      _rows.removeAt(index).dispose();
+                    });
+                  },
+                ),
+              ],
+            ),
+          ),
+        Align(
+          alignment: Alignment.centerLeft,
+          child: TextButton.icon(
+            icon: const Icon(Icons.add),
+            label: const Text('Add script'),
+            onPressed: () {
+              setState(() {
+                _rows.add(_ScriptRow('', ''));
+              });
+            },
+          ),
+        ),
+      ],
+    );
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: Text('Scripts of ${widget.project.name}'),
+      content: SizedBox(
+        width: 640,
+        child: SingleChildScrollView(
+          child: Column(
+            crossAxisAlignment: CrossAxisAlignment.start,
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              if (_editingJson)
+                TextField(
+                  controller: _jsonController,
+                  decoration: const InputDecoration(
+                    border: OutlineInputBorder(),
+                    isDense: true,
+                  ),
+                  style: const TextStyle(fontFamily: 'monospace'),
+                  minLines: 8,
+                  maxLines: 20,
+                )
+              else
+                _buildTable(),
+              if (_error != null) ...[
+                const SizedBox(height: AppConstants.spacingS),
+                Text(
+                  _error!,
+                  style: TextStyle(color: Theme.of(context).colorScheme.error),
+                ),
+              ],
+              const SizedBox(height: AppConstants.spacingS),
+              Text(
+                'Only "scripts" of package.json is rewritten. The task list refreshes once the file is saved.',
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                    ),
+              ),
+            ],
+          ),
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: _toggleJson,
+          child: Text(_editingJson ? 'Edit as table' : 'Edit as JSON'),
+        ),
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _save,
+          child: const Text('Save'),
+        ),
+      ],
+    );
+  }
+}
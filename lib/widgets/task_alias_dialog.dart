@@ -0,0 +1,80 @@
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../models/project.dart';
+
+/// Gives a task a display name, leaving its script name alone. Pops the new
+/// name, an empty string to go back to the script name, or null when
+/// cancelled.
+class TaskAliasDialog extends StatefulWidget {
+  final Task task;
+
+  const TaskAliasDialog({super.key, required this.task});
+
+  @override
+  State<TaskAliasDialog> createState() => _TaskAliasDialogState();
+}
+
+class _TaskAliasDialogState extends State<TaskAliasDialog> {
+  final _aliasController = TextEditingController();
+
+  @override
+  void initState() {
+    super.initState();
+    _aliasController.text = widget.task.alias ?? '';
+  }
+
+  @override
+  void dispose() {
+    _aliasController.dispose();
+    super.dispose();
+  }
+
+  void _save() {
+    Navigator.of(context).pop(_aliasController.text.trim());
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: Text('Rename ${widget.task.name}'),
+      content: SizedBox(
+        width: 400,
+        child: Column(
+          crossAxisAlignment: CrossAxisAlignment.start,
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            TextField(
+              controller: _aliasController,
+              autofocus: true,
+              decoration: InputDecoration(
+                labelText: 'Display name',
+                hintText: widget.task.name,
+                border: const OutlineInputBorder(),
+                isDense: true,
+              ),
+              onSubmitted: (_) => _save(),
+            ),
+            const SizedBox(height: AppConstants.spacingS),
+            Text(
+              'Only changes how bob shows the task. The script keeps its name in package.json; leave empty to show it again.',
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                    color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                  ),
+            ),
+          ],
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _save,
+          child: const Text('Save'),
+        ),
+      ],
+    );
+  }
+}
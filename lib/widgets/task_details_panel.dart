@@ -0,0 +1,183 @@
+import 'package:flutter/material.dart';
+import 'package:path/path.dart' as path;
+
+import '../config/constants.dart';
+import '../models/project.dart';
+import '../models/task_settings.dart';
+import '../utils/env_utils.dart';
+import '../utils/secret_masker.dart';
+import '../utils/task_utils.dart';
+
+/// How a task runs and how it last ended, above its output
+class TaskDetailsPanel extends StatefulWidget {
+  final Project project;
+  final Task task;
+  final TaskSettings settings;
+  final VoidCallback onEditEnvironment;
+  final VoidCallback onOpenFolder;
+  final VoidCallback onViewHistory;
+
+  const TaskDetailsPanel({
+    super.key,
+    required this.project,
+    required this.task,
+    required this.settings,
+    required this.onEditEnvironment,
+    required this.onOpenFolder,
+    required this.onViewHistory,
+  });
+
+  @override
+  State<TaskDetailsPanel> createState() => _TaskDetailsPanelState();
+}
+
+class _TaskDetailsPanelState extends State<TaskDetailsPanel> {
+  bool _expanded = false;
+
+  String get _workingDirectory {
+    final task = widget.task;
+    final directory = widget.settings.workingDirectory;
+    if (directory == null || (task.type != TaskType.script && task.type != TaskType.custom)) {
+      return widget.project.path;
+    }
+    return path.normalize(path.join(widget.project.path, directory));
+  }
+
+  String get _lastRun {
+    final task = widget.task;
+    if (task.status == TaskStatus.running) {
+      return 'Running';
+    }
+    if (task.lastExitCode == null) {
+      return 'Not run yet';
+    }
+    final duration = task.lastDuration;
+    return 'Exited with code ${task.lastExitCode}'
+        '${duration != null ? ' after ${TaskUtils.formatDuration(duration)}' : ''}';
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final project = widget.project;
+    final muted = Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7);
+    final secrets = SecretMasker.secretsOf(project.environment).toSet();
+    final envFiles = project.loadEnvFiles ? [...EnvUtils.defaultEnvFiles, ...project.envFiles] : const <String>[];
+
+    return Container(
+      decoration: BoxDecoration(
+        border: Border(
+          bottom: BorderSide(color: Theme.of(context).dividerColor),
+        ),
+      ),
+      child: Column(
+        crossAxisAlignment: CrossAxisAlignment.start,
+        children: [
+          InkWell(
+            onTap: () => setState(() => _expanded = !_expanded),
+            child: Padding(
+              padding: const EdgeInsets.symmetric(
+                horizontal: AppConstants.spacingM,
+                vertical: AppConstants.spacingXs,
+              ),
+              child: Row(
+                children: [
+                  Icon(_expanded ? Icons.expand_more : Icons.chevron_right, size: 18),
+                  const SizedBox(width: AppConstants.spacingXs),
+                  Text('Details', style: Theme.of(context).textTheme.titleSmall),
+                  const SizedBox(width: AppConstants.spacingS),
+                  Flexible(
+                    child: Text(
+                      _lastRun,
+                      overflow: TextOverflow.ellipsis,
+                      style: Theme.of(context).textTheme.bodySmall?.copyWith(color: muted),
+                    ),
+                  ),
+                ],
+              ),
+            ),
+          ),
+          if (_expanded)
+            ConstrainedBox(
+              constraints: const BoxConstraints(maxHeight: 240),
+              child: SingleChildScrollView(
+                padding: const EdgeInsets.fromLTRB(
+                  AppConstants.spacingM,
+                  0,
+                  AppConstants.spacingM,
+                  AppConstants.spacingS,
+                ),
+                child: Column(
+                  crossAxisAlignment: CrossAxisAlignment.start,
+                  children: [
+                    _buildField(context, 'Command', widget.task.command),
+                    _buildField(context, 'Working directory', _workingDirectory),
+                    if (project.environment.isNotEmpty || project.secretEnvironment.isNotEmpty)
+                      _buildField(
+                        context,
+                        'Environment',
+                        [
+                          for (final MapEntry(:key, :value) in project.environment.entries)
+                            '$key=${secrets.contains(value) ? SecretMasker.mask : value}',
+                          for (final name in project.secretEnvironment) '$name=${SecretMasker.mask}',
+                        ].join('\n'),
+                      ),
+                    if (envFiles.isNotEmpty) _buildField(context, 'Env files', envFiles.join(', ')),
+                    _buildField(context, 'Last run', _lastRun),
+                    const SizedBox(height: AppConstants.spacingXs),
+                    Wrap(
+                      spacing: AppConstants.spacingS,
+                      children: [
+                        if (!project.isRemote)
+                          TextButton.icon(
+                            icon: const Icon(Icons.edit, size: 16),
+                            label: const Text('Edit environment'),
+                            onPressed: widget.onEditEnvironment,
+                          ),
+                        if (!project.isRemote)
+                          TextButton.icon(
+                            icon: const Icon(Icons.folder_open, size: 16),
+                            label: const Text('Open folder'),
+                            onPressed: widget.onOpenFolder,
+                          ),
+                        TextButton.icon(
+                          icon: const Icon(Icons.history, size: 16),
+                          label: const Text('View history'),
+                          onPressed: widget.onViewHistory,
+                        ),
+                      ],
+                    ),
+                  ],
+                ),
+              ),
+            ),
+        ],
+      ),
+    );
+  }
+
+  Widget _buildField(BuildContext context, String label, String value) {
+    return Padding(
+      padding: const EdgeInsets.only(bottom: AppConstants.spacingXs),
+      child: Row(
+        crossAxisAlignment: CrossAxisAlignment.start,
+        children: [
+          SizedBox(
+            width: 130,
+            child: Text(
+              label,
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                    color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                  ),
+            ),
+          ),
+          Expanded(
+            child: SelectableText(
+              value,
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(fontFamily: 'monospace'),
+            ),
+          ),
+        ],
+      ),
+    );
+  }
+}
@@ -0,0 +1,430 @@
+import 'dart:io';
+
+import 'package:file_picker/file_picker.dart';
+import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
+
+import '../config/constants.dart';
+import '../models/project.dart';
+import '../models/task_settings.dart';
+import '../services/task_service.dart';
+
+/// Edits the run configuration of a task. Pops the updated [TaskSettings],
+/// or null when cancelled.
+class TaskSettingsDialog extends StatefulWidget {
+  final Task task;
+  final TaskSettings settings;
+
+  /// Tasks of the same project that can be chained on exit
+  final List<String> otherTasks;
+
+  const TaskSettingsDialog({
+    super.key,
+    required this.task,
+    required this.settings,
+    this.otherTasks = const [],
+  });
+
+  @override
+  State<TaskSettingsDialog> createState() => _TaskSettingsDialogState();
+}
+
+// An exit action being edited
+class _ExitActionEntry {
+  ExitActionKind kind;
+  ExitTrigger trigger;
+  final TextEditingController target;
+
+  _ExitActionEntry({required this.kind, required this.trigger, String target = ''})
+      : target = TextEditingController(text: target);
+}
+
+class _TaskSettingsDialogState extends State<TaskSettingsDialog> {
+  late Set<int> _cpuCores;
+  late bool _highPriority;
+  late bool _restartOnChange;
+  late bool _keepOutput;
+  String? _onSuccess;
+  String? _onFailure;
+  final _workingDirectoryController = TextEditingController();
+  final _maxRetriesController = TextEditingController();
+  final _preRunController = TextEditingController();
+  final _postRunController = TextEditingController();
+  final _teeFileController = TextEditingController();
+  late List<_ExitActionEntry> _exitActions;
+
+  @override
+  void initState() {
+    super.initState();
+    _workingDirectoryController.text = widget.settings.workingDirectory ?? '';
+    _maxRetriesController.text = widget.settings.maxRetries > 0 ? '${widget.settings.maxRetries}' : '';
+    _preRunController.text = widget.settings.preRun ?? '';
+    _postRunController.text = widget.settings.postRun ?? '';
+    _teeFileController.text = widget.settings.teeFile ?? '';
+    _cpuCores = widget.settings.cpuCores.toSet();
+    _highPriority = widget.settings.highPriority;
+    _restartOnChange = widget.settings.restartOnChange;
+    _keepOutput = widget.settings.keepOutput;
+    _onSuccess = widget.settings.onSuccess;
+    _onFailure = widget.settings.onFailure;
+    _exitActions = [
+      for (final action in widget.settings.exitActions)
+        _ExitActionEntry(kind: action.kind, trigger: action.trigger, target: action.target),
+    ];
+  }
+
+  @override
+  void dispose() {
+    _workingDirectoryController.dispose();
+    _maxRetriesController.dispose();
+    _preRunController.dispose();
+    _postRunController.dispose();
+    _teeFileController.dispose();
+    for (final entry in _exitActions) {
+      entry.target.dispose();
+    }
+    super.dispose();
+  }
+
+  void _save() {
+    final workingDirectory = _workingDirectoryController.text.trim();
+    final preRun = _preRunController.text.trim();
+    final postRun = _postRunController.text.trim();
+    final teeFile = _teeFileController.text.trim();
+
+    // Built from scratch since copyWith can't clear the exit hooks
+    Navigator.of(context).pop(TaskSettings(
+      cpuCores: _cpuCores.toList()..sort(),
+      highPriority: _highPriority,
+      onSuccess: _onSuccess,
+      onFailure: _onFailure,
+      workingDirectory: workingDirectory.isEmpty || workingDirectory == '.' ? null : workingDirectory,
+      restartOnChange: _restartOnChange,
+      keepOutput: _keepOutput,
+      maxRetries: int.tryParse(_maxRetriesController.text.trim()) ?? 0,
+      preRun: preRun.isEmpty ? null : preRun,
+      postRun: postRun.isEmpty ? null : postRun,
+      teeFile: teeFile.isEmpty ? null : teeFile,
+      exitActions: [
+        for (final entry in _exitActions)
+          if (entry.target.text.trim().isNotEmpty)
+            ExitAction(kind: entry.kind, trigger: entry.trigger, target: entry.target.text.trim()),
+      ],
+    ));
+  }
+
+  Future<void> _pickTeeFile() async {
+    final filePath = await FilePicker.platform.saveFile(
+      dialogTitle: 'Write output of ${widget.task.name} to',
+      fileName: '${widget.task.name}.log',
+    );
+    if (filePath != null) {
+      _teeFileController.text = filePath;
+    }
+  }
+
+  Widget _buildHookField(String label, String? value, ValueChanged<String?> onChanged) {
+    // Keep a hook pointing at a task that no longer exists selectable
+    final choices = {...widget.otherTasks, ?value};
+    return DropdownButtonFormField<String?>(
+      initialValue: value,
+      decoration: InputDecoration(
+        labelText: label,
+        border: const OutlineInputBorder(),
+        isDense: true,
+      ),
+      items: [
+        const DropdownMenuItem<String?>(value: null, child: Text('Nothing')),
+        for (final name in choices)
+          DropdownMenuItem<String?>(value: name, child: Text(name)),
+      ],
+      onChanged: (value) => setState(() => onChanged(value)),
+    );
+  }
+
+  Widget _buildExitAction(_ExitActionEntry entry) {
+    return Row(
+      key: ObjectKey(entry),
+      crossAxisAlignment: CrossAxisAlignment.start,
+      children: [
+        DropdownButton<ExitTrigger>(
+          value: entry.trigger,
+          items: const [
+            DropdownMenuItem(value: ExitTrigger.success, child: Text('On success')),
+            DropdownMenuItem(value: ExitTrigger.failure, child: Text('On failure')),
+            DropdownMenuItem(value: ExitTrigger.always, child: Text('On exit')),
+          ],
+          onChanged: (value) => setState(() => entry.trigger = value!),
+        ),
+        const SizedBox(width: AppConstants.spacingS),
+        DropdownButton<ExitActionKind>(
+          value: entry.kind,
+          items: const [
+            DropdownMenuItem(value: ExitActionKind.webhook, child: Text('POST to')),
+            DropdownMenuItem(value: ExitActionKind.command, child: Text('Run')),
+          ],
+          onChanged: (value) => setState(() => entry.kind = value!),
+        ),
+        const SizedBox(width: AppConstants.spacingS),
+        Expanded(
+          child: TextField(
+            controller: entry.target,
+            style: const TextStyle(fontFamily: AppConstants.terminalFontFamily),
+            decoration: InputDecoration(
+              hintText: entry.kind == ExitActionKind.webhook
+                  ? 'https://hooks.slack.com/services/…'
+                  : 'notify-send "{task} {status}"',
+              border: const OutlineInputBorder(),
+              isDense: true,
+            ),
+          ),
+        ),
+        IconButton(
+          icon: const Icon(Icons.close, size: 18),
+          tooltip: 'Remove',
+          onPressed: () {
+            setState(() {
+              _exitActions.remove(entry);
+            });
+            // The field lets go of the controller on the next frame
+            WidgetsBinding.instance.addPostFrameCallback((_) => entry.target.dispose());
+          },
+        ),
+      ],
+    );
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final coreCount = Platform.numberOfProcessors;
+
+    return AlertDialog(
+      title: Text('Run configuration of ${widget.task.name}'),
+      content: SizedBox(
+        width: 480,
+        child: SingleChildScrollView(
+          child: Column(
+            crossAxisAlignment: CrossAxisAlignment.start,
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              TextField(
+                controller: _workingDirectoryController,
+                decoration: const InputDecoration(
+                  labelText: 'Working directory',
+                  hintText: 'Project folder',
+                  helperText: 'Relative to the project, e.g. packages/web',
+                  border: OutlineInputBorder(),
+                  isDense: true,
+                ),
+              ),
+              const SizedBox(height: AppConstants.spacingM),
+              TextField(
+                controller: _preRunController,
+                style: const TextStyle(fontFamily: AppConstants.terminalFontFamily),
+                decoration: const InputDecoration(
+                  labelText: 'Before starting, run',
+                  hintText: 'docker compose up -d db',
+                  helperText: 'The task doesn\'t start when this command fails',
+                  border: OutlineInputBorder(),
+                  isDense: true,
+                ),
+              ),
+              const SizedBox(height: AppConstants.spacingM),
+              TextField(
+                controller: _postRunController,
+                style: const TextStyle(fontFamily: AppConstants.terminalFontFamily),
+                decoration: const InputDecoration(
+                  labelText: 'After stopping, run',
+                  hintText: 'docker compose stop db',
+                  helperText: 'Runs however the task stops, before it counts as stopped',
+                  border: OutlineInputBorder(),
+                  isDense: true,
+                ),
+              ),
+              const SizedBox(height: AppConstants.spacingM),
+              TextField(
+                controller: _teeFileController,
+                decoration: InputDecoration(
+                  labelText: 'Also write output to',
+                  hintText: 'logs/${widget.task.name}.log',
+                  helperText: 'Relative to the project, or absolute. Moved aside at '
+                      '${AppConstants.teeFileMaxBytes ~/ (1024 * 1024)} MB, keeping ${AppConstants.teeFileBackups} older files.',
+                  helperMaxLines: 2,
+                  border: const OutlineInputBorder(),
+                  isDense: true,
+                  suffixIcon: IconButton(
+                    icon: const Icon(Icons.folder_open, size: 18),
+                    tooltip: 'Choose file',
+                    onPressed: _pickTeeFile,
+                  ),
+                ),
+              ),
+              const SizedBox(height: AppConstants.spacingM),
+              Text(
+                'CPU cores',
+                style: Theme.of(context).textTheme.titleSmall?.copyWith(
+                      fontWeight: FontWeight.bold,
+                    ),
+              ),
+              const SizedBox(height: AppConstants.spacingXs),
+              Text(
+                TaskService.supportsCpuPinning
+                    ? 'Pin the task to specific cores for reproducible benchmarks. Leave all unchecked to use every core.'
+                    : 'Pinning tasks to cores is not supported on this platform.',
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                    ),
+              ),
+              const SizedBox(height: AppConstants.spacingS),
+              Wrap(
+                spacing: AppConstants.spacingS,
+                runSpacing: AppConstants.spacingS,
+                children: [
+                  for (var core = 0; core < coreCount; core++)
+                    FilterChip(
+                      label: Text('$core'),
+                      selected: _cpuCores.contains(core),
+                      onSelected: TaskService.supportsCpuPinning
+                          ? (selected) {
+                              setState(() {
+                                if (selected) {
+                                  _cpuCores.add(core);
+                                } else {
+                                  _cpuCores.remove(core);
+                                }
+                              });
+                            }
+                          : null,
+                    ),
+                ],
+              ),
+              const SizedBox(height: AppConstants.spacingM),
+              SwitchListTile(
+                contentPadding: EdgeInsets.zero,
+                title: const Text('High priority'),
+                subtitle: Text(
+                  Platform.isWindows
+                      ? 'Runs the task with the High priority class.'
+                      : 'Runs the task with niceness -10. Requires permission to raise priority.',
+                ),
+                value: _highPriority,
+                onChanged: (value) {
+                  setState(() {
+                    _highPriority = value;
+                  });
+                },
+              ),
+              SwitchListTile(
+                contentPadding: EdgeInsets.zero,
+                title: const Text('Restart on file change'),
+                subtitle: const Text(
+                  'Restarts the task when files of the project change, for servers without a watch mode of their own.',
+                ),
+                value: _restartOnChange,
+                onChanged: (value) {
+                  setState(() {
+                    _restartOnChange = value;
+                  });
+                },
+              ),
+              SwitchListTile(
+                contentPadding: EdgeInsets.zero,
+                title: const Text('Keep output across runs'),
+                subtitle: const Text(
+                  'Appends each run to the output of the previous ones instead of clearing it when the task starts.',
+                ),
+                value: _keepOutput,
+                onChanged: (value) {
+                  setState(() {
+                    _keepOutput = value;
+                  });
+                },
+              ),
+              const SizedBox(height: AppConstants.spacingM),
+              Text(
+                'Exit hooks',
+                style: Theme.of(context).textTheme.titleSmall?.copyWith(
+                      fontWeight: FontWeight.bold,
+                    ),
+              ),
+              const SizedBox(height: AppConstants.spacingXs),
+              Text(
+                'Run another task of this project when this one exits on its own.',
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                    ),
+              ),
+              const SizedBox(height: AppConstants.spacingS),
+              _buildHookField('On success run', _onSuccess, (value) => _onSuccess = value),
+              const SizedBox(height: AppConstants.spacingS),
+              _buildHookField('On failure run', _onFailure, (value) => _onFailure = value),
+              const SizedBox(height: AppConstants.spacingM),
+              Text(
+                'Webhooks and commands',
+                style: Theme.of(context).textTheme.titleSmall?.copyWith(
+                      fontWeight: FontWeight.bold,
+                    ),
+              ),
+              const SizedBox(height: AppConstants.spacingXs),
+              Text(
+                'POST a JSON description of the run to a URL, or run a shell command from the project folder. '
+                '{project}, {path}, {task}, {status}, {exitCode} and {duration} are filled in, '
+                'in commands as quoted references to BOB_PROJECT, BOB_PATH and the like, even within quotes.',
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                    ),
+              ),
+              const SizedBox(height: AppConstants.spacingS),
+              for (final entry in _exitActions) ...[
+                _buildExitAction(entry),
+                const SizedBox(height: AppConstants.spacingS),
+              ],
+              TextButton.icon(
+                icon: const Icon(Icons.add, size: 18),
+                label: const Text('Add webhook or command'),
+                onPressed: () {
+                  setState(() {
+                    _exitActions.add(_ExitActionEntry(kind: ExitActionKind.webhook, trigger: ExitTrigger.failure));
+                  });
+                },
+              ),
+              const SizedBox(height: AppConstants.spacingM),
+              TextField(
+                controller: _maxRetriesController,
+                keyboardType: TextInputType.number,
+                inputFormatters: [FilteringTextInputFormatter.digitsOnly],
+                decoration: InputDecoration(
+                  labelText: 'Retries on failure',
+                  hintText: '0',
+                  helperText: 'Runs the task again when it fails, waiting '
+                      '${AppConstants.retryBaseDelay.inSeconds}s before the first retry and twice as long before each next one. '
+                      'The failure hook runs once retries are used up.',
+                  helperMaxLines: 3,
+                  border: const OutlineInputBorder(),
+                  isDense: true,
+                ),
+              ),
+              const SizedBox(height: AppConstants.spacingS),
+              Text(
+                'Directory, CPU and priority changes apply the next time the task starts.',
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.7),
+                    ),
+              ),
+            ],
+          ),
+        ),
+      ),
+      actions: [
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _save,
+          child: const Text('Save'),
+        ),
+      ],
+    );
+  }
+}
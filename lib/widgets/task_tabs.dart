@@ -0,0 +1,127 @@
+import 'package:flutter/foundation.dart';
+import 'package:flutter/material.dart';
+import 'package:provider/provider.dart';
+
+import '../config/constants.dart';
+import '../extensions/task_status_extension.dart';
+import '../models/project.dart';
+import '../providers/project_provider.dart';
+
+/// Tabs across the top of the right pane for the tasks opened so far, to
+/// switch between their output without going back to the task list
+class TaskTabs extends StatelessWidget {
+  const TaskTabs({super.key});
+
+  @override
+  Widget build(BuildContext context) {
+    return Selector<ProjectProvider, List<(String, String?, String, String, TaskStatus, bool)>>(
+      selector: (_, provider) => [
+        for (final (project, task) in provider.openTabs)
+          (
+            project.path,
+            provider.projectIcons[project.path],
+            task.name,
+            task.displayName,
+            task.status,
+            project.tasks.contains(provider.selectedTask) && provider.selectedTask!.name == task.name,
+          ),
+      ],
+      shouldRebuild: (previous, next) => !listEquals(previous, next),
+      builder: (context, tabs, _) {
+        if (tabs.isEmpty) {
+          return const SizedBox.shrink();
+        }
+
+        return Container(
+          height: 36,
+          decoration: BoxDecoration(
+            color: Theme.of(context).colorScheme.surfaceContainerHighest,
+            border: Border(
+              bottom: BorderSide(
+                color: Theme.of(context).dividerColor,
+              ),
+            ),
+          ),
+          child: ListView(
+            scrollDirection: Axis.horizontal,
+            children: [
+              for (final (projectPath, icon, taskName, label, status, selected) in tabs)
+                _buildTab(context, projectPath, icon, taskName, label, status, selected),
+            ],
+          ),
+        );
+      },
+    );
+  }
+
+  Widget _buildTab(
+    BuildContext context,
+    String projectPath,
+    String? icon,
+    String taskName,
+    String label,
+    TaskStatus status,
+    bool selected,
+  ) {
+    final provider = context.read<ProjectProvider>();
+    final colorScheme = Theme.of(context).colorScheme;
+    (Project, Task)? find() => provider.openTabs
+        .where((tab) => tab.$1.path == projectPath && tab.$2.name == taskName)
+        .firstOrNull;
+
+    return Tooltip(
+      message: label == taskName ? projectPath : '$taskName · $projectPath',
+      child: InkWell(
+        onTap: () {
+          if (find() case (_, final task)) {
+            provider.selectTask(task);
+          }
+        },
+        child: Container(
+          padding: const EdgeInsets.only(left: AppConstants.spacingM, right: AppConstants.spacingXs),
+          decoration: BoxDecoration(
+            color: selected ? colorScheme.surface : null,
+            border: Border(
+              right: BorderSide(color: Theme.of(context).dividerColor),
+              bottom: BorderSide(
+                color: selected ? colorScheme.primary : Colors.transparent,
+                width: 2,
+              ),
+            ),
+          ),
+          child: Row(
+            mainAxisSize: MainAxisSize.min,
+            children: [
+              Icon(
+                Icons.circle,
+                size: 8,
+                color: status == TaskStatus.idle ? Colors.transparent : status.getColor(context),
+              ),
+              const SizedBox(width: AppConstants.spacingS),
+              if (icon != null) ...[
+                Text(icon),
+                const SizedBox(width: AppConstants.spacingXs),
+              ],
+              Text(
+                label,
+                style: Theme.of(context).textTheme.bodySmall?.copyWith(
+                      fontWeight: selected ? FontWeight.bold : FontWeight.normal,
+                    ),
+              ),
+              IconButton(
+                icon: const Icon(Icons.close, size: 14),
+                tooltip: 'Close tab',
+                visualDensity: VisualDensity.compact,
+                onPressed: () {
+                  if (find() case (final project, final task)) {
+                    provider.closeTab(project, task);
+                  }
+                },
+              ),
+            ],
+          ),
+        ),
+      ),
+    );
+  }
+}
@@ -1,18 +1,54 @@
+import 'dart:math';
+
+import 'package:flutter/gestures.dart';
 import 'package:flutter/material.dart';
+import 'package:flutter/rendering.dart';
+import 'package:flutter/services.dart';
+import 'package:provider/provider.dart';
 import '../config/constants.dart';
+import '../providers/project_provider.dart';
+import '../services/preferences_service.dart';
 import '../utils/ansi_parser.dart';
+import '../utils/json_log_utils.dart';
+import '../utils/log_level_utils.dart';
+import '../utils/log_section_utils.dart';
+import '../utils/secret_masker.dart';
+import '../utils/ui_utils.dart';
 
-/// A reusable terminal output display widget with black background and monospace font
+/// A reusable terminal output display widget with black background and
+/// monospace font. Follows new output until scrolled up, and can be
+/// searched to show only the lines matching a query. Long lines wrap or
+/// scroll horizontally, as last chosen in any view. Ctrl+= and Ctrl+- zoom
+/// the text of every log, Ctrl+0 resets it. Clicking a line selects it and
+/// shift-clicking another extends the selection, for Ctrl+C to copy.
+/// Chips narrow the output down to warnings, errors or stderr, whose lines
+/// stand out in color. Installs, rebuild cycles and stack traces fold away,
+/// successful installs from the start. F8 and Shift+F8 jump between the
+/// errors of the current run. JSON lines of structured loggers show as
+/// entries colored by level, unfolding to their fields, and searching for
+/// field=value shows the entries with that value. Secrets show as •••, in
+/// what gets copied too.
 class TerminalOutput extends StatefulWidget {
   final String output;
   final bool autoScroll;
   final Widget? placeholder;
+  final LocationTapCallback? onLocationTap;
+
+  /// Indices of the lines of [output] that came from stderr
+  final Set<int> stderrLines;
+
+  /// Lines dropped from the start of [output] so far, so lines unfolded by
+  /// the user stay so as the output moves on
+  final int droppedLines;
 
   const TerminalOutput({
     super.key,
     required this.output,
     this.autoScroll = true,
     this.placeholder,
+    this.onLocationTap,
+    this.stderrLines = const {},
+    this.droppedLines = 0,
   });
 
   @override
@@ -21,61 +57,848 @@ class TerminalOutput extends StatefulWidget {
 
 class _TerminalOutputState extends State<TerminalOutput> {
   final ScrollController _scrollController = ScrollController();
+  final TextEditingController _searchController = TextEditingController();
+  final FocusNode _focusNode = FocusNode();
   String? _lastOutput;
 
+  // Whether new output scrolls to the end, until the user scrolls up
+  late bool _following = widget.autoScroll;
+
+  bool _searching = false;
+  bool _wrap = true;
+
+  // Levels of lines shown, all of them when empty
+  Set<LogLevel> _levels = {};
+  bool _stderrOnly = false;
+
+  // Default color of stderr lines, those with colors of their own keep them
+  static const _stderrColor = Color(0xFFF0A070);
+
+  @override
+  void initState() {
+    super.initState();
+    PreferencesService().getWrapLogLines().then((wrap) {
+      if (mounted && wrap != _wrap) {
+        setState(() {
+          _wrap = wrap;
+        });
+      }
+    });
+  }
+
+  // Over-long lines the user chose to display in full, by line number
+  // counting dropped lines, like the JSON entries and sections below
+  final Set<int> _expandedLines = {};
+
+  // Whether JSON lines show as entries, and the ones unfolded to their fields
+  bool _prettyJson = true;
+  final Set<int> _expandedEntries = {};
+
+  // First and last line selected by clicking, and the line shift-clicks extend from
+  (int, int)? _selectedLines;
+  int? _anchorLine;
+
+  // Spans shown and the text selected in them by dragging
+  List<InlineSpan> _spans = const [];
+  TextSelection? _textSelection;
+
+  // Lines of the output shown on each line of the spans, when sections are folded
+  List<int>? _shownLines;
+
+  // Header lines of the sections folded or unfolded against their default
+  final Set<int> _toggledSections = {};
+  List<LogSection> _sections = const [];
+
+  // Recognizers of the links and toggles in the spans, disposed when
+  // the spans are replaced
+  List<GestureRecognizer> _recognizers = const [];
+
+  // Holds the text, to find where a line is drawn
+  final GlobalKey _contentKey = GlobalKey();
+
+  // Characters of a raw line laid out together in its dialog
+  static const _rawLinePieceLength = 4000;
+
+  // Matches an escape sequence cut off by truncation
+  static final _partialAnsiRegex = RegExp(r'\x1B(\[[0-9;]*)?$');
+
   @override
   void dispose() {
+    _disposeRecognizers();
     _scrollController.dispose();
+    _searchController.dispose();
+    _focusNode.dispose();
     super.dispose();
   }
 
+  String get _query => _searching ? _searchController.text.toLowerCase() : '';
+
+  // The output with its secrets hidden, kept until it or the secrets change
+  SecretMasker _masker = SecretMasker.none;
+  (String, SecretMasker)? _maskedFrom;
+  String _masked = '';
+
+  String get _output {
+    if (_maskedFrom case (final output, final masker) when identical(output, widget.output) && masker == _masker) {
+      return _masked;
+    }
+    _maskedFrom = (widget.output, _masker);
+    _masked = _masker.apply(widget.output);
+    return _masked;
+  }
+
+  /// The output, or only its lines matching the search, levels and stream,
+  /// with the indices of the lines shown that came from stderr and, when
+  /// filtered, the index in the output of each line shown
+  (String, Set<int>, List<int>?) get _visible {
+    if (_query.isEmpty && _levels.isEmpty && !_stderrOnly) {
+      return (_output, widget.stderrLines, null);
+    }
+
+    // Field names keep their case, reqId isn't reqid
+    final fieldFilter = _searching ? JsonLogUtils.fieldFilter(_searchController.text) : null;
+    final lines = <String>[];
+    final stderrLines = <int>{};
+    final sourceLines = <int>[];
+    for (final (index, line) in _output.split('\n').indexed) {
+      final fromStderr = widget.stderrLines.contains(index);
+      final plain = AnsiParser.stripAnsi(line);
+      if ((_stderrOnly && !fromStderr) ||
+          !switch (fieldFilter) {
+            (final field, final value) => JsonLogUtils.matchesField(plain, field, value),
+            null => plain.toLowerCase().contains(_query),
+          } ||
+          (_levels.isNotEmpty && !_levels.contains(LogLevelUtils.classify(plain)))) {
+        continue;
+      }
+      if (fromStderr) {
+        stderrLines.add(lines.length);
+      }
+      lines.add(line);
+      sourceLines.add(index);
+    }
+    return (lines.join('\n'), stderrLines, sourceLines);
+  }
+
+  String get _visibleOutput => _visible.$1;
+
+  void _toggleStderrOnly() {
+    setState(() {
+      _stderrOnly = !_stderrOnly;
+      _expandedLines.clear();
+      _expandedEntries.clear();
+      _clearLineSelection();
+    });
+  }
+
+  void _toggleLevel(LogLevel level) {
+    setState(() {
+      _levels = _levels.contains(level) ? _levels.difference({level}) : {..._levels, level};
+      _expandedLines.clear();
+      _expandedEntries.clear();
+      _clearLineSelection();
+    });
+  }
+
+  Widget _buildFilterChip(String label, Color color, bool selected, VoidCallback onSelected) {
+    return FilterChip(
+      label: Text(label),
+      labelStyle: TextStyle(fontSize: 11, color: selected ? Colors.black : color),
+      selected: selected,
+      showCheckmark: false,
+      selectedColor: color,
+      backgroundColor: Colors.black,
+      side: BorderSide(color: color.withValues(alpha: 0.6)),
+      visualDensity: VisualDensity.compact,
+      materialTapTargetSize: MaterialTapTargetSize.shrinkWrap,
+      onSelected: (_) => onSelected(),
+    );
+  }
+
+  void _scrollToEnd() {
+    WidgetsBinding.instance.addPostFrameCallback((_) {
+      if (_scrollController.hasClients) {
+        _scrollController.animateTo(
+          _scrollController.position.maxScrollExtent,
+          duration: AppConstants.autoScrollDuration,
+          curve: Curves.easeOut,
+        );
+      }
+    });
+  }
+
+  void _follow() {
+    setState(() {
+      _following = true;
+    });
+    _scrollToEnd();
+  }
+
+  void _toggleWrap() {
+    setState(() {
+      _wrap = !_wrap;
+    });
+    PreferencesService().setWrapLogLines(_wrap);
+  }
+
+  void _zoom(double delta) {
+    final provider = context.read<ProjectProvider>();
+    provider.setLogFontSize(delta == 0 ? AppConstants.terminalFontSize : provider.logFontSize + delta);
+  }
+
+  Map<ShortcutActivator, VoidCallback> get _zoomBindings {
+    final bindings = <ShortcutActivator, VoidCallback>{};
+    for (final (control, meta) in [(true, false), (false, true)]) {
+      bindings.addAll({
+        SingleActivator(LogicalKeyboardKey.equal, control: control, meta: meta): () => _zoom(1),
+        SingleActivator(LogicalKeyboardKey.equal, control: control, meta: meta, shift: true): () => _zoom(1),
+        SingleActivator(LogicalKeyboardKey.numpadAdd, control: control, meta: meta): () => _zoom(1),
+        SingleActivator(LogicalKeyboardKey.minus, control: control, meta: meta): () => _zoom(-1),
+        SingleActivator(LogicalKeyboardKey.numpadSubtract, control: control, meta: meta): () => _zoom(-1),
+        SingleActivator(LogicalKeyboardKey.digit0, control: control, meta: meta): () => _zoom(0),
+      });
+    }
+    return bindings;
+  }
+
+  bool _onScroll(ScrollNotification notification) {
+    // Horizontal scrolling of unwrapped lines has nothing to do with following
+    if (!widget.autoScroll || notification.metrics.axis != Axis.vertical) {
+      return false;
+    }
+    // Scrolling up stops following, getting back to the end resumes it
+    if (notification is UserScrollNotification && notification.direction == ScrollDirection.forward) {
+      if (_following) {
+        setState(() {
+          _following = false;
+        });
+      }
+    } else if (notification is ScrollEndNotification && notification.metrics.extentAfter == 0 && !_following) {
+      setState(() {
+        _following = true;
+      });
+    }
+    return false;
+  }
+
+  /// Lines classified as errors in the current run, which starts after the
+  /// last "Run started" marker when the task keeps output across runs
+  List<int> _errorLines() {
+    final lines = _visibleOutput.split('\n');
+    final start = lines.lastIndexWhere((line) => line.startsWith('[bob] Run started at'));
+    return [
+      for (var i = max(start, 0); i < lines.length; i++)
+        if (LogLevelUtils.classify(AnsiParser.stripAnsi(lines[i])) == LogLevel.error) i,
+    ];
+  }
+
+  /// Select and scroll to the error after the selected line, or before it
+  void _jumpToError({bool previous = false}) {
+    final errors = _errorLines();
+    if (errors.isEmpty) {
+      UiUtils.showInfoSnackbar(context, 'No errors in this run');
+      return;
+    }
+
+    final current = _anchorLine;
+    final line = current == null
+        ? errors.first
+        : previous
+            ? errors.lastWhere((line) => line < current, orElse: () => errors.last)
+            : errors.firstWhere((line) => line > current, orElse: () => errors.first);
+    setState(() {
+      _following = false;
+      _anchorLine = line;
+      _selectedLines = (line, line);
+
+      // Unfold the sections hiding the line
+      for (final section in _sections) {
+        final header = widget.droppedLines + section.header;
+        if (section.header < line && line <= section.end && section.collapsed != _toggledSections.contains(header)) {
+          if (!_toggledSections.remove(header)) {
+            _toggledSections.add(header);
+          }
+        }
+      }
+    });
+    WidgetsBinding.instance.addPostFrameCallback((_) => _scrollToLine(line));
+  }
+
+  void _scrollToLine(int line) {
+    final shownLine = _shownLines?.indexOf(line) ?? line;
+    final content = _contentKey.currentContext?.findRenderObject();
+    final editable = _findEditable(content);
+    if (shownLine < 0 || content == null || editable == null || !_scrollController.hasClients) {
+      return;
+    }
+
+    final plainText = TextSpan(children: _spans).toPlainText();
+    var offset = 0;
+    for (var i = 0; i < shownLine && offset >= 0; i++) {
+      offset = plainText.indexOf('\n', offset) + 1;
+    }
+    final caret = editable.getLocalRectForCaret(TextPosition(offset: max(offset, 0)));
+    final top = editable.localToGlobal(caret.topLeft, ancestor: content).dy + AppConstants.rightPaneContentPadding;
+
+    // Leave some of what led to the error above it
+    final position = _scrollController.position;
+    _scrollController.animateTo(
+      (top - position.viewportDimension / 3).clamp(0.0, position.maxScrollExtent),
+      duration: AppConstants.autoScrollDuration,
+      curve: Curves.easeOut,
+    );
+  }
+
+  RenderEditable? _findEditable(RenderObject? object) {
+    if (object is RenderEditable) {
+      return object;
+    }
+    RenderEditable? found;
+    object?.visitChildren((child) {
+      found ??= _findEditable(child);
+    });
+    return found;
+  }
+
+  void _clearLineSelection() {
+    _selectedLines = null;
+    _anchorLine = null;
+  }
+
+  void _onSelectionChanged(TextSelection selection, SelectionChangedCause? cause) {
+    _textSelection = selection;
+
+    // Anything but a click is a regular text selection
+    if (cause != SelectionChangedCause.tap) {
+      if (_selectedLines != null) {
+        setState(_clearLineSelection);
+      }
+      return;
+    }
+
+    final plainText = TextSpan(children: _spans).toPlainText();
+    final offset = selection.extentOffset.clamp(0, plainText.length);
+    final shownLine = '\n'.allMatches(plainText.substring(0, offset)).length;
+    final line = _shownLines?[min(shownLine, _shownLines!.length - 1)] ?? shownLine;
+    setState(() {
+      if (HardwareKeyboard.instance.isShiftPressed && _anchorLine != null) {
+        _selectedLines = (min(_anchorLine!, line), max(_anchorLine!, line));
+      } else {
+        _anchorLine = line;
+        _selectedLines = (line, line);
+      }
+    });
+  }
+
+  /// Copy the selected lines, or the selected text
+  void _copySelection() {
+    final String text;
+    if (_selectedLines case (final start, final end)) {
+      final lines = _visibleOutput.split('\n');
+      text = lines.sublist(start, min(end + 1, lines.length)).map(AnsiParser.stripAnsi).join('\n');
+    } else if (_textSelection case final selection? when selection.isValid && !selection.isCollapsed) {
+      text = selection.textInside(TextSpan(children: _spans).toPlainText());
+    } else {
+      return;
+    }
+    Clipboard.setData(ClipboardData(text: text));
+  }
+
+  Future<void> _copyAll() async {
+    final output = AnsiParser.stripAnsi(_visibleOutput);
+    await Clipboard.setData(ClipboardData(text: output));
+    if (mounted) {
+      final lines = output.isEmpty ? 0 : '\n'.allMatches(output).length + 1;
+      UiUtils.showInfoSnackbar(context, 'Copied $lines ${lines == 1 ? 'line' : 'lines'}');
+    }
+  }
+
+  void _setSearching(bool searching) {
+    setState(() {
+      _searching = searching;
+      _expandedLines.clear();
+      _expandedEntries.clear();
+      _clearLineSelection();
+      if (!searching) {
+        _searchController.clear();
+      }
+    });
+  }
+
   @override
   void didUpdateWidget(TerminalOutput oldWidget) {
     super.didUpdateWidget(oldWidget);
 
-    // Auto-scroll when output changes if enabled
-    if (widget.autoScroll && widget.output != _lastOutput) {
+    // Output was cleared (task restarted), forget expanded lines
+    if (widget.output.isEmpty) {
+      _expandedLines.clear();
+      _expandedEntries.clear();
+      _toggledSections.clear();
+      _clearLineSelection();
+    }
+
+    // Auto-scroll when output changes while following
+    if (widget.autoScroll && _following && widget.output != _lastOutput) {
       _lastOutput = widget.output;
 
       // Schedule scroll after the frame is built
-      WidgetsBinding.instance.addPostFrameCallback((_) {
-        if (_scrollController.hasClients) {
-          _scrollController.animateTo(
-            _scrollController.position.maxScrollExtent,
-            duration: AppConstants.autoScrollDuration,
-            curve: Curves.easeOut,
-          );
+      _scrollToEnd();
+    }
+  }
+
+  /// Build spans for the output, truncating lines longer than
+  /// [AppConstants.maxLineLength] so a single huge line can't freeze layout,
+  /// coloring stderr lines, folding sections and highlighting the selected
+  /// lines
+  List<InlineSpan> _buildSpans(String output, Set<int> stderrLines, List<int>? sourceLines) {
+    final lines = output.split('\n');
+
+    // Sections only make sense in the whole output, not in filtered lines
+    final filtered = _query.isNotEmpty || _levels.isNotEmpty || _stderrOnly;
+    final sections = filtered || !LogSectionUtils.mayContainSections(output)
+        ? const <LogSection>[]
+        : LogSectionUtils.find(lines.map(AnsiParser.stripAnsi).toList());
+    _shownLines = null;
+    _sections = sections;
+    final json = _prettyJson && JsonLogUtils.mayContainJson(output);
+
+    // Fast path: parse the whole output at once so colors can span lines
+    if (_selectedLines == null &&
+        stderrLines.isEmpty &&
+        sections.isEmpty &&
+        !json &&
+        !lines.any((line) => line.length > AppConstants.maxLineLength)) {
+      return AnsiParser.parse(output, defaultColor: Colors.white, onLocationTap: widget.onLocationTap);
+    }
+
+    final sectionsByHeader = {for (final section in sections) section.header: section};
+    final shownLines = <int>[];
+    final spans = <InlineSpan>[];
+    var i = 0;
+    while (i < lines.length) {
+      final line = lines[i];
+      final lineSpans = <InlineSpan>[];
+      final color = stderrLines.contains(i) ? _stderrColor : Colors.white;
+      final lineNumber = widget.droppedLines + (sourceLines?[i] ?? i);
+      shownLines.add(i);
+
+      final section = sectionsByHeader[i];
+      final collapsed = section != null && section.collapsed != _toggledSections.contains(lineNumber);
+      if (section != null) {
+        lineSpans.add(_buildSectionToggle(lineNumber, collapsed));
+      }
+
+      final entry = json && line.length <= AppConstants.maxLineLength
+          ? JsonLogUtils.parse(AnsiParser.stripAnsi(line))
+          : null;
+      if (entry != null) {
+        final expanded = _expandedEntries.contains(lineNumber);
+        lineSpans.addAll(_buildJsonEntry(lineNumber, entry, expanded, color));
+        // Each field unfolds on a line of its own, of the same output line
+        if (expanded) {
+          shownLines.addAll([for (final _ in entry.fields.keys) i]);
         }
+      } else if (line.length > AppConstants.maxLineLength && !_expandedLines.contains(lineNumber)) {
+        final truncated = line
+            .substring(0, AppConstants.maxLineLength)
+            .replaceFirst(_partialAnsiRegex, '');
+        lineSpans.addAll(AnsiParser.parse(truncated, defaultColor: color, onLocationTap: widget.onLocationTap));
+        lineSpans.addAll(_buildTruncationMarker(lineNumber, line));
+      } else {
+        lineSpans.addAll(AnsiParser.parse(line, defaultColor: color, onLocationTap: widget.onLocationTap));
+      }
+      if (collapsed) {
+        final hidden = section!.hiddenLines;
+        lineSpans.add(TextSpan(
+          text: ' … $hidden more ${hidden == 1 ? 'line' : 'lines'}',
+          style: TextStyle(color: Colors.white.withValues(alpha: 0.5)),
+        ));
+      }
+
+      if (_selectedLines case (final start, final end) when i >= start && i <= end) {
+        spans.add(TextSpan(
+          style: TextStyle(backgroundColor: Colors.blue.withValues(alpha: 0.35)),
+          children: lineSpans,
+        ));
+      } else {
+        spans.addAll(lineSpans);
+      }
+
+      i = collapsed ? section!.end + 1 : i + 1;
+      if (i < lines.length) {
+        spans.add(const TextSpan(text: '\n'));
+      }
+    }
+    if (sections.isNotEmpty || json) {
+      _shownLines = shownLines;
+    }
+    return spans;
+  }
+
+  /// A JSON line as its time, level and message, with its fields after it
+  /// or unfolded below it
+  List<InlineSpan> _buildJsonEntry(int lineNumber, JsonLogEntry entry, bool expanded, Color color) {
+    final muted = Colors.white.withValues(alpha: 0.5);
+    final levelColor = switch (entry.level) {
+      LogLevel.error => const Color(0xFFF14C4C),
+      LogLevel.warning => const Color(0xFFE5E510),
+      LogLevel.info when entry.levelName == 'debug' || entry.levelName == 'trace' => muted,
+      LogLevel.info => const Color(0xFF23D18B),
+    };
+
+    return [
+      if (entry.fields.isNotEmpty)
+        TextSpan(
+          text: expanded ? '▾ ' : '▸ ',
+          style: const TextStyle(color: Color(0xFF3B8EEA)),
+          recognizer: TapGestureRecognizer()
+            ..onTap = () {
+              setState(() {
+                if (!_expandedEntries.remove(lineNumber)) {
+                  _expandedEntries.add(lineNumber);
+                }
+                _clearLineSelection();
+              });
+            },
+        ),
+      if (entry.time != null) TextSpan(text: '${entry.time} ', style: TextStyle(color: muted)),
+      if (entry.levelName != null)
+        TextSpan(
+          text: '${entry.levelName!.toUpperCase().padRight(5)} ',
+          style: TextStyle(color: levelColor, fontWeight: FontWeight.bold),
+        ),
+      if (entry.message != null) TextSpan(text: entry.message, style: TextStyle(color: color)),
+      for (final MapEntry(:key, :value) in entry.fields.entries)
+        if (expanded)
+          TextSpan(children: [
+            TextSpan(text: '\n    $key: ', style: const TextStyle(color: Color(0xFF3B8EEA))),
+            TextSpan(text: JsonLogUtils.formatValue(value), style: TextStyle(color: color)),
+          ])
+        else
+          TextSpan(text: ' $key=${JsonLogUtils.formatValue(value)}', style: TextStyle(color: muted)),
+    ];
+  }
+
+  InlineSpan _buildSectionToggle(int lineNumber, bool collapsed) {
+    return TextSpan(
+      text: collapsed ? '▸ ' : '▾ ',
+      style: const TextStyle(color: Color(0xFF3B8EEA)),
+      recognizer: TapGestureRecognizer()
+        ..onTap = () {
+          setState(() {
+            if (!_toggledSections.remove(lineNumber)) {
+              _toggledSections.add(lineNumber);
+            }
+            _clearLineSelection();
+          });
+        },
+    );
+  }
+
+  List<InlineSpan> _buildTruncationMarker(int lineNumber, String line) {
+    final hidden = line.length - AppConstants.maxLineLength;
+    const linkStyle = TextStyle(
+      color: Color(0xFF3B8EEA),
+      decoration: TextDecoration.underline,
+    );
+
+    return [
+      TextSpan(
+        text: ' … $hidden more characters ',
+        style: TextStyle(color: Colors.white.withValues(alpha: 0.5)),
+      ),
+      TextSpan(
+        text: '[expand]',
+        style: linkStyle,
+        recognizer: TapGestureRecognizer()
+          ..onTap = () {
+            setState(() {
+              _expandedLines.add(lineNumber);
+            });
+          },
+      ),
+      const TextSpan(text: ' '),
+      TextSpan(
+        text: '[view raw]',
+        style: linkStyle,
+        recognizer: TapGestureRecognizer()..onTap = () => _showRawLine(line),
+      ),
+    ];
+  }
+
+  void _disposeRecognizers() {
+    for (final recognizer in _recognizers) {
+      recognizer.dispose();
+    }
+    _recognizers = const [];
+  }
+
+  /// The recognizers of [spans] and of the spans within them
+  static List<GestureRecognizer> _recognizersOf(List<InlineSpan> spans) {
+    final recognizers = <GestureRecognizer>[];
+    for (final span in spans) {
+      span.visitChildren((child) {
+        if (child is TextSpan && child.recognizer != null) {
+          recognizers.add(child.recognizer!);
+        }
+        return true;
       });
     }
+    return recognizers;
+  }
+
+  void _showRawLine(String line) {
+    final raw = AnsiParser.stripAnsi(line);
+
+    showDialog(
+      context: context,
+      builder: (context) => AlertDialog(
+        title: Text('Raw line (${raw.length} characters)'),
+        content: SizedBox(
+          width: 800,
+          height: 500,
+          child: Container(
+            color: Colors.black,
+            padding: const EdgeInsets.all(AppConstants.spacingS),
+            // Laid out a piece at a time as it scrolls into view, a line of
+            // megabytes in one text would freeze the app. Copy takes it whole.
+            child: SelectionArea(
+              child: ListView.builder(
+                itemCount: (raw.length / _rawLinePieceLength).ceil(),
+                itemBuilder: (context, index) => Text(
+                  raw.substring(
+                    index * _rawLinePieceLength,
+                    min(raw.length, (index + 1) * _rawLinePieceLength),
+                  ),
+                  style: AppConstants.terminalTextStyle,
+                ),
+              ),
+            ),
+          ),
+        ),
+        actions: [
+          TextButton(
+            onPressed: () => Clipboard.setData(ClipboardData(text: raw)),
+            child: const Text('Copy'),
+          ),
+          FilledButton(
+            onPressed: () => Navigator.of(context).pop(),
+            child: const Text('Close'),
+          ),
+        ],
+      ),
+    );
+  }
+
+  Widget _buildSearchBar(int matches, TextStyle textStyle) {
+    final muted = Colors.white.withValues(alpha: 0.6);
+    return Container(
+      color: Colors.grey.shade900,
+      padding: const EdgeInsets.symmetric(horizontal: AppConstants.spacingS),
+      child: Row(
+        children: [
+          Icon(Icons.search, size: 16, color: muted),
+          const SizedBox(width: AppConstants.spacingS),
+          Expanded(
+            child: CallbackShortcuts(
+              bindings: {
+                const SingleActivator(LogicalKeyboardKey.escape): () => _setSearching(false),
+              },
+              child: TextField(
+                controller: _searchController,
+                autofocus: true,
+                style: textStyle,
+                decoration: InputDecoration(
+                  hintText: 'Show lines containing…, or field=value of JSON lines',
+                  hintStyle: textStyle.copyWith(color: muted),
+                  border: InputBorder.none,
+                  isDense: true,
+                ),
+                onChanged: (_) => setState(() {
+                  _expandedLines.clear();
+                  _expandedEntries.clear();
+                  _clearLineSelection();
+                }),
+              ),
+            ),
+          ),
+          if (_query.isNotEmpty || _levels.isNotEmpty || _stderrOnly)
+            Text(
+              '$matches ${matches == 1 ? 'line' : 'lines'}',
+              style: Theme.of(context).textTheme.bodySmall?.copyWith(color: muted),
+            ),
+          IconButton(
+            icon: const Icon(Icons.close, size: 16),
+            tooltip: 'Close search',
+            visualDensity: VisualDensity.compact,
+            color: muted,
+            onPressed: () => _setSearching(false),
+          ),
+        ],
+      ),
+    );
   }
 
   @override
   Widget build(BuildContext context) {
+    if (widget.output.isEmpty && widget.placeholder != null) {
+      return Container(
+        color: Colors.black,
+        width: double.infinity,
+        child: Center(child: widget.placeholder!),
+      );
+    }
+
+    final (fontSize, fontFamily) = context.select((ProjectProvider p) => (p.logFontSize, p.logFontFamily));
+    _masker = context.select((ProjectProvider p) => p.secretMasker);
+    final textStyle = AppConstants.terminalTextStyle.copyWith(
+      fontSize: fontSize,
+      fontFamily: fontFamily ?? AppConstants.terminalFontFamily,
+    );
+
+    final (output, stderrLines, sourceLines) = _visible;
+    _disposeRecognizers();
+    _spans = widget.output.isEmpty ? const [] : _buildSpans(output, stderrLines, sourceLines);
+    _recognizers = _recognizersOf(_spans);
+    return CallbackShortcuts(
+      bindings: {
+        ..._zoomBindings,
+        const SingleActivator(LogicalKeyboardKey.f8): _jumpToError,
+        const SingleActivator(LogicalKeyboardKey.f8, shift: true): () => _jumpToError(previous: true),
+      },
+      child: Focus(
+        focusNode: _focusNode,
+        child: Listener(
+          // Clicking into the log gives it focus, so zooming works right away
+          onPointerDown: (_) {
+            if (!_focusNode.hasFocus) {
+              _focusNode.requestFocus();
+            }
+          },
+          child: _buildView(output, textStyle),
+        ),
+      ),
+    );
+  }
+
+  Widget _buildView(String output, TextStyle textStyle) {
     return Container(
       color: Colors.black,
       width: double.infinity,
-      child: widget.output.isEmpty && widget.placeholder != null
-          ? Center(child: widget.placeholder!)
-          : SingleChildScrollView(
-              controller: widget.autoScroll ? _scrollController : null,
-              scrollDirection: Axis.vertical,
-              padding: const EdgeInsets.all(AppConstants.rightPaneContentPadding),
-              child: widget.output.isEmpty
-                  ? SelectableText(
-                      'Output will appear here...',
-                      style: AppConstants.terminalTextStyle,
-                    )
-                  : SelectableText.rich(
-                      TextSpan(
-                        children: AnsiParser.parse(
-                          widget.output,
-                          defaultColor: Colors.white,
+      child: Column(
+        children: [
+          if (_searching) _buildSearchBar(output.isEmpty ? 0 : '\n'.allMatches(output).length + 1, textStyle),
+          Expanded(
+            child: Stack(
+              children: [
+                CallbackShortcuts(
+                  bindings: {
+                    const SingleActivator(LogicalKeyboardKey.keyC, control: true): _copySelection,
+                    const SingleActivator(LogicalKeyboardKey.keyC, meta: true): _copySelection,
+                  },
+                  child: NotificationListener<ScrollNotification>(
+                    onNotification: _onScroll,
+                    child: SingleChildScrollView(
+                      controller: _scrollController,
+                      scrollDirection: Axis.vertical,
+                      padding: const EdgeInsets.all(AppConstants.rightPaneContentPadding),
+                      child: KeyedSubtree(
+                        key: _contentKey,
+                        child: widget.output.isEmpty
+                            ? SelectableText(
+                                'Output will appear here...',
+                                style: textStyle,
+                              )
+                            : _wrap
+                                ? SelectableText.rich(
+                                    TextSpan(
+                                      children: _spans,
+                                      style: textStyle,
+                                    ),
+                                    onSelectionChanged: _onSelectionChanged,
+                                  )
+                                : SingleChildScrollView(
+                                    scrollDirection: Axis.horizontal,
+                                    child: SelectableText.rich(
+                                      TextSpan(
+                                        children: _spans,
+                                        style: textStyle,
+                                      ),
+                                      onSelectionChanged: _onSelectionChanged,
+                                    ),
+                                  ),
+                      ),
+                    ),
+                  ),
+                ),
+                Positioned(
+                  top: AppConstants.spacingXs,
+                  right: AppConstants.spacingXs,
+                  child: Row(
+                    mainAxisSize: MainAxisSize.min,
+                    children: [
+                      _buildFilterChip('Warnings', const Color(0xFFE5E510), _levels.contains(LogLevel.warning),
+                          () => _toggleLevel(LogLevel.warning)),
+                      const SizedBox(width: AppConstants.spacingXs),
+                      _buildFilterChip('Errors', const Color(0xFFF14C4C), _levels.contains(LogLevel.error),
+                          () => _toggleLevel(LogLevel.error)),
+                      if (widget.stderrLines.isNotEmpty || _stderrOnly) ...[
+                        const SizedBox(width: AppConstants.spacingXs),
+                        _buildFilterChip('stderr', _stderrColor, _stderrOnly, _toggleStderrOnly),
+                      ],
+                      if (JsonLogUtils.mayContainJson(widget.output))
+                        IconButton(
+                          icon: Icon(_prettyJson ? Icons.data_object : Icons.code, size: 18),
+                          tooltip: _prettyJson ? 'Show JSON lines as written' : 'Show JSON lines as entries',
+                          color: Colors.white.withValues(alpha: 0.6),
+                          onPressed: () => setState(() {
+                            _prettyJson = !_prettyJson;
+                            _expandedEntries.clear();
+                          }),
                         ),
-                        style: AppConstants.terminalTextStyle,
+                      IconButton(
+                        icon: const Icon(Icons.error_outline, size: 18),
+                        tooltip: 'Next error (F8)',
+                        color: Colors.white.withValues(alpha: 0.6),
+                        onPressed: widget.output.isEmpty ? null : _jumpToError,
+                      ),
+                      IconButton(
+                        icon: Icon(_wrap ? Icons.wrap_text : Icons.notes, size: 18),
+                        tooltip: _wrap ? 'Don\'t wrap long lines' : 'Wrap long lines',
+                        color: Colors.white.withValues(alpha: 0.6),
+                        onPressed: _toggleWrap,
                       ),
+                      IconButton(
+                        icon: const Icon(Icons.copy_all, size: 18),
+                        tooltip: 'Copy all',
+                        color: Colors.white.withValues(alpha: 0.6),
+                        onPressed: widget.output.isEmpty ? null : _copyAll,
+                      ),
+                      if (!_searching)
+                        IconButton(
+                          icon: const Icon(Icons.search, size: 18),
+                          tooltip: 'Search output',
+                          color: Colors.white.withValues(alpha: 0.6),
+                          onPressed: () => _setSearching(true),
+                        ),
+                    ],
+                  ),
+                ),
+                if (widget.autoScroll && !_following)
+                  Positioned(
+                    bottom: AppConstants.spacingM,
+                    right: AppConstants.spacingM,
+                    child: FilledButton.tonalIcon(
+                      icon: const Icon(Icons.arrow_downward, size: 16),
+                      label: const Text('Follow'),
+                      onPressed: _follow,
                     ),
+                  ),
+              ],
             ),
+          ),
+        ],
+      ),
     );
   }
 }
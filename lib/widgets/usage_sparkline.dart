@@ -0,0 +1,91 @@
+import 'dart:math' as math;
+
+import 'package:flutter/material.dart';
+
+import '../config/constants.dart';
+import '../services/resource_monitor.dart';
+
+/// Current CPU and memory of a running task, with their recent history
+class UsageSparkline extends StatelessWidget {
+  final List<ProcessUsage> history;
+
+  const UsageSparkline({super.key, required this.history});
+
+  @override
+  Widget build(BuildContext context) {
+    if (history.isEmpty) {
+      return const SizedBox.shrink();
+    }
+
+    final colorScheme = Theme.of(context).colorScheme;
+    final cpuColor = colorScheme.onPrimaryContainer;
+    final memoryColor = colorScheme.tertiary;
+    final style = Theme.of(context).textTheme.bodySmall;
+    final latest = history.last;
+
+    return Padding(
+      padding: const EdgeInsets.only(top: AppConstants.spacingXs),
+      child: Row(
+        children: [
+          Text('CPU ${latest.formattedCpu}', style: style?.copyWith(color: cpuColor)),
+          const SizedBox(width: AppConstants.spacingS),
+          Text(latest.formattedRss, style: style?.copyWith(color: memoryColor)),
+          const SizedBox(width: AppConstants.spacingS),
+          Tooltip(
+            message: 'Last ${history.length} samples, '
+                'taken every ${AppConstants.resourceSampleInterval.inSeconds}s',
+            child: CustomPaint(
+              size: const Size(120, 20),
+              painter: _SparklinePainter(
+                series: [
+                  (history.map((usage) => usage.cpuPercent).toList(), cpuColor),
+                  (history.map((usage) => usage.rssBytes.toDouble()).toList(), memoryColor),
+                ],
+              ),
+            ),
+          ),
+        ],
+      ),
+    );
+  }
+}
+
+/// Draws each series scaled to its own maximum, aligned to the right edge
+class _SparklinePainter extends CustomPainter {
+  final List<(List<double>, Color)> series;
+
+  _SparklinePainter({required this.series});
+
+  @override
+  void paint(Canvas canvas, Size size) {
+    final step = size.width / (AppConstants.resourceHistoryLength - 1);
+    for (final (values, color) in series) {
+      if (values.length < 2) {
+        continue;
+      }
+
+      final maximum = math.max(values.reduce(math.max), 1.0);
+      final offset = size.width - (values.length - 1) * step;
+      final path = Path();
+      for (var i = 0; i < values.length; i++) {
+        final point = Offset(offset + i * step, size.height - values[i] / maximum * size.height);
+        if (i == 0) {
+          path.moveTo(point.dx, point.dy);
+        } else {
+          path.lineTo(point.dx, point.dy);
+        }
+      }
+
+      canvas.drawPath(
+        path,
+        Paint()
+          ..color = color
+          ..style = PaintingStyle.stroke
+          ..strokeWidth = 1.5,
+      );
+    }
+  }
+
+  @override
+  bool shouldRepaint(_SparklinePainter oldDelegate) => true;
+}
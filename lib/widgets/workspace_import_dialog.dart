@@ -0,0 +1,163 @@
+import 'package:flutter/material.dart';
+import 'package:path/path.dart' as path;
+
+import '../config/constants.dart';
+import '../models/project.dart';
+import '../services/project_service.dart';
+
+/// Dialog that scans a workspace directory for package.json files and lets
+/// the user pick which projects to import.
+/// Pops with the list of selected project paths, or null if cancelled.
+class WorkspaceImportDialog extends StatefulWidget {
+  final String rootPath;
+  final ProjectService projectService;
+  final Set<String> existingPaths;
+
+  const WorkspaceImportDialog({
+    super.key,
+    required this.rootPath,
+    required this.projectService,
+    required this.existingPaths,
+  });
+
+  @override
+  State<WorkspaceImportDialog> createState() => _WorkspaceImportDialogState();
+}
+
+class _WorkspaceImportDialogState extends State<WorkspaceImportDialog> {
+  List<Project> _candidates = [];
+  final Set<String> _selected = {};
+  bool _isScanning = true;
+
+  @override
+  void initState() {
+    super.initState();
+    _scan();
+  }
+
+  Future<void> _scan() async {
+    final candidates = await widget.projectService.scanDirectory(widget.rootPath);
+    if (!mounted) return;
+
+    setState(() {
+      _candidates = candidates;
+      // Preselect everything that isn't already imported
+      _selected.addAll(
+        candidates
+            .map((p) => p.path)
+            .where((p) => !widget.existingPaths.contains(p)),
+      );
+      _isScanning = false;
+    });
+  }
+
+  void _setAll(bool selected) {
+    setState(() {
+      _selected.clear();
+      if (selected) {
+        _selected.addAll(
+          _candidates
+              .map((p) => p.path)
+              .where((p) => !widget.existingPaths.contains(p)),
+        );
+      }
+    });
+  }
+
+  String _relativePath(Project project) {
+    final relative = path.relative(project.path, from: widget.rootPath);
+    return relative == '.' ? path.basename(widget.rootPath) : relative;
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return AlertDialog(
+      title: const Text('Import Workspace'),
+      content: SizedBox(
+        width: 500,
+        height: 400,
+        child: _buildContent(context),
+      ),
+      actions: [
+        if (!_isScanning && _candidates.isNotEmpty) ...[
+          TextButton(
+            onPressed: () => _setAll(true),
+            child: const Text('Select all'),
+          ),
+          TextButton(
+            onPressed: () => _setAll(false),
+            child: const Text('Select none'),
+          ),
+        ],
+        TextButton(
+          onPressed: () => Navigator.of(context).pop(),
+          child: const Text('Cancel'),
+        ),
+        FilledButton(
+          onPressed: _selected.isEmpty
+              ? null
+              : () => Navigator.of(context).pop(_selected.toList()),
+          child: Text('Import ${_selected.length}'),
+        ),
+      ],
+    );
+  }
+
+  Widget _buildContent(BuildContext context) {
+    if (_isScanning) {
+      return Center(
+        child: Column(
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            const CircularProgressIndicator(),
+            const SizedBox(height: AppConstants.spacingM),
+            Text(
+              'Scanning ${widget.rootPath}...',
+              style: Theme.of(context).textTheme.bodyMedium,
+            ),
+          ],
+        ),
+      );
+    }
+
+    if (_candidates.isEmpty) {
+      return Center(
+        child: Text(
+          'No package.json found in this directory',
+          style: Theme.of(context).textTheme.bodyMedium?.copyWith(
+                color: Theme.of(context).colorScheme.onSurface.withValues(alpha: 0.5),
+              ),
+        ),
+      );
+    }
+
+    return ListView.builder(
+      itemCount: _candidates.length,
+      itemBuilder: (context, index) {
+        final project = _candidates[index];
+        final alreadyImported = widget.existingPaths.contains(project.path);
+
+        return CheckboxListTile(
+          dense: true,
+          value: alreadyImported || _selected.contains(project.path),
+          onChanged: alreadyImported
+              ? null
+              : (value) {
+                  setState(() {
+                    if (value == true) {
+                      _selected.add(project.path);
+                    } else {
+                      _selected.remove(project.path);
+                    }
+                  });
+                },
+          title: Text(project.name),
+          subtitle: Text(
+            alreadyImported ? '${_relativePath(project)} (already imported)' : _relativePath(project),
+            overflow: TextOverflow.ellipsis,
+          ),
+        );
+      },
+    );
+  }
+}
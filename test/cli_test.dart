@@ -0,0 +1,36 @@
+import 'package:bob/cli.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+void main() {
+  group('commandArguments', () {
+    test('drops the app flags wherever they are', () {
+      expect(commandArguments(['--profile', 'work', 'run', 'api', 'dev']), ['run', 'api', 'dev']);
+      expect(commandArguments(['run', '--data-dir', '/tmp/bob', 'api', 'dev']), ['run', 'api', 'dev']);
+      expect(commandArguments(['--portable', 'list']), ['list']);
+    });
+
+    test('leaves nothing of an app launch', () {
+      expect(commandArguments([]), isEmpty);
+      expect(commandArguments(['--headless', '--config-dir', 'config']), isEmpty);
+    });
+  });
+
+  group('isCliCommand', () {
+    test('knows the commands', () {
+      for (final command in cliCommands) {
+        expect(isCliCommand([command]), isTrue, reason: command);
+      }
+      expect(isCliCommand(['--profile', 'work', 'status', 'api', 'dev']), isTrue);
+    });
+
+    test('takes a profile named like a command for a profile', () {
+      expect(isCliCommand(['--profile', 'run']), isFalse);
+    });
+
+    test('opens the window otherwise', () {
+      expect(isCliCommand([]), isFalse);
+      expect(isCliCommand(['--headless']), isFalse);
+      expect(isCliCommand(['open']), isFalse);
+    });
+  });
+}
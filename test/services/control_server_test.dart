@@ -0,0 +1,45 @@
+import 'package:bob/services/control_server.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+void main() {
+  group('routes', () {
+    const task = {'project': 'web app', 'task': 'dev'};
+
+    for (final command in ['status', 'output', 'run', 'stop']) {
+      test('$command goes to a route leading back to it', () {
+        final (method, segments) = ControlServer.routeOf(command, task);
+        final route = ControlServer.route(method, segments);
+
+        expect(route?.$1, command);
+        expect(route?.$2, task);
+      });
+    }
+
+    for (final command in ['list', 'show']) {
+      test('$command goes to a route leading back to it, without a task', () {
+        final (method, segments) = ControlServer.routeOf(command, {});
+        final route = ControlServer.route(method, segments);
+
+        expect(route?.$1, command);
+        expect(route?.$2, isEmpty);
+      });
+    }
+
+    test('the logs and view pages of a task have routes of their own', () {
+      final logs = ControlServer.route('GET', ['projects', 'web', 'tasks', 'dev', 'logs']);
+      final view = ControlServer.route('GET', ['projects', 'web', 'tasks', 'dev', 'view']);
+
+      expect(logs?.$1, 'logs');
+      expect(logs?.$2, {'project': 'web', 'task': 'dev'});
+      expect(view?.$1, 'view');
+      expect(view?.$2, {'project': 'web', 'task': 'dev'});
+    });
+
+    test('unknown commands and routes are refused', () {
+      expect(() => ControlServer.routeOf('restart', {}), throwsA(isA<ControlException>()));
+      expect(ControlServer.route('DELETE', ['projects']), isNull);
+      expect(ControlServer.route('GET', ['projects', 'web', 'tasks', 'dev', 'start']), isNull);
+      expect(ControlServer.route('POST', ['projects', 'web']), isNull);
+    });
+  });
+}
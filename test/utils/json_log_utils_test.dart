@@ -0,0 +1,76 @@
+import 'package:bob/utils/json_log_utils.dart';
+import 'package:bob/utils/log_level_utils.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+void main() {
+  final timeOfDay = matches(RegExp(r'^\d\d:\d\d:\d\d$'));
+
+  group('parse', () {
+    test('reads pino lines', () {
+      final entry = JsonLogUtils.parse('{"level":30,"time":1700000000000,"msg":"listening","port":3000}')!;
+
+      expect(entry.level, LogLevel.info);
+      expect(entry.levelName, 'info');
+      expect(entry.time, timeOfDay);
+      expect(entry.message, 'listening');
+      expect(entry.fields, {'port': 3000});
+    });
+
+    test('ranks numeric levels as pino does', () {
+      expect(JsonLogUtils.parse('{"level":40}')!.level, LogLevel.warning);
+      expect(JsonLogUtils.parse('{"level":40}')!.levelName, 'warn');
+      expect(JsonLogUtils.parse('{"level":60}')!.level, LogLevel.error);
+      expect(JsonLogUtils.parse('{"level":60}')!.levelName, 'fatal');
+    });
+
+    test('reads named levels and other keys for them', () {
+      final entry =
+          JsonLogUtils.parse('{"severity":"WARNING","message":"disk low","@timestamp":"2024-05-01T10:20:30Z"}')!;
+
+      expect(entry.level, LogLevel.warning);
+      expect(entry.levelName, 'WARNING');
+      expect(entry.message, 'disk low');
+      expect(entry.time, timeOfDay);
+      expect(entry.fields, isEmpty);
+    });
+
+    test('keeps times it cannot read as they are', () {
+      expect(JsonLogUtils.parse('{"time":"yesterday"}')!.time, 'yesterday');
+    });
+
+    test('passes over lines that are not JSON objects', () {
+      expect(JsonLogUtils.parse('plain text'), isNull);
+      expect(JsonLogUtils.parse('{not json}'), isNull);
+      expect(JsonLogUtils.parse('[1, 2]'), isNull);
+      expect(JsonLogUtils.parse('{"a":1} trailing'), isNull);
+    });
+  });
+
+  group('searching', () {
+    const line = '{"level":50,"msg":"boom","reqId":42}';
+
+    test('tells field searches from plain ones', () {
+      expect(JsonLogUtils.fieldFilter('level=error'), ('level', 'error'));
+      expect(JsonLogUtils.fieldFilter(' reqId=42 '), ('reqId', '42'));
+      expect(JsonLogUtils.fieldFilter('plain search'), isNull);
+    });
+
+    test('matches levels by name and number', () {
+      expect(JsonLogUtils.matchesField(line, 'level', 'error'), isTrue);
+      expect(JsonLogUtils.matchesField('{"level":40}', 'level', 'warn'), isTrue);
+      expect(JsonLogUtils.matchesField('{"level":30}', 'level', 'warn'), isFalse);
+    });
+
+    test('matches messages and fields ignoring case', () {
+      expect(JsonLogUtils.matchesField(line, 'msg', 'BOOM'), isTrue);
+      expect(JsonLogUtils.matchesField(line, 'reqId', '42'), isTrue);
+      expect(JsonLogUtils.matchesField(line, 'reqId', '43'), isFalse);
+      expect(JsonLogUtils.matchesField('not json', 'msg', 'boom'), isFalse);
+    });
+
+    test('shows strings without quotes and other values as JSON', () {
+      expect(JsonLogUtils.formatValue('a'), 'a');
+      expect(JsonLogUtils.formatValue({'b': 1}), '{"b":1}');
+    });
+  });
+}
@@ -0,0 +1,69 @@
+import 'dart:io';
+
+import 'package:bob/models/project.dart';
+import 'package:bob/utils/makefile_utils.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as path;
+
+void main() {
+  group('parseTargets', () {
+    test('lists targets in order, once each', () {
+      const makefile = '.PHONY: build test\n'
+          'build: deps\n'
+          '\tgo build ./...\n'
+          'test lint: build\n'
+          'deps:\n'
+          'build: more\n';
+
+      expect(MakefileUtils.parseTargets(makefile), ['build', 'test', 'lint', 'deps']);
+    });
+
+    test('skips assignments, comments, pattern rules and variable targets', () {
+      const makefile = 'CC := gcc\n'
+          'VERSION ::= 1\n'
+          '# release: not a target\n'
+          '%.o: %.c\n'
+          r'$(OUT): build' '\n'
+          'release:\n';
+
+      expect(MakefileUtils.parseTargets(makefile), ['release']);
+    });
+
+    test('reads Windows line endings', () {
+      expect(MakefileUtils.parseTargets('build:\r\ntest:\r\n'), ['build', 'test']);
+    });
+  });
+
+  group('readTasks', () {
+    late Directory project;
+
+    setUp(() async {
+      project = await Directory.systemTemp.createTemp('bob-make-');
+    });
+
+    tearDown(() async {
+      await project.delete(recursive: true);
+    });
+
+    test('names tasks after make targets', () async {
+      await File(path.join(project.path, 'Makefile')).writeAsString('build:\n\tgo build\n');
+
+      final tasks = await MakefileUtils.readTasks(project.path);
+
+      expect(tasks.map((task) => task.name), ['make build']);
+      expect(tasks.single.command, 'make build');
+      expect(tasks.single.type, TaskType.make);
+    });
+
+    test('prefers GNUmakefile, as make does', () async {
+      await File(path.join(project.path, 'GNUmakefile')).writeAsString('gnu:\n');
+      await File(path.join(project.path, 'Makefile')).writeAsString('plain:\n');
+
+      expect((await MakefileUtils.readTasks(project.path)).map((task) => task.name), ['make gnu']);
+    });
+
+    test('finds nothing without a Makefile', () async {
+      expect(await MakefileUtils.readTasks(project.path), isEmpty);
+    });
+  });
+}
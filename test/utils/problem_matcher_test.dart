@@ -0,0 +1,101 @@
+import 'package:bob/models/problem.dart';
+import 'package:bob/utils/problem_matcher.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+void main() {
+  group('ProblemMatcher.parse', () {
+    test('reads tsc errors in both formats', () {
+      const output = "src/a.ts(12,5): error TS2322: Type 'string' is not assignable to type 'number'.\n"
+          "src/b.ts:3:7 - warning TS6133: 'y' is declared but its value is never read.\n";
+
+      expect(ProblemMatcher.parse(output), [
+        const Problem(
+          file: 'src/a.ts',
+          line: 12,
+          column: 5,
+          message: "TS2322: Type 'string' is not assignable to type 'number'.",
+        ),
+        const Problem(
+          file: 'src/b.ts',
+          line: 3,
+          column: 7,
+          message: "TS6133: 'y' is declared but its value is never read.",
+          isWarning: true,
+        ),
+      ]);
+    });
+
+    test('sees through colors', () {
+      const output = "\x1B[31msrc/c.ts(1,1): error TS1005: ';' expected.\x1B[0m";
+
+      expect(ProblemMatcher.parse(output).single.location, 'src/c.ts:1:1');
+    });
+
+    test('finds the location esbuild prints below its error', () {
+      const output = '✘ [ERROR] Could not resolve "./missing"\n'
+          '\n'
+          '    src/index.ts:2:7:\n'
+          '      2 │ import "./missing"\n';
+
+      expect(ProblemMatcher.parse(output), [
+        const Problem(file: 'src/index.ts', line: 2, column: 7, message: 'Could not resolve "./missing"'),
+      ]);
+    });
+
+    test('finds the location rustc prints below its error or warning', () {
+      const output = 'error[E0308]: mismatched types\n'
+          ' --> src/main.rs:4:18\n'
+          'warning: unused variable: `x`\n'
+          '  --> src/lib.rs:10:9\n';
+
+      expect(ProblemMatcher.parse(output), [
+        const Problem(file: 'src/main.rs', line: 4, column: 18, message: 'mismatched types'),
+        const Problem(file: 'src/lib.rs', line: 10, column: 9, message: 'unused variable: `x`', isWarning: true),
+      ]);
+    });
+
+    test('gives up on a location that comes too late', () {
+      const output = 'error[E0601]: main function not found\n'
+          'one\n'
+          'two\n'
+          'three\n'
+          'four\n'
+          ' --> src/main.rs:1:1\n';
+
+      expect(ProblemMatcher.parse(output), isEmpty);
+    });
+
+    test('reads vite errors', () {
+      const output = '[vite] Internal server error: /src/App.tsx: Unexpected token (12:5)\n';
+
+      expect(ProblemMatcher.parse(output), [
+        const Problem(file: '/src/App.tsx', line: 12, column: 5, message: 'Unexpected token'),
+      ]);
+    });
+
+    test('gives a failing file the error above it', () {
+      const output = 'error during build:\n'
+          '[vite:esbuild] Transform failed with 1 error:\n'
+          'file: /app/src/main.ts:8:3\n';
+
+      expect(ProblemMatcher.parse(output), [
+        const Problem(
+          file: '/app/src/main.ts',
+          line: 8,
+          column: 3,
+          message: '[vite:esbuild] Transform failed with 1 error:',
+        ),
+      ]);
+    });
+
+    test('lists a problem reported twice once', () {
+      const line = 'src/a.ts(1,1): error TS1005: expected.';
+
+      expect(ProblemMatcher.parse('$line\n$line\n'), hasLength(1));
+    });
+
+    test('finds nothing in ordinary output', () {
+      expect(ProblemMatcher.parse('VITE v5.0.0  ready in 300 ms\n  ➜  Local:   http://localhost:5173/\n'), isEmpty);
+    });
+  });
+}
@@ -0,0 +1,57 @@
+import 'dart:io';
+
+import 'package:bob/models/project.dart';
+import 'package:bob/utils/procfile_utils.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as path;
+
+void main() {
+  group('parse', () {
+    test('maps process types to their commands in file order', () {
+      const procfile = 'web: bundle exec rails server -p \$PORT\n'
+          'worker:   bundle exec sidekiq  \n'
+          'release_1: rake db:migrate\n';
+
+      expect(ProcfileUtils.parse(procfile), {
+        'web': r'bundle exec rails server -p $PORT',
+        'worker': 'bundle exec sidekiq',
+        'release_1': 'rake db:migrate',
+      });
+      expect(ProcfileUtils.parse(procfile).keys, ['web', 'worker', 'release_1']);
+    });
+
+    test('skips comments and lines without a process type', () {
+      const procfile = '# web: not this one\r\n'
+          'not a process type\r\n'
+          'web: node server.js\r\n';
+
+      expect(ProcfileUtils.parse(procfile), {'web': 'node server.js'});
+    });
+  });
+
+  group('readTasks', () {
+    late Directory project;
+
+    setUp(() async {
+      project = await Directory.systemTemp.createTemp('bob-procfile-');
+    });
+
+    tearDown(() async {
+      await project.delete(recursive: true);
+    });
+
+    test('names tasks after process types, suffixing taken names', () async {
+      await File(path.join(project.path, 'Procfile')).writeAsString('web: node server.js\nworker: node worker.js\n');
+
+      final tasks = await ProcfileUtils.readTasks(project.path, ['web']);
+
+      expect(tasks.map((task) => task.name), ['web (Procfile)', 'worker']);
+      expect(tasks.map((task) => task.command), ['node server.js', 'node worker.js']);
+      expect(tasks.every((task) => task.type == TaskType.procfile), isTrue);
+    });
+
+    test('finds nothing without a Procfile', () async {
+      expect(await ProcfileUtils.readTasks(project.path, []), isEmpty);
+    });
+  });
+}
@@ -0,0 +1,81 @@
+import 'dart:io';
+
+import 'package:bob/models/project.dart';
+import 'package:bob/utils/pyproject_utils.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as path;
+
+void main() {
+  group('parseTableKeys', () {
+    test('lists the keys of each table, bare or quoted', () {
+      const toml = '[project]\n'
+          'name = "app"\n'
+          '\n'
+          '[project.scripts]  # entry points\n'
+          'serve = "app.main:run"\n'
+          '"migrate-db" = "app.db:migrate"\n'
+          "'seed' = \"app.db:seed\"\n";
+
+      expect(PyprojectUtils.parseTableKeys(toml), {
+        'project': ['name'],
+        'project.scripts': ['serve', 'migrate-db', 'seed'],
+      });
+    });
+
+    test('ignores keys before the first table', () {
+      expect(PyprojectUtils.parseTableKeys('stray = 1\n[tool.uv]\npackage = true\n'), {
+        'tool.uv': ['package'],
+      });
+    });
+  });
+
+  group('readTasks', () {
+    late Directory project;
+
+    setUp(() async {
+      project = await Directory.systemTemp.createTemp('bob-pyproject-');
+    });
+
+    tearDown(() async {
+      await project.delete(recursive: true);
+    });
+
+    Future<List<Task>> read(String toml) async {
+      await File(path.join(project.path, 'pyproject.toml')).writeAsString(toml);
+      return PyprojectUtils.readTasks(project.path);
+    }
+
+    test('runs scripts of poetry projects with poetry, plus pytest', () async {
+      final tasks = await read('[project.scripts]\n'
+          'serve = "app.main:run"\n'
+          '[tool.poetry.scripts]\n'
+          'cli = "app.cli:main"\n'
+          '[tool.pytest.ini_options]\n'
+          'addopts = "-q"\n');
+
+      expect(tasks.map((task) => task.name), ['poetry run serve', 'poetry run cli', 'poetry run pytest']);
+      expect(tasks.every((task) => task.type == TaskType.python && task.command == task.name), isTrue);
+    });
+
+    test('runs scripts with uv otherwise, finding pytest among dependencies', () async {
+      final tasks = await read('[project.scripts]\n'
+          'serve = "app:run"\n'
+          '[dependency-groups]\n'
+          'dev = ["pytest>=8"]\n');
+
+      expect(tasks.map((task) => task.name), ['uv run serve', 'uv run pytest']);
+    });
+
+    test('takes a poetry.lock as a poetry project', () async {
+      await File(path.join(project.path, 'poetry.lock')).writeAsString('');
+
+      final tasks = await read('[project.scripts]\nserve = "app:run"\n');
+
+      expect(tasks.map((task) => task.name), ['poetry run serve']);
+    });
+
+    test('finds nothing without a pyproject.toml', () async {
+      expect(await PyprojectUtils.readTasks(project.path), isEmpty);
+    });
+  });
+}
@@ -0,0 +1,66 @@
+import 'package:bob/utils/secret_masker.dart';
+import 'package:flutter_test/flutter_test.dart';
+
+void main() {
+  group('SecretMasker', () {
+    test('picks the values of variables named like secrets', () {
+      final environment = {
+        'API_KEY': 'abc123456',
+        'DB_PASSWORD': 'hunter22',
+        'PORT': '3000',
+        'GITHUB_TOKEN': 'ghp_abcdef',
+      };
+
+      expect(SecretMasker.secretsOf(environment), ['abc123456', 'hunter22', 'ghp_abcdef']);
+    });
+
+    test('hides values, leaving short ones alone', () {
+      final masker = SecretMasker(values: ['supersecret', 'abc']);
+
+      expect(masker.apply('token=supersecret abc'), 'token=${SecretMasker.mask} abc');
+    });
+
+    test('hides a secret containing another whole', () {
+      final masker = SecretMasker(values: ['secret1', 'secret12345']);
+
+      expect(masker.apply('secret12345'), SecretMasker.mask);
+    });
+
+    test('hides matches of patterns, skipping invalid ones and those matching nothing', () {
+      final masker = SecretMasker(patterns: [r'ghp_\w+', '(', 'x*']);
+
+      expect(masker.apply('ghp_abc and xx'), '${SecretMasker.mask} and xx');
+    });
+
+    test('leaves text alone without secrets', () {
+      expect(SecretMasker.none.isEmpty, isTrue);
+      expect(SecretMasker(values: ['line one\nline two']).isEmpty, isTrue);
+      expect(SecretMasker().apply('plain'), 'plain');
+    });
+  });
+
+  group('LineSecretMasker', () {
+    test('hides a secret split across chunks', () {
+      final masker = SecretMasker(values: ['supersecret']);
+      final lines = LineSecretMasker(() => masker);
+
+      expect(lines.add('key: super'), '');
+      expect(lines.add('secret\nnext'), 'key: ${SecretMasker.mask}\n');
+      expect(lines.flush(), 'next');
+    });
+
+    test('passes output through when there is nothing to hide', () {
+      final lines = LineSecretMasker(() => SecretMasker.none);
+
+      expect(lines.add('partial'), 'partial');
+      expect(lines.flush(), '');
+    });
+
+    test('lets a long unfinished line out', () {
+      final lines = LineSecretMasker(() => SecretMasker(values: ['supersecret']));
+      final long = 'a' * (LineSecretMasker.maxPendingLength + 1);
+
+      expect(lines.add(long), long);
+    });
+  });
+}
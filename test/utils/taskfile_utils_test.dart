@@ -0,0 +1,71 @@
+import 'dart:io';
+
+import 'package:bob/models/project.dart';
+import 'package:bob/utils/taskfile_utils.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:path/path.dart' as path;
+
+void main() {
+  group('parseTaskNames', () {
+    test('lists the tasks under tasks, quoted names included', () {
+      const taskfile = "version: '3'\n"
+          '\n'
+          'vars:\n'
+          '  NAME: app\n'
+          '\n'
+          'tasks:\n'
+          '  build:\n'
+          '    cmds:\n'
+          '      - go build\n'
+          '  # lint: commented out\n'
+          '  "test:unit":\n'
+          '    cmds:\n'
+          '      - go test ./...\n'
+          '  lint:\n'
+          '    desc: Lint # the code\n'
+          '\n'
+          'includes:\n'
+          '  docs: ./docs\n';
+
+      expect(TaskfileUtils.parseTaskNames(taskfile), ['build', 'test:unit', 'lint']);
+    });
+
+    test('skips internal tasks', () {
+      const taskfile = 'tasks:\n'
+          '  setup:\n'
+          '    internal: true\n'
+          '    cmds:\n'
+          '      - echo setup\n'
+          '  dev:\n'
+          '    internal: false\n';
+
+      expect(TaskfileUtils.parseTaskNames(taskfile), ['dev']);
+    });
+  });
+
+  group('readTasks', () {
+    late Directory project;
+
+    setUp(() async {
+      project = await Directory.systemTemp.createTemp('bob-taskfile-');
+    });
+
+    tearDown(() async {
+      await project.delete(recursive: true);
+    });
+
+    test('names tasks after Taskfile tasks', () async {
+      await File(path.join(project.path, 'Taskfile.yml')).writeAsString('tasks:\n  build:\n    cmds: [go build]\n');
+
+      final tasks = await TaskfileUtils.readTasks(project.path);
+
+      expect(tasks.map((task) => task.name), ['task build']);
+      expect(tasks.single.command, 'task build');
+      expect(tasks.single.type, TaskType.taskfile);
+    });
+
+    test('finds nothing without a Taskfile', () async {
+      expect(await TaskfileUtils.readTasks(project.path), isEmpty);
+    });
+  });
+}
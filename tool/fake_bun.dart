@@ -0,0 +1,97 @@
+// A stand-in for bun, so task flows can be exercised without network access
+// or a real runtime.
+//
+// The integration tests compile it and run tasks with it:
+//
+//   flutter test integration_test -d <linux|macos|windows>
+//
+// What each command does is read from fake-bun.json in the project folder,
+// or from the file named by FAKE_BUN_CONFIG:
+//
+//   {
+//     "install": { "output": ["installed 3 packages"] },
+//     "scripts": {
+//       "dev": { "output": ["listening on :3000"], "delayMs": 200, "hang": true },
+//       "test": { "output": ["1 failing"], "exitCode": 1 }
+//     }
+//   }
+//
+// Lines of "output" are printed one every "delayMs" (to stderr when "stderr"
+// is true), then the process exits with "exitCode", or keeps running when
+// "hang" is true. SIGTERM exits with 143 unless "ignoreSigterm" is true.
+// Commands without an entry print their arguments and succeed.
+
+import 'dart:async';
+import 'dart:convert';
+import 'dart:io';
+
+Future<void> main(List<String> args) async {
+  if (args.isNotEmpty && args.first == '--version') {
+    stdout.writeln('1.0.0-fake');
+    return;
+  }
+
+  final behavior = _behaviorFor(args, await _readConfig());
+  if (behavior == null) {
+    stdout.writeln('fake bun ${args.join(' ')}');
+    return;
+  }
+
+  if (!Platform.isWindows) {
+    ProcessSignal.sigterm.watch().listen((_) async {
+      if (behavior['ignoreSigterm'] == true) {
+        stdout.writeln('ignoring SIGTERM');
+        return;
+      }
+      stdout.writeln('received SIGTERM, shutting down');
+      await stdout.flush();
+      exit(143);
+    });
+  }
+
+  final sink = behavior['stderr'] == true ? stderr : stdout;
+  final delay = Duration(milliseconds: behavior['delayMs'] as int? ?? 0);
+  for (final line in (behavior['output'] as List<dynamic>? ?? []).whereType<String>()) {
+    if (delay > Duration.zero) {
+      await Future<void>.delayed(delay);
+    }
+    sink.writeln(line);
+  }
+
+  if (behavior['hang'] == true) {
+    // Keep the event loop alive until a signal ends the process
+    Timer.periodic(const Duration(hours: 1), (_) {});
+    return;
+  }
+
+  await stdout.flush();
+  await stderr.flush();
+  exit(behavior['exitCode'] as int? ?? 0);
+}
+
+Future<Map<String, dynamic>> _readConfig() async {
+  final file = File(Platform.environment['FAKE_BUN_CONFIG'] ?? 'fake-bun.json');
+  if (!await file.exists()) {
+    return {};
+  }
+  return jsonDecode(await file.readAsString()) as Map<String, dynamic>;
+}
+
+Map<String, dynamic>? _behaviorFor(List<String> args, Map<String, dynamic> config) {
+  if (args.isEmpty) {
+    return null;
+  }
+
+  switch (args.first) {
+    case 'install':
+      return config['install'] as Map<String, dynamic>?;
+    case 'run':
+      if (args.length < 2) {
+        return null;
+      }
+      final scripts = config['scripts'] as Map<String, dynamic>? ?? {};
+      return scripts[args[1]] as Map<String, dynamic>?;
+    default:
+      return null;
+  }
+}